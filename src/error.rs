@@ -1,6 +1,7 @@
 use core::fmt;
 
-use super::types::GeometryKind;
+use super::types::{CoordinatesKind, GeometryKind};
+use crate::prelude::*;
 
 #[derive(Debug)]
 pub enum Error {
@@ -8,22 +9,273 @@ pub enum Error {
         expecting: GeometryKind,
         got: GeometryKind,
     },
+    /// Les coordonnées n'ont pas la forme attendue.
+    InvalidCoordinatesKind {
+        expecting: CoordinatesKind,
+        got: CoordinatesKind,
+    },
+    /// La géométrie ne peut pas être représentée dans le format cible.
+    UnsupportedGeometry {
+        kind: GeometryKind,
+        reason: &'static str,
+    },
+    /// L'appel de fonction brut a été refusé.
+    InvalidFunctionCall { name: String, reason: &'static str },
+    /// Les coordonnées n'ont pas la dimension attendue.
+    InvalidDimension { expecting: usize, got: usize },
+    /// L'octet de boutisme n'est ni 0 (big endian) ni 1 (little endian).
+    InvalidByteOrder(u8),
+    /// Le code du type de géométrie est inconnu.
+    UnknownGeometryCode(u32),
+    /// Un octet de balisage du format binaire n'a pas la valeur attendue.
+    InvalidMarker {
+        marker: &'static str,
+        expecting: u8,
+        got: u8,
+    },
+    /// Le flux décodé dépasse une des limites de [crate::DecodeLimits].
+    LimitExceeded {
+        limit: &'static str,
+        max: usize,
+        got: usize,
+    },
+    /// Les géométries d'une même opération n'ont pas le même SRID.
+    SridMismatch {
+        expecting: Option<u32>,
+        got: Option<u32>,
+    },
+    /// Aucune définition de projection n'est connue pour ce SRID.
+    UnknownSrid(u32),
+    /// La reprojection a échoué.
+    Projection(String),
+    /// GDAL a rejeté la géométrie.
+    Gdal(String),
+    /// Le résultat d'une opération dépasserait `max` sommets.
+    TooManyVertices { max: usize },
+    /// Les coordonnées sont inutilisables (valeur non finie, anneau vide...).
+    InvalidCoordinates { reason: &'static str },
+    /// Le texte WKT est mal formé ; `offset` est la position, en octets, de l'erreur.
+    InvalidWkt { offset: usize, reason: &'static str },
+    /// Erreur de décodage, située dans le flux.
+    ///
+    /// `offset` est le nombre d'octets lus lorsque l'erreur est détectée, `path`
+    /// la position dans les coordonnées (par exemple `ring 2, vertex 17`).
+    Located {
+        offset: usize,
+        path: String,
+        error: Box<Error>,
+    },
+    /// Le flux s'arrête au milieu d'une géométrie.
+    UnexpectedEof,
+    /// Des octets restent après la géométrie décodée.
+    TrailingBytes { consumed: usize, remaining: usize },
+    /// Erreur de lecture ou d'écriture du flux.
+    #[cfg(feature = "std")]
+    Io(std::io::Error),
 }
 
 impl fmt::Display for Error {
-    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        todo!()
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidGeometryKind { expecting, got } => {
+                write!(f, "expecting a {expecting} geometry, got a {got}")
+            }
+            Self::InvalidCoordinatesKind { expecting, got } => {
+                write!(f, "expecting a {expecting}, got a {got}")
+            }
+            Self::UnsupportedGeometry { kind, reason } => {
+                write!(f, "unsupported {kind} geometry: {reason}")
+            }
+            Self::InvalidFunctionCall { name, reason } => {
+                write!(f, "invalid call to {name}: {reason}")
+            }
+            Self::InvalidDimension { expecting, got } => {
+                write!(
+                    f,
+                    "expecting coordinates of dimension {expecting}, got {got}"
+                )
+            }
+            Self::InvalidByteOrder(byte) => {
+                write!(f, "invalid byte order {byte:#04x}, expecting 0x00 or 0x01")
+            }
+            Self::UnknownGeometryCode(code) => write!(f, "unknown geometry code {code}"),
+            Self::InvalidMarker {
+                marker,
+                expecting,
+                got,
+            } => {
+                write!(
+                    f,
+                    "invalid {marker} marker {got:#04x}, expecting {expecting:#04x}"
+                )
+            }
+            Self::LimitExceeded { limit, max, got } => {
+                write!(f, "decode limit exceeded for {limit}: {got} > {max}")
+            }
+            Self::SridMismatch { expecting, got } => {
+                write!(
+                    f,
+                    "SRID mismatch: expecting {}, got {}",
+                    DisplaySrid(*expecting),
+                    DisplaySrid(*got)
+                )
+            }
+            Self::UnknownSrid(srid) => write!(f, "no projection definition for SRID {srid}"),
+            Self::Projection(reason) => write!(f, "reprojection failed: {reason}"),
+            Self::Gdal(reason) => write!(f, "GDAL error: {reason}"),
+            Self::TooManyVertices { max } => write!(f, "result would exceed {max} vertices"),
+            Self::InvalidCoordinates { reason } => write!(f, "invalid coordinates: {reason}"),
+            Self::InvalidWkt { offset, reason } => {
+                write!(f, "invalid WKT at byte {offset}: {reason}")
+            }
+            Self::Located {
+                offset,
+                path,
+                error,
+            } if path.is_empty() => write!(f, "at byte {offset}: {error}"),
+            Self::Located {
+                offset,
+                path,
+                error,
+            } => write!(f, "at byte {offset} ({path}): {error}"),
+            Self::UnexpectedEof => write!(f, "unexpected end of stream"),
+            Self::TrailingBytes {
+                consumed,
+                remaining,
+            } => {
+                write!(
+                    f,
+                    "{remaining} trailing bytes after the {consumed} bytes of the geometry"
+                )
+            }
+            #[cfg(feature = "std")]
+            Self::Io(error) => write!(f, "I/O error: {error}"),
+        }
+    }
+}
+
+/// Affiche un SRID optionnel.
+struct DisplaySrid(Option<u32>);
+
+impl fmt::Display for DisplaySrid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(srid) => write!(f, "{srid}"),
+            None => write!(f, "no SRID"),
+        }
     }
 }
 
-impl std::error::Error for Error {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        None
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "std")]
+            Self::Io(error) => Some(error),
+            Self::Located { error, .. } => Some(error.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        match value.kind() {
+            std::io::ErrorKind::UnexpectedEof => Self::UnexpectedEof,
+            _ => Self::Io(value),
+        }
     }
 }
 
 impl Error {
+    /// Erreur sous-jacente, débarrassée de sa position dans le flux.
+    pub fn into_inner(self) -> Self {
+        match self {
+            Self::Located { error, .. } => error.into_inner(),
+            error => error,
+        }
+    }
+
+    /// Vrai si le flux décodé est tronqué.
+    pub fn is_unexpected_eof(&self) -> bool {
+        match self {
+            Self::UnexpectedEof => true,
+            Self::Located { error, .. } => error.is_unexpected_eof(),
+            _ => false,
+        }
+    }
+
     pub fn invalid_geometry_kind(expecting: GeometryKind, got: GeometryKind) -> Self {
         Self::InvalidGeometryKind { expecting, got }
     }
+
+    pub fn invalid_coordinates_kind(expecting: CoordinatesKind, got: CoordinatesKind) -> Self {
+        Self::InvalidCoordinatesKind { expecting, got }
+    }
+
+    pub fn unsupported_geometry(kind: GeometryKind, reason: &'static str) -> Self {
+        Self::UnsupportedGeometry { kind, reason }
+    }
+
+    pub fn invalid_function_call(name: &str, reason: &'static str) -> Self {
+        Self::InvalidFunctionCall {
+            name: name.to_owned(),
+            reason,
+        }
+    }
+
+    pub fn invalid_dimension(expecting: usize, got: usize) -> Self {
+        Self::InvalidDimension { expecting, got }
+    }
+
+    pub fn srid_mismatch(expecting: Option<u32>, got: Option<u32>) -> Self {
+        Self::SridMismatch { expecting, got }
+    }
+
+    pub fn invalid_coordinates(reason: &'static str) -> Self {
+        Self::InvalidCoordinates { reason }
+    }
+
+    pub fn invalid_wkt(offset: usize, reason: &'static str) -> Self {
+        Self::InvalidWkt { offset, reason }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            Error::invalid_geometry_kind(GeometryKind::Point, GeometryKind::Polygon).to_string(),
+            "expecting a Point geometry, got a Polygon"
+        );
+        assert_eq!(
+            Error::InvalidByteOrder(7).to_string(),
+            "invalid byte order 0x07, expecting 0x00 or 0x01"
+        );
+        assert_eq!(
+            Error::srid_mismatch(Some(4326), None).to_string(),
+            "SRID mismatch: expecting 4326, got no SRID"
+        );
+        assert_eq!(
+            Error::invalid_wkt(9, "expecting ')'").to_string(),
+            "invalid WKT at byte 9: expecting ')'"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_source() {
+        use core::error::Error as _;
+
+        let error = Error::from(std::io::Error::from(std::io::ErrorKind::PermissionDenied));
+        assert!(error.source().is_some());
+        assert!(
+            Error::from(std::io::Error::from(std::io::ErrorKind::UnexpectedEof))
+                .is_unexpected_eof()
+        );
+        assert!(Error::UnknownGeometryCode(42).source().is_none());
+    }
 }
@@ -10,15 +10,21 @@ use std::{
 };
 
 use crate::{
-    io::{Decodable, Encodable},
+    error::Error,
+    io::{
+        coordinates_size, read_frames, trace_decode, trace_encode, write_frame, CountingReader,
+        Decodable, DecodeBudget, DecodeOptions, Encodable, Endian,
+    },
     types::{
         CoordinatesRef, GeometryImpl as _, LineString, LineStringZ, MultiLineString,
         MultiLineStringZ, MultiPoint, MultiPointZ, MultiPolygon, MultiPolygonZ, Point, PointZ,
-        Polygon, PolygonZ, Vector, VectorArray, VectorMatrix, VectorTensor,
+        Polygon, PolygonZ, RingOrientation, Vector, VectorArray, VectorMatrix, VectorTensor,
     },
 };
 
-use super::types::{Geometry, GeometryKind};
+#[cfg(feature = "arena")]
+use super::types::{ArenaCoordinates, ArenaGeometry, Bump};
+use super::types::{Geometry, GeometryKind, GeometryRef};
 
 /// Objet intermédiaire pour encoder/decoder
 /// au format EWKB toute géométrie.
@@ -29,19 +35,23 @@ impl Encodable for EWKBGeometry {
     fn encode<W: Write>(&self, stream: &mut W) -> Result<(), std::io::Error> {
         encode_geometry(&self.0, stream)
     }
+
+    fn encoded_size(&self) -> Option<usize> {
+        Some(encoded_size(&self.0))
+    }
 }
 
 impl Decodable for EWKBGeometry {
-    fn decode<R: Read>(stream: &mut R) -> Result<Self, std::io::Error> {
+    fn decode<R: Read>(stream: &mut R) -> Result<Self, Error> {
         decode_geometry(stream).map(Self)
     }
 }
 
 impl TryFrom<&[u8]> for EWKBGeometry {
-    type Error = std::io::Error;
+    type Error = Error;
 
-    fn try_from(mut value: &[u8]) -> Result<Self, Self::Error> {
-        decode_geometry(&mut value).map(Self::new)
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Self::decode_from_slice(value)
     }
 }
 
@@ -80,10 +90,95 @@ impl EWKBGeometry {
 
 impl_geometry_proxies!(EWKB);
 
+/// Lie une géométrie dans une requête sea-query, encodée en EWKB.
+#[cfg(feature = "sea-query")]
+mod sea_query {
+    use ::sea_query::{
+        Alias, ArrayType, ColumnType, IntoIden, Nullable, Value, ValueType, ValueTypeErr,
+    };
+
+    use super::*;
+
+    impl From<EWKBGeometry> for Value {
+        fn from(value: EWKBGeometry) -> Self {
+            let encoded = value
+                .encode_to_vec()
+                .expect("encoding into a Vec cannot fail");
+            Value::Bytes(Some(Box::new(encoded)))
+        }
+    }
+
+    impl Nullable for EWKBGeometry {
+        fn null() -> Value {
+            Value::Bytes(None)
+        }
+    }
+
+    impl ValueType for EWKBGeometry {
+        fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+            match v {
+                Value::Bytes(Some(bytes)) => {
+                    EWKBGeometry::decode_from_slice(&bytes).map_err(|_| ValueTypeErr)
+                }
+                _ => Err(ValueTypeErr),
+            }
+        }
+
+        fn type_name() -> String {
+            stringify!(EWKBGeometry).to_owned()
+        }
+
+        fn array_type() -> ArrayType {
+            ArrayType::Bytes
+        }
+
+        fn column_type() -> ColumnType {
+            ColumnType::Custom(Alias::new("geometry").into_iden())
+        }
+    }
+
+    /// Une [Geometry] nue est toujours liée au format EWKB, que seul PostGIS comprend.
+    ///
+    /// Pour une requête SpatiaLite, liez plutôt le proxy du backend
+    /// (`SpatiaLiteGeometry`, `SpatiaLitePolygon`, ...), qui produit un BLOB SpatiaLite.
+    impl From<Geometry> for Value {
+        fn from(value: Geometry) -> Self {
+            EWKBGeometry::from(value).into()
+        }
+    }
+
+    impl Nullable for Geometry {
+        fn null() -> Value {
+            Value::Bytes(None)
+        }
+    }
+
+    impl ValueType for Geometry {
+        fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+            <EWKBGeometry as ValueType>::try_from(v).map(Geometry::from)
+        }
+
+        fn type_name() -> String {
+            stringify!(Geometry).to_owned()
+        }
+
+        fn array_type() -> ArrayType {
+            ArrayType::Bytes
+        }
+
+        fn column_type() -> ColumnType {
+            <EWKBGeometry as ValueType>::column_type()
+        }
+    }
+
+    impl_geometry_sea_query_values!(EWKB);
+}
+
 const BIG_ENDIAN: u8 = 0;
 const LITTLE_ENDIAN: u8 = 1;
 
 /// Objet permettant d'encoder ou décoder suivant le boutisme définit dans la base de données.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Endianess {
     BigEndian,
     LittleEndian,
@@ -101,6 +196,15 @@ impl From<PhantomData<LittleEndian>> for Endianess {
     }
 }
 
+impl From<Endianess> for Endian {
+    fn from(value: Endianess) -> Self {
+        match value {
+            Endianess::BigEndian => Endian::Big,
+            Endianess::LittleEndian => Endian::Little,
+        }
+    }
+}
+
 impl From<Endianess> for u8 {
     fn from(value: Endianess) -> Self {
         match value {
@@ -110,80 +214,661 @@ impl From<Endianess> for u8 {
     }
 }
 
-pub fn encode_geometry<W: Write>(
-    geometry: &Geometry,
+/// Encode une géométrie, possédée ou empruntée (géométrie typée, proxy, [GeometryRef]).
+pub fn encode_geometry<'a, G, W>(geometry: G, stream: &mut W) -> Result<(), std::io::Error>
+where
+    G: Into<GeometryRef<'a>>,
+    W: Write,
+{
+    encode_geometry_ref(geometry.into(), stream)
+}
+
+/// Taille exacte, en octets, de la géométrie encodée en EWKB.
+pub fn encoded_size<'a, G: Into<GeometryRef<'a>>>(geometry: G) -> usize {
+    let geometry = geometry.into();
+    let srid = if geometry.srid().is_some() { 4 } else { 0 };
+
+    // endianness, flags, SRID and coordinates, then the header of each member
+    1 + 4 + srid + coordinates_size(geometry.borrow_coordinates()) + 5 * nb_members(&geometry)
+}
+
+/// Nombre de membres d'une multi-géométrie, 0 pour une géométrie simple.
+fn nb_members(geometry: &GeometryRef<'_>) -> usize {
+    if !geometry.kind().is_multi() {
+        return 0;
+    }
+
+    match geometry.borrow_coordinates() {
+        CoordinatesRef::VectorArray2D(a) => a.len(),
+        CoordinatesRef::VectorMatrix2D(m) => m.len(),
+        CoordinatesRef::VectorTensor2D(t) => t.len(),
+        CoordinatesRef::VectorArray3D(a) => a.len(),
+        CoordinatesRef::VectorMatrix3D(m) => m.len(),
+        CoordinatesRef::VectorTensor3D(t) => t.len(),
+        CoordinatesRef::Vector2D(_) | CoordinatesRef::Vector3D(_) => 0,
+    }
+}
+
+/// Encode une géométrie empruntée, sans nécessiter de [Geometry] possédée.
+pub fn encode_geometry_ref<W: Write>(
+    geometry: GeometryRef<'_>,
     stream: &mut W,
 ) -> Result<(), std::io::Error> {
     encode_geometry_with_endianess::<NativeEndian, _>(geometry, stream)
 }
 
+/// Encode la géométrie dans le boutisme `E`.
+///
+/// Les anneaux sont écrits tels quels, comme par les liaisons sqlx et sea-query :
+/// voir [EWKBEncoder::with_orientation], ou [Geometry::oriented] avant la liaison.
 pub fn encode_geometry_with_endianess<E: ByteOrder, W: Write>(
-    geom: &Geometry,
+    geom: GeometryRef<'_>,
     stream: &mut W,
 ) -> Result<(), std::io::Error>
 where
     Endianess: From<PhantomData<E>>,
 {
+    encode_geometry_with_endian(Endianess::from(PhantomData::<E>).into(), geom, stream)
+}
+
+fn encode_geometry_with_endian<W: Write>(
+    endian: Endian,
+    geom: GeometryRef<'_>,
+    stream: &mut W,
+) -> Result<(), std::io::Error> {
+    trace_encode(
+        "ewkb",
+        geom,
+        || encoded_size(geom),
+        || encode_nested_geometry(endian, geom, Flags::from_geometry(&geom), stream),
+    )
+}
+
+/// Encode une géométrie empruntée en WKB, sans le SRID que les lecteurs WKB
+/// (GDAL, GEOS) ne comprennent pas.
+#[cfg(any(feature = "gdal", test))]
+pub(crate) fn encode_wkb<W: Write>(
+    geom: GeometryRef<'_>,
+    stream: &mut W,
+) -> Result<(), std::io::Error> {
+    let endian = Endianess::from(PhantomData::<NativeEndian>).into();
+    let flags = Flags {
+        kind: geom.kind(),
+        with_srid: false,
+    };
+
+    encode_nested_geometry(endian, geom, flags, stream)
+}
+
+fn encode_nested_geometry<W: Write>(
+    endian: Endian,
+    geom: GeometryRef<'_>,
+    flags: Flags,
+    stream: &mut W,
+) -> Result<(), std::io::Error> {
     // Write endianess.
-    stream.write_u8(Endianess::from(PhantomData::<E>).into())?;
+    encode_endianess(endian, stream)?;
 
     // Write the EWKB flags
-    let flags = Flags::from_geometry(geom);
-    encode_flags::<E, _>(&flags, stream)?;
+    encode_flags(endian, &flags, stream)?;
 
     // Write the SRID, if any
-    if let Some(srid) = geom.srid() {
-        stream.write_u32::<E>(srid)?;
+    if let Some(srid) = geom.srid().filter(|_| flags.with_srid) {
+        endian.write_u32(srid, stream)?;
     }
 
     // Encode the coordinate
-    encode_coordinates::<E, _>(geom.borrow_coordinates(), stream)
+    match flags.kind.single_of() {
+        Some(member) => encode_members(endian, member, geom.borrow_coordinates(), stream),
+        None => encode_coordinates(endian, geom.borrow_coordinates(), stream),
+    }
+}
+
+fn encode_endianess<W: Write>(endian: Endian, stream: &mut W) -> Result<(), std::io::Error> {
+    stream.write_u8(match endian {
+        Endian::Big => BIG_ENDIAN,
+        Endian::Little => LITTLE_ENDIAN,
+    })
+}
+
+/// Encode les membres d'une multi-géométrie, chacun précédé de son propre en-tête
+/// (boutisme et type, sans SRID) comme le veut le WKB.
+fn encode_members<W: Write>(
+    endian: Endian,
+    member: GeometryKind,
+    coordinates: CoordinatesRef<'_>,
+    stream: &mut W,
+) -> Result<(), std::io::Error> {
+    fn each<T, W: Write>(
+        endian: Endian,
+        member: GeometryKind,
+        members: &[T],
+        stream: &mut W,
+        encode: impl Fn(Endian, &T, &mut W) -> Result<(), std::io::Error>,
+    ) -> Result<(), std::io::Error> {
+        let flags = Flags {
+            kind: member,
+            with_srid: false,
+        };
+
+        endian.write_u32(members.len() as u32, stream)?;
+        members.iter().try_for_each(|coordinates| {
+            encode_endianess(endian, stream)?;
+            encode_flags(endian, &flags, stream)?;
+            encode(endian, coordinates, stream)
+        })
+    }
+
+    match coordinates {
+        CoordinatesRef::VectorArray2D(a) => each(endian, member, a, stream, encode_vector::<2, _>),
+        CoordinatesRef::VectorMatrix2D(m) => each(endian, member, m, stream, encode_array::<2, _>),
+        CoordinatesRef::VectorTensor2D(t) => each(endian, member, t, stream, encode_matrix::<2, _>),
+        CoordinatesRef::VectorArray3D(a) => each(endian, member, a, stream, encode_vector::<3, _>),
+        CoordinatesRef::VectorMatrix3D(m) => each(endian, member, m, stream, encode_array::<3, _>),
+        CoordinatesRef::VectorTensor3D(t) => each(endian, member, t, stream, encode_matrix::<3, _>),
+        coordinates => encode_coordinates(endian, coordinates, stream),
+    }
+}
+
+/// Encodeur EWKB réutilisable, pour encoder des lots de géométries.
+///
+/// Le tampon interne est conservé d'un encodage à l'autre : une fois dimensionné
+/// pour la plus grande géométrie du lot, il n'est plus réalloué.
+#[derive(Debug, Clone)]
+pub struct EWKBEncoder {
+    endian: Endian,
+    orientation: RingOrientation,
+    scratch: Vec<u8>,
+}
+
+impl Default for EWKBEncoder {
+    /// Encodeur dans le boutisme natif.
+    fn default() -> Self {
+        Self::new(Endianess::from(PhantomData::<NativeEndian>))
+    }
+}
+
+impl EWKBEncoder {
+    pub fn new(endianess: Endianess) -> Self {
+        Self {
+            endian: endianess.into(),
+            orientation: RingOrientation::Native,
+            scratch: Vec::default(),
+        }
+    }
+
+    /// Sens de parcours imposé aux anneaux des polygones encodés.
+    ///
+    /// Une géométrie dont un anneau doit être inversé est clonée avant l'encodage ;
+    /// les autres sont encodées sans copie.
+    pub fn with_orientation(mut self, orientation: RingOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Ajoute la géométrie encodée à la fin de `buf`.
+    pub fn encode_into<'a, G: Into<GeometryRef<'a>>>(
+        &self,
+        geometry: G,
+        buf: &mut Vec<u8>,
+    ) -> Result<(), std::io::Error> {
+        let geometry = geometry.into();
+        let oriented = geometry.to_oriented(self.orientation);
+        let geometry = oriented.as_ref().map_or(geometry, GeometryRef::from);
+
+        buf.reserve(encoded_size(geometry));
+        encode_geometry_with_endian(self.endian, geometry, buf)
+    }
+
+    /// Encode la géométrie dans le tampon interne, valable jusqu'au prochain encodage.
+    pub fn encode<'a, G: Into<GeometryRef<'a>>>(
+        &mut self,
+        geometry: G,
+    ) -> Result<&[u8], std::io::Error> {
+        let mut scratch = std::mem::take(&mut self.scratch);
+        scratch.clear();
+
+        let encoded = self.encode_into(geometry, &mut scratch);
+        self.scratch = scratch;
+        encoded?;
+
+        Ok(&self.scratch)
+    }
+}
+
+/// Encode un lot de géométries, chacune précédée de sa longueur en octets (`u32` petit-boutiste).
+///
+/// Le lot se relit avec [decode_all], pour transférer des géométries hors de la base de données.
+pub fn encode_all<'a, I, W>(geometries: I, stream: &mut W) -> Result<(), std::io::Error>
+where
+    I: IntoIterator<Item = &'a Geometry>,
+    W: Write,
+{
+    let mut encoder = EWKBEncoder::default();
+
+    geometries
+        .into_iter()
+        .try_for_each(|geometry| write_frame(encoder.encode(geometry)?, stream))
+}
+
+/// Décode les `count` géométries d'un lot écrit par [encode_all].
+///
+/// Le lot doit contenir exactement `count` géométries.
+pub fn decode_all(bytes: &[u8], count: usize) -> Result<Vec<Geometry>, Error> {
+    read_frames(bytes, count, |frame| {
+        EWKBGeometry::decode_from_slice(frame).map(Geometry::from)
+    })
+}
+
+/// Encode un lot de géométries en parallèle.
+#[cfg(feature = "rayon")]
+pub fn par_encode_geometries(geometries: &[Geometry]) -> Result<Vec<Vec<u8>>, std::io::Error> {
+    use rayon::prelude::*;
+
+    geometries
+        .par_iter()
+        .map(|geometry| {
+            let mut encoded = Vec::with_capacity(encoded_size(geometry));
+            encode_geometry(geometry, &mut encoded)?;
+            Ok(encoded)
+        })
+        .collect()
+}
+
+/// Décode un lot de géométries en parallèle.
+#[cfg(feature = "rayon")]
+pub fn par_decode_geometries<B>(encoded: &[B]) -> Result<Vec<Geometry>, Error>
+where
+    B: AsRef<[u8]> + Sync,
+{
+    use rayon::prelude::*;
+
+    encoded
+        .par_iter()
+        .map(|bytes| decode_geometry(&mut bytes.as_ref()))
+        .collect()
+}
+
+/// Décode une colonne Arrow de valeurs (E)WKB, telle que renvoyée par ADBC ou Flight SQL.
+///
+/// Les valeurs nulles sont conservées.
+#[cfg(feature = "arrow")]
+pub fn decode_arrow_array<O>(
+    array: &arrow_array::GenericBinaryArray<O>,
+) -> Result<Vec<Option<Geometry>>, Error>
+where
+    O: arrow_array::OffsetSizeTrait,
+{
+    array
+        .iter()
+        .map(|value| value.map(|bytes| decode_geometry(&mut &*bytes)).transpose())
+        .collect()
+}
+
+/// Encode un lot de géométries en une colonne Arrow de valeurs EWKB.
+#[cfg(feature = "arrow")]
+pub fn encode_arrow_array<'a, I>(geometries: I) -> Result<arrow_array::BinaryArray, std::io::Error>
+where
+    I: IntoIterator<Item = Option<&'a Geometry>>,
+{
+    let mut builder = arrow_array::builder::BinaryBuilder::new();
+    let mut encoder = EWKBEncoder::default();
+
+    for geometry in geometries {
+        match geometry {
+            Some(geometry) => builder.append_value(encoder.encode(geometry)?),
+            None => builder.append_null(),
+        }
+    }
+
+    Ok(builder.finish())
 }
 
-pub fn decode_geometry<R: Read>(stream: &mut R) -> Result<Geometry, std::io::Error> {
-    // start byte must be 0x00
-    assert_eq!(stream.read_u8()?, 0);
+/// Sérialise une géométrie en EWKB, à utiliser via `#[serde(with = "sql_gis::ewkb")]`.
+///
+/// Les formats lisibles (JSON, TOML, ...) reçoivent une chaîne hexadécimale,
+/// comme celle affichée par PostGIS ; les autres reçoivent les octets bruts.
+pub fn serialize<S>(geometry: &Geometry, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    let mut encoded = Vec::with_capacity(encoded_size(geometry));
+    encode_geometry(geometry, &mut encoded).map_err(serde::ser::Error::custom)?;
 
-    let endian = stream.read_u8()?;
+    if serializer.is_human_readable() {
+        serializer.serialize_str(&encode_hex(&encoded))
+    } else {
+        serializer.serialize_bytes(&encoded)
+    }
+}
 
-    if endian == BIG_ENDIAN {
-        decode_geometry_with_endianess::<BigEndian, _>(stream)
-    } else if endian == LITTLE_ENDIAN {
-        decode_geometry_with_endianess::<LittleEndian, _>(stream)
+/// Désérialise une géométrie (E)WKB, voir [serialize].
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Geometry, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(GeometryVisitor)
     } else {
-        panic!("wrong value for endianess")
+        deserializer.deserialize_bytes(GeometryVisitor)
     }
 }
 
-fn decode_geometry_with_endianess<E: ByteOrder, R: Read>(
+struct GeometryVisitor;
+
+impl<'de> serde::de::Visitor<'de> for GeometryVisitor {
+    type Value = Geometry;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("EWKB bytes or an hexadecimal EWKB string")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        let bytes = decode_hex(v).ok_or_else(|| E::custom("invalid hexadecimal string"))?;
+        self.visit_bytes(&bytes)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        EWKBGeometry::decode_from_slice(v)
+            .map(Geometry::from)
+            .map_err(E::custom)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+
+        self.visit_bytes(&bytes)
+    }
+}
+
+pub(crate) fn encode_hex(bytes: &[u8]) -> String {
+    use std::fmt::Write as _;
+
+    bytes.iter().fold(String::new(), |mut hex, byte| {
+        let _ = write!(hex, "{byte:02X}");
+        hex
+    })
+}
+
+pub(crate) fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+/// Décode une géométrie EWKB (ou WKB).
+///
+/// Un flux corrompu ou tronqué renvoie une erreur, sans jamais paniquer.
+/// Les options par défaut de [DecodeOptions] s'appliquent.
+pub fn decode_geometry<R: Read>(stream: &mut R) -> Result<Geometry, Error> {
+    decode_geometry_with(stream, &DecodeOptions::default())
+}
+
+/// Décode une géométrie EWKB (ou WKB) selon les options données.
+///
+/// Les erreurs de décodage sont situées dans le flux, voir [Error::Located].
+pub fn decode_geometry_with<R: Read>(
+    stream: &mut R,
+    options: &DecodeOptions,
+) -> Result<Geometry, Error> {
+    let mut stream = CountingReader::new(stream);
+    let mut budget = DecodeBudget::new(&options.limits);
+
+    let geometry = trace_decode(
+        "ewkb",
+        |geometry: &Geometry| (geometry.kind(), geometry.srid(), encoded_size(geometry)),
+        || {
+            decode_nested_geometry(&mut stream, &mut budget)
+                .map_err(|error| budget.locate(error, stream.offset()))
+        },
+    )?;
+
+    options.finish(geometry, None)
+}
+
+/// Décode une géométrie EWKB (ou WKB) en allouant ses anneaux et ses sommets dans l'arène.
+///
+/// En réutilisant la même [Bump], vidée par [Bump::reset] entre deux lots, décoder de
+/// nombreuses petites géométries ne coûte plus une allocation par anneau.
+/// Les limites par défaut de [DecodeOptions] s'appliquent.
+#[cfg(feature = "arena")]
+pub fn decode_geometry_in<'bump, R: Read>(
+    arena: &'bump Bump,
     stream: &mut R,
-) -> Result<Geometry, std::io::Error> {
-    let flags = decode_flags::<E, _>(stream)?;
+) -> Result<ArenaGeometry<'bump>, Error> {
+    let options = DecodeOptions::default();
+    let mut stream = CountingReader::new(stream);
+    let mut budget = DecodeBudget::new(&options.limits);
+
+    decode_arena_geometry(arena, &mut stream, &mut budget)
+        .map_err(|error| budget.locate(error, stream.offset()))
+}
+
+/// Décode uniquement l'en-tête EWKB (type et SRID), sans lire les coordonnées.
+///
+/// Permet d'aiguiller une géométrie selon son type ou son SRID sans la décoder.
+pub fn decode_header<R: Read>(stream: &mut R) -> Result<(GeometryKind, Option<u32>), Error> {
+    let endian = decode_endianess(stream)?;
+    decode_header_with_endianess(endian, stream)
+}
+
+/// Lit le type d'une géométrie EWKB, à partir des seuls octets d'en-tête.
+pub fn peek_kind(bytes: &[u8]) -> Result<GeometryKind, Error> {
+    decode_header(&mut &bytes[..]).map(|(kind, _)| kind)
+}
+
+/// Lit le SRID d'une géométrie EWKB, à partir des seuls octets d'en-tête.
+pub fn peek_srid(bytes: &[u8]) -> Result<Option<u32>, Error> {
+    decode_header(&mut &bytes[..]).map(|(_, srid)| srid)
+}
+
+fn decode_endianess<R: Read>(stream: &mut R) -> Result<Endian, Error> {
+    match stream.read_u8()? {
+        BIG_ENDIAN => Ok(Endian::Big),
+        LITTLE_ENDIAN => Ok(Endian::Little),
+        endian => Err(Error::InvalidByteOrder(endian)),
+    }
+}
+
+fn decode_header_with_endianess<R: Read>(
+    endian: Endian,
+    stream: &mut R,
+) -> Result<(GeometryKind, Option<u32>), Error> {
+    let flags = decode_flags(endian, stream)?;
+    let srid = if flags.with_srid {
+        Some(endian.read_u32(stream)?)
+    } else {
+        None
+    };
+
+    Ok((flags.kind, srid))
+}
+
+/// Décode au fil de l'eau les sommets d'une ligne brisée EWKB (ou WKB).
+///
+/// Les sommets sont lus à la demande : calculer la longueur ou l'emprise d'une trace
+/// de plusieurs millions de points ne nécessite pas de la charger en mémoire.
+/// La première erreur (en-tête invalide, géométrie autre qu'une `LineString`, flux tronqué)
+/// termine l'itération.
+pub fn decode_vertices<R: Read>(stream: R) -> impl Iterator<Item = Result<Vector<2, f64>, Error>> {
+    VertexDecoder {
+        stream,
+        header: None,
+        done: false,
+    }
+}
+
+struct VertexDecoder<R> {
+    stream: R,
+    /// Boutisme et nombre de sommets restant à lire.
+    header: Option<(Endian, u32)>,
+    done: bool,
+}
+
+impl<R: Read> VertexDecoder<R> {
+    fn read_header(&mut self) -> Result<(Endian, u32), Error> {
+        let endian = decode_endianess(&mut self.stream)?;
+        let (kind, _) = decode_header_with_endianess(endian, &mut self.stream)?;
+
+        if kind != GeometryKind::LineString {
+            return Err(Error::invalid_geometry_kind(GeometryKind::LineString, kind));
+        }
+
+        let nb_points = endian.read_u32(&mut self.stream)?;
+        Ok((endian, nb_points))
+    }
+}
+
+impl<R: Read> Iterator for VertexDecoder<R> {
+    type Item = Result<Vector<2, f64>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let (endian, remaining) = match self.header {
+            Some(header) => header,
+            None => match self.read_header() {
+                Ok(header) => header,
+                Err(error) => {
+                    self.done = true;
+                    return Some(Err(error));
+                }
+            },
+        };
+
+        if remaining == 0 {
+            self.done = true;
+            return None;
+        }
+
+        self.header = Some((endian, remaining - 1));
+
+        let vertex = read_vector::<2, _>(endian, &mut self.stream);
+        self.done = vertex.is_err();
+        Some(vertex)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.header {
+            _ if self.done => (0, Some(0)),
+            Some((_, remaining)) => (0, Some(remaining as usize)),
+            None => (0, None),
+        }
+    }
+}
+
+fn decode_nested_geometry<R: Read>(
+    stream: &mut R,
+    budget: &mut DecodeBudget<'_>,
+) -> Result<Geometry, Error> {
+    budget.enter()?;
+    budget.bytes(1)?;
+    let endian = decode_endianess(stream)?;
+    let geom = decode_geometry_with_endianess(endian, stream, budget)?;
+
+    budget.leave();
+    Ok(geom)
+}
+
+fn decode_geometry_with_endianess<R: Read>(
+    endian: Endian,
+    stream: &mut R,
+    budget: &mut DecodeBudget<'_>,
+) -> Result<Geometry, Error> {
+    budget.bytes(4)?;
+    let flags = decode_flags(endian, stream)?;
     let srid: Option<u32> = if flags.with_srid {
-        Some(stream.read_u32::<E>()?)
+        budget.bytes(4)?;
+        Some(endian.read_u32(stream)?)
     } else {
         None
     };
 
+    budget.set_kind(flags.kind);
+
     let mut geom: Geometry = match flags.kind {
-        GeometryKind::Point => Point::new(decode_vector::<2, E, _>(stream)?).into(),
-        GeometryKind::LineString => LineString::new(decode_array::<2, E, _>(stream)?).into(),
-        GeometryKind::Polygon => Polygon::new(decode_matrix::<2, E, _>(stream)?).into(),
-        GeometryKind::MultiPoint => MultiPoint::new(decode_array::<2, E, _>(stream)?).into(),
-        GeometryKind::MultiLineString => {
-            MultiLineString::new(decode_matrix::<2, E, _>(stream)?).into()
-        }
-        GeometryKind::MultiPolygon => MultiPolygon::new(decode_tensor::<2, E, _>(stream)?).into(),
-        GeometryKind::GeometryCollection => todo!(),
-        GeometryKind::PointZ => PointZ::new(decode_vector::<3, E, _>(stream)?).into(),
-        GeometryKind::LineStringZ => LineStringZ::new(decode_array::<3, E, _>(stream)?).into(),
-        GeometryKind::PolygonZ => PolygonZ::new(decode_matrix::<3, E, _>(stream)?).into(),
-        GeometryKind::MultiPointZ => MultiPointZ::new(decode_array::<3, E, _>(stream)?).into(),
-        GeometryKind::MultiLineStringZ => {
-            MultiLineStringZ::new(decode_matrix::<3, E, _>(stream)?).into()
+        GeometryKind::Point => Point::new(decode_vector::<2, _>(endian, stream, budget)?).into(),
+        GeometryKind::LineString => {
+            LineString::new(decode_array::<2, _>(endian, stream, budget)?).into()
+        }
+        GeometryKind::Polygon => {
+            Polygon::new(decode_matrix::<2, _>(endian, stream, budget)?).into()
+        }
+        GeometryKind::MultiPoint => MultiPoint::new(decode_multi_point::<2, _>(
+            endian,
+            GeometryKind::Point,
+            stream,
+            budget,
+        )?)
+        .into(),
+        GeometryKind::MultiLineString => MultiLineString::new(decode_multi_line_string::<2, _>(
+            endian,
+            GeometryKind::LineString,
+            stream,
+            budget,
+        )?)
+        .into(),
+        GeometryKind::MultiPolygon => MultiPolygon::new(decode_multi_polygon::<2, _>(
+            endian,
+            GeometryKind::Polygon,
+            stream,
+            budget,
+        )?)
+        .into(),
+        kind @ (GeometryKind::GeometryCollection | GeometryKind::GeometryCollectionZ) => {
+            return Err(Error::unsupported_geometry(
+                kind,
+                "geometry collections are not supported",
+            ))
         }
-        GeometryKind::MultiPolygonZ => MultiPolygonZ::new(decode_tensor::<3, E, _>(stream)?).into(),
-        GeometryKind::GeometryCollectionZ => todo!(),
+        GeometryKind::PointZ => PointZ::new(decode_vector::<3, _>(endian, stream, budget)?).into(),
+        GeometryKind::LineStringZ => {
+            LineStringZ::new(decode_array::<3, _>(endian, stream, budget)?).into()
+        }
+        GeometryKind::PolygonZ => {
+            PolygonZ::new(decode_matrix::<3, _>(endian, stream, budget)?).into()
+        }
+        GeometryKind::MultiPointZ => MultiPointZ::new(decode_multi_point::<3, _>(
+            endian,
+            GeometryKind::PointZ,
+            stream,
+            budget,
+        )?)
+        .into(),
+        GeometryKind::MultiLineStringZ => MultiLineStringZ::new(decode_multi_line_string::<3, _>(
+            endian,
+            GeometryKind::LineStringZ,
+            stream,
+            budget,
+        )?)
+        .into(),
+        GeometryKind::MultiPolygonZ => MultiPolygonZ::new(decode_multi_polygon::<3, _>(
+            endian,
+            GeometryKind::PolygonZ,
+            stream,
+            budget,
+        )?)
+        .into(),
     };
 
     geom.set_srid(srid);
@@ -191,6 +876,116 @@ fn decode_geometry_with_endianess<E: ByteOrder, R: Read>(
     Ok(geom)
 }
 
+#[cfg(feature = "arena")]
+fn decode_arena_geometry<'bump, R: Read>(
+    arena: &'bump Bump,
+    stream: &mut R,
+    budget: &mut DecodeBudget<'_>,
+) -> Result<ArenaGeometry<'bump>, Error> {
+    budget.enter()?;
+    budget.bytes(1)?;
+
+    let endian = decode_endianess(stream)?;
+    let geom = decode_arena_geometry_with_endianess(endian, arena, stream, budget)?;
+
+    budget.leave();
+    Ok(geom)
+}
+
+#[cfg(feature = "arena")]
+fn decode_arena_geometry_with_endianess<'bump, R: Read>(
+    endian: Endian,
+    arena: &'bump Bump,
+    stream: &mut R,
+    budget: &mut DecodeBudget<'_>,
+) -> Result<ArenaGeometry<'bump>, Error> {
+    budget.bytes(4)?;
+    let flags = decode_flags(endian, stream)?;
+    let srid: Option<u32> = if flags.with_srid {
+        budget.bytes(4)?;
+        Some(endian.read_u32(stream)?)
+    } else {
+        None
+    };
+
+    budget.set_kind(flags.kind);
+
+    let coordinates = match flags.kind {
+        GeometryKind::Point => {
+            ArenaCoordinates::Vector2D(arena.alloc(decode_vector::<2, _>(endian, stream, budget)?))
+        }
+        GeometryKind::LineString => {
+            ArenaCoordinates::VectorArray2D(decode_array_in::<2, _>(endian, arena, stream, budget)?)
+        }
+        GeometryKind::Polygon => ArenaCoordinates::VectorMatrix2D(decode_matrix_in::<2, _>(
+            endian, arena, stream, budget,
+        )?),
+        GeometryKind::MultiPoint => ArenaCoordinates::VectorArray2D(decode_multi_point_in::<2, _>(
+            endian,
+            GeometryKind::Point,
+            arena,
+            stream,
+            budget,
+        )?),
+        GeometryKind::MultiLineString => {
+            ArenaCoordinates::VectorMatrix2D(decode_multi_line_string_in::<2, _>(
+                endian,
+                GeometryKind::LineString,
+                arena,
+                stream,
+                budget,
+            )?)
+        }
+        GeometryKind::MultiPolygon => {
+            ArenaCoordinates::VectorTensor2D(decode_multi_polygon_in::<2, _>(
+                endian,
+                GeometryKind::Polygon,
+                arena,
+                stream,
+                budget,
+            )?)
+        }
+        kind @ (GeometryKind::GeometryCollection | GeometryKind::GeometryCollectionZ) => {
+            return Err(Error::unsupported_geometry(
+                kind,
+                "geometry collections are not supported",
+            ))
+        }
+        GeometryKind::PointZ => {
+            ArenaCoordinates::Vector3D(arena.alloc(decode_vector::<3, _>(endian, stream, budget)?))
+        }
+        GeometryKind::LineStringZ => {
+            ArenaCoordinates::VectorArray3D(decode_array_in::<3, _>(endian, arena, stream, budget)?)
+        }
+        GeometryKind::PolygonZ => ArenaCoordinates::VectorMatrix3D(decode_matrix_in::<3, _>(
+            endian, arena, stream, budget,
+        )?),
+        GeometryKind::MultiPointZ => ArenaCoordinates::VectorArray3D(
+            decode_multi_point_in::<3, _>(endian, GeometryKind::PointZ, arena, stream, budget)?,
+        ),
+        GeometryKind::MultiLineStringZ => {
+            ArenaCoordinates::VectorMatrix3D(decode_multi_line_string_in::<3, _>(
+                endian,
+                GeometryKind::LineStringZ,
+                arena,
+                stream,
+                budget,
+            )?)
+        }
+        GeometryKind::MultiPolygonZ => {
+            ArenaCoordinates::VectorTensor3D(decode_multi_polygon_in::<3, _>(
+                endian,
+                GeometryKind::PolygonZ,
+                arena,
+                stream,
+                budget,
+            )?)
+        }
+    };
+
+    Ok(ArenaGeometry::new(flags.kind, srid, coordinates))
+}
+
 /// The flags for the EWKB codec.
 /// Source: [https://github.com/postgis/postgis/blob/master/doc/ZMSgeoms.txt]
 struct Flags {
@@ -199,7 +994,7 @@ struct Flags {
 }
 
 impl Flags {
-    pub fn from_geometry(geom: &Geometry) -> Self {
+    pub fn from_geometry(geom: &GeometryRef<'_>) -> Self {
         Self {
             kind: geom.kind(),
             with_srid: geom.srid().is_some(),
@@ -209,171 +1004,368 @@ impl Flags {
 
 const WITH_SRID_MASK: u32 = 0x20000000;
 
-fn decode_flags<E: ByteOrder, R: Read>(stream: &mut R) -> Result<Flags, std::io::Error> {
-    let encoded = stream.read_u32::<E>()?;
+fn decode_flags<R: Read>(endian: Endian, stream: &mut R) -> Result<Flags, Error> {
+    let encoded = endian.read_u32(stream)?;
 
     let with_srid = (encoded & WITH_SRID_MASK) == WITH_SRID_MASK;
 
-    let kind = match encoded & !WITH_SRID_MASK {
-        1 => GeometryKind::Point,
-        2 => GeometryKind::LineString,
-        3 => GeometryKind::Polygon,
-        4 => GeometryKind::MultiPoint,
-        5 => GeometryKind::MultiLineString,
-        6 => GeometryKind::MultiPolygon,
-        7 => GeometryKind::GeometryCollection,
-
-        0x80000001 => GeometryKind::PointZ,
-        0x80000002 => GeometryKind::LineStringZ,
-        0x80000003 => GeometryKind::PolygonZ,
-        0x80000004 => GeometryKind::MultiPointZ,
-        0x80000005 => GeometryKind::MultiLineStringZ,
-        0x80000006 => GeometryKind::MultiPolygonZ,
-        0x80000007 => GeometryKind::GeometryCollectionZ,
-
-        _ => panic!("unhandled geometry class"),
-    };
+    let code = encoded & !WITH_SRID_MASK;
+    let kind = GeometryKind::from_ewkb_code(code).ok_or(Error::UnknownGeometryCode(code))?;
 
     Ok(Flags { kind, with_srid })
 }
 
-fn encode_flags<E: ByteOrder, W: Write>(
+fn encode_flags<W: Write>(
+    endian: Endian,
     flags: &Flags,
     stream: &mut W,
 ) -> Result<(), std::io::Error> {
-    let mut encoded = match flags.kind {
-        GeometryKind::Point => 1,
-        GeometryKind::LineString => 2,
-        GeometryKind::Polygon => 3,
-        GeometryKind::MultiPoint => 4,
-        GeometryKind::MultiLineString => 5,
-        GeometryKind::MultiPolygon => 6,
-        GeometryKind::GeometryCollection => 7,
-
-        GeometryKind::PointZ => 0x80000001,
-        GeometryKind::LineStringZ => 0x80000002,
-        GeometryKind::PolygonZ => 0x80000003,
-        GeometryKind::MultiPointZ => 0x80000004,
-        GeometryKind::MultiLineStringZ => 0x80000005,
-        GeometryKind::MultiPolygonZ => 0x80000006,
-        GeometryKind::GeometryCollectionZ => 0x80000007,
-    };
+    let mut encoded = flags.kind.ewkb_code();
 
     encoded |= if flags.with_srid { WITH_SRID_MASK } else { 0 };
 
-    stream.write_u32::<E>(encoded)
+    endian.write_u32(encoded, stream)
 }
 
-fn encode_coordinates<E: ByteOrder, W: Write>(
+fn encode_coordinates<W: Write>(
+    endian: Endian,
     coordinates: CoordinatesRef<'_>,
     stream: &mut W,
 ) -> Result<(), std::io::Error> {
     match coordinates {
-        CoordinatesRef::Vector2D(vector) => encode_vector::<2, E, _>(vector, stream),
-        CoordinatesRef::VectorArray2D(array) => encode_array::<2, E, _>(array, stream),
-        CoordinatesRef::VectorMatrix2D(matrix) => encode_matrix::<2, E, _>(matrix, stream),
-        CoordinatesRef::VectorTensor2D(tensor) => encode_tensor::<2, E, _>(tensor, stream),
-        CoordinatesRef::Vector3D(vector) => encode_vector::<3, E, _>(vector, stream),
-        CoordinatesRef::VectorArray3D(array) => encode_array::<3, E, _>(array, stream),
-        CoordinatesRef::VectorMatrix3D(matrix) => encode_matrix::<3, E, _>(matrix, stream),
-        CoordinatesRef::VectorTensor3D(tensor) => encode_tensor::<3, E, _>(tensor, stream),
+        CoordinatesRef::Vector2D(vector) => encode_vector::<2, _>(endian, vector, stream),
+        CoordinatesRef::VectorArray2D(array) => encode_array::<2, _>(endian, array, stream),
+        CoordinatesRef::VectorMatrix2D(matrix) => encode_matrix::<2, _>(endian, matrix, stream),
+        CoordinatesRef::VectorTensor2D(tensor) => encode_tensor::<2, _>(endian, tensor, stream),
+        CoordinatesRef::Vector3D(vector) => encode_vector::<3, _>(endian, vector, stream),
+        CoordinatesRef::VectorArray3D(array) => encode_array::<3, _>(endian, array, stream),
+        CoordinatesRef::VectorMatrix3D(matrix) => encode_matrix::<3, _>(endian, matrix, stream),
+        CoordinatesRef::VectorTensor3D(tensor) => encode_tensor::<3, _>(endian, tensor, stream),
     }
 }
 
-fn encode_vector<const N: usize, E: ByteOrder, W: Write>(
+fn encode_vector<const N: usize, W: Write>(
+    endian: Endian,
     vector: &Vector<N, f64>,
     stream: &mut W,
 ) -> Result<(), std::io::Error> {
-    vector
-        .iter()
-        .copied()
-        .try_for_each(|scalar| stream.write_f64::<E>(scalar))
+    endian.write_f64s(vector, stream)
 }
 
-fn decode_vector<const N: usize, E: ByteOrder, R: Read>(
+fn decode_vector<const N: usize, R: Read>(
+    endian: Endian,
     stream: &mut R,
-) -> Result<Vector<N, f64>, std::io::Error> {
-    let mut scalars: [f64; N] = [0f64; N];
-
-    for i in 0..N {
-        scalars[i] = stream.read_f64::<E>()?;
-    }
+    budget: &mut DecodeBudget<'_>,
+) -> Result<Vector<N, f64>, Error> {
+    budget.vertices::<N>(1)?;
+    read_vector::<N, _>(endian, stream)
+}
 
-    Ok(Vector::new(scalars))
+fn read_vector<const N: usize, R: Read>(
+    endian: Endian,
+    stream: &mut R,
+) -> Result<Vector<N, f64>, Error> {
+    Ok(Vector::new(endian.read_f64s(stream)?))
 }
 
-fn encode_array<const N: usize, E: ByteOrder, W: Write>(
+fn encode_array<const N: usize, W: Write>(
+    endian: Endian,
     array: &VectorArray<N, f64>,
     stream: &mut W,
 ) -> Result<(), std::io::Error> {
-    stream.write_u32::<E>(array.len() as u32)?;
+    endian.write_u32(array.len() as u32, stream)?;
     array
         .iter()
-        .try_for_each(|vector| encode_vector::<N, E, _>(vector, stream))
+        .try_for_each(|vector| encode_vector::<N, _>(endian, vector, stream))
 }
 
-fn decode_array<const N: usize, E: ByteOrder, R: Read>(
+fn decode_array<const N: usize, R: Read>(
+    endian: Endian,
     stream: &mut R,
-) -> Result<VectorArray<N, f64>, std::io::Error> {
-    let nb_points: u32 = stream.read_u32::<E>()?;
-    let mut coordinates = Vec::<Vector<N, f64>>::with_capacity(nb_points as usize);
-
-    for _ in 0..nb_points {
-        coordinates.push(decode_vector::<N, E, _>(stream)?);
+    budget: &mut DecodeBudget<'_>,
+) -> Result<VectorArray<N, f64>, Error> {
+    budget.bytes(4)?;
+    let nb_points = budget.vertices::<N>(endian.read_u32(stream)?)?;
+    let mut coordinates = Vec::<Vector<N, f64>>::with_capacity(budget.capacity(nb_points));
+
+    budget.enter_part();
+    for i in 0..nb_points {
+        budget.at_part(i);
+        coordinates.push(read_vector::<N, _>(endian, stream)?);
     }
+    budget.leave_part();
 
     Ok(VectorArray::new(coordinates))
 }
 
-fn encode_matrix<const N: usize, E: ByteOrder, W: Write>(
+fn encode_matrix<const N: usize, W: Write>(
+    endian: Endian,
     matrix: &VectorMatrix<N, f64>,
     stream: &mut W,
 ) -> Result<(), std::io::Error> {
-    stream.write_u32::<E>(matrix.len() as u32)?;
+    endian.write_u32(matrix.len() as u32, stream)?;
     matrix
         .iter()
-        .try_for_each(|array| encode_array::<N, E, _>(array, stream))
+        .try_for_each(|array| encode_array::<N, _>(endian, array, stream))
 }
 
-fn decode_matrix<const N: usize, E: ByteOrder, R: Read>(
+fn decode_matrix<const N: usize, R: Read>(
+    endian: Endian,
     stream: &mut R,
-) -> Result<VectorMatrix<N, f64>, std::io::Error> {
-    let nb_points: u32 = stream.read_u32::<E>()?;
-    let mut coordinates = Vec::<VectorArray<N, f64>>::with_capacity(nb_points as usize);
-
-    for _ in 0..nb_points {
-        coordinates.push(decode_array::<N, E, _>(stream)?);
+    budget: &mut DecodeBudget<'_>,
+) -> Result<VectorMatrix<N, f64>, Error> {
+    budget.bytes(4)?;
+    let nb_parts = budget.rings(endian.read_u32(stream)?)?;
+    let mut coordinates = Vec::<VectorArray<N, f64>>::with_capacity(budget.capacity(nb_parts));
+
+    budget.enter_part();
+    for i in 0..nb_parts {
+        budget.at_part(i);
+        coordinates.push(decode_array::<N, _>(endian, stream, budget)?);
     }
+    budget.leave_part();
 
     Ok(VectorMatrix::new(coordinates))
 }
 
-fn encode_tensor<const N: usize, E: ByteOrder, W: Write>(
+fn encode_tensor<const N: usize, W: Write>(
+    endian: Endian,
     tensor: &VectorTensor<N, f64>,
     stream: &mut W,
 ) -> Result<(), std::io::Error> {
-    stream.write_u32::<E>(tensor.len() as u32)?;
+    endian.write_u32(tensor.len() as u32, stream)?;
     tensor
         .iter()
-        .try_for_each(|matrix| encode_matrix::<N, E, _>(matrix, stream))
+        .try_for_each(|matrix| encode_matrix::<N, _>(endian, matrix, stream))
 }
 
-fn decode_tensor<const N: usize, E: ByteOrder, R: Read>(
+/// Lit les membres d'une multi-géométrie, chacun précédé de son propre en-tête.
+///
+/// Chaque membre a son propre boutisme et doit être du type `member` ; un SRID
+/// porté par un membre est lu puis ignoré, seul celui de la multi-géométrie compte.
+fn decode_members<R: Read>(
+    member: GeometryKind,
+    count: usize,
     stream: &mut R,
-) -> Result<VectorTensor<N, f64>, std::io::Error> {
-    let nb_points: u32 = stream.read_u32::<E>()?;
-    let mut coordinates = Vec::<VectorMatrix<N, f64>>::with_capacity(nb_points as usize);
+    budget: &mut DecodeBudget<'_>,
+    mut decode: impl FnMut(Endian, &mut R, &mut DecodeBudget<'_>) -> Result<(), Error>,
+) -> Result<(), Error> {
+    budget.enter_part();
+    for i in 0..count {
+        budget.at_part(i);
+        budget.enter()?;
+        budget.bytes(5)?;
+        let endian = decode_endianess(stream)?;
+        let flags = decode_flags(endian, stream)?;
+
+        if flags.kind != member {
+            return Err(Error::invalid_geometry_kind(member, flags.kind));
+        }
 
-    for _ in 0..nb_points {
-        coordinates.push(decode_matrix::<N, E, _>(stream)?);
+        if flags.with_srid {
+            budget.bytes(4)?;
+            endian.read_u32(stream)?;
+        }
+
+        decode(endian, stream, budget)?;
+        budget.leave();
     }
+    budget.leave_part();
+
+    Ok(())
+}
+
+fn decode_multi_point<const N: usize, R: Read>(
+    endian: Endian,
+    member: GeometryKind,
+    stream: &mut R,
+    budget: &mut DecodeBudget<'_>,
+) -> Result<VectorArray<N, f64>, Error> {
+    budget.bytes(4)?;
+    let nb_points = budget.vertices::<N>(endian.read_u32(stream)?)?;
+    let mut coordinates = Vec::<Vector<N, f64>>::with_capacity(budget.capacity(nb_points));
+
+    decode_members(member, nb_points, stream, budget, |endian, stream, _| {
+        coordinates.push(read_vector::<N, _>(endian, stream)?);
+        Ok(())
+    })?;
+
+    Ok(VectorArray::new(coordinates))
+}
+
+fn decode_multi_line_string<const N: usize, R: Read>(
+    endian: Endian,
+    member: GeometryKind,
+    stream: &mut R,
+    budget: &mut DecodeBudget<'_>,
+) -> Result<VectorMatrix<N, f64>, Error> {
+    budget.bytes(4)?;
+    let nb_parts = budget.rings(endian.read_u32(stream)?)?;
+    let mut coordinates = Vec::<VectorArray<N, f64>>::with_capacity(budget.capacity(nb_parts));
+
+    decode_members(
+        member,
+        nb_parts,
+        stream,
+        budget,
+        |endian, stream, budget| {
+            coordinates.push(decode_array::<N, _>(endian, stream, budget)?);
+            Ok(())
+        },
+    )?;
+
+    Ok(VectorMatrix::new(coordinates))
+}
+
+fn decode_multi_polygon<const N: usize, R: Read>(
+    endian: Endian,
+    member: GeometryKind,
+    stream: &mut R,
+    budget: &mut DecodeBudget<'_>,
+) -> Result<VectorTensor<N, f64>, Error> {
+    budget.bytes(4)?;
+    let nb_parts = budget.rings(endian.read_u32(stream)?)?;
+    let mut coordinates = Vec::<VectorMatrix<N, f64>>::with_capacity(budget.capacity(nb_parts));
+
+    decode_members(
+        member,
+        nb_parts,
+        stream,
+        budget,
+        |endian, stream, budget| {
+            coordinates.push(decode_matrix::<N, _>(endian, stream, budget)?);
+            Ok(())
+        },
+    )?;
 
     Ok(VectorTensor::new(coordinates))
 }
 
+#[cfg(feature = "arena")]
+fn decode_array_in<'bump, const N: usize, R: Read>(
+    endian: Endian,
+    arena: &'bump Bump,
+    stream: &mut R,
+    budget: &mut DecodeBudget<'_>,
+) -> Result<&'bump [Vector<N, f64>], Error> {
+    budget.bytes(4)?;
+    let nb_points = budget.vertices::<N>(endian.read_u32(stream)?)?;
+    let mut coordinates =
+        bumpalo::collections::Vec::with_capacity_in(budget.capacity(nb_points), arena);
+
+    budget.enter_part();
+    for i in 0..nb_points {
+        budget.at_part(i);
+        coordinates.push(read_vector::<N, _>(endian, stream)?);
+    }
+    budget.leave_part();
+
+    Ok(coordinates.into_bump_slice())
+}
+
+#[cfg(feature = "arena")]
+fn decode_matrix_in<'bump, const N: usize, R: Read>(
+    endian: Endian,
+    arena: &'bump Bump,
+    stream: &mut R,
+    budget: &mut DecodeBudget<'_>,
+) -> Result<&'bump [&'bump [Vector<N, f64>]], Error> {
+    budget.bytes(4)?;
+    let nb_parts = budget.rings(endian.read_u32(stream)?)?;
+    let mut coordinates =
+        bumpalo::collections::Vec::with_capacity_in(budget.capacity(nb_parts), arena);
+
+    budget.enter_part();
+    for i in 0..nb_parts {
+        budget.at_part(i);
+        coordinates.push(decode_array_in::<N, _>(endian, arena, stream, budget)?);
+    }
+    budget.leave_part();
+
+    Ok(coordinates.into_bump_slice())
+}
+
+#[cfg(feature = "arena")]
+fn decode_multi_point_in<'bump, const N: usize, R: Read>(
+    endian: Endian,
+    member: GeometryKind,
+    arena: &'bump Bump,
+    stream: &mut R,
+    budget: &mut DecodeBudget<'_>,
+) -> Result<&'bump [Vector<N, f64>], Error> {
+    budget.bytes(4)?;
+    let nb_points = budget.vertices::<N>(endian.read_u32(stream)?)?;
+    let mut coordinates =
+        bumpalo::collections::Vec::with_capacity_in(budget.capacity(nb_points), arena);
+
+    decode_members(member, nb_points, stream, budget, |endian, stream, _| {
+        coordinates.push(read_vector::<N, _>(endian, stream)?);
+        Ok(())
+    })?;
+
+    Ok(coordinates.into_bump_slice())
+}
+
+#[cfg(feature = "arena")]
+fn decode_multi_line_string_in<'bump, const N: usize, R: Read>(
+    endian: Endian,
+    member: GeometryKind,
+    arena: &'bump Bump,
+    stream: &mut R,
+    budget: &mut DecodeBudget<'_>,
+) -> Result<&'bump [&'bump [Vector<N, f64>]], Error> {
+    budget.bytes(4)?;
+    let nb_parts = budget.rings(endian.read_u32(stream)?)?;
+    let mut coordinates =
+        bumpalo::collections::Vec::with_capacity_in(budget.capacity(nb_parts), arena);
+
+    decode_members(
+        member,
+        nb_parts,
+        stream,
+        budget,
+        |endian, stream, budget| {
+            coordinates.push(decode_array_in::<N, _>(endian, arena, stream, budget)?);
+            Ok(())
+        },
+    )?;
+
+    Ok(coordinates.into_bump_slice())
+}
+
+#[cfg(feature = "arena")]
+fn decode_multi_polygon_in<'bump, const N: usize, R: Read>(
+    endian: Endian,
+    member: GeometryKind,
+    arena: &'bump Bump,
+    stream: &mut R,
+    budget: &mut DecodeBudget<'_>,
+) -> Result<&'bump [&'bump [&'bump [Vector<N, f64>]]], Error> {
+    budget.bytes(4)?;
+    let nb_parts = budget.rings(endian.read_u32(stream)?)?;
+    let mut coordinates =
+        bumpalo::collections::Vec::with_capacity_in(budget.capacity(nb_parts), arena);
+
+    decode_members(
+        member,
+        nb_parts,
+        stream,
+        budget,
+        |endian, stream, budget| {
+            coordinates.push(decode_matrix_in::<N, _>(endian, arena, stream, budget)?);
+            Ok(())
+        },
+    )?;
+
+    Ok(coordinates.into_bump_slice())
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::types::GeometryImpl;
+    use crate::{
+        io::{DecodeLimits, SridPolicy},
+        types::GeometryImpl,
+    };
 
     use super::*;
 
@@ -384,4 +1376,708 @@ mod tests {
         let value = EWKBGeometry::decode_from_slice(&bytes).expect("cannot decode from stream");
         assert_eq!(value, expected)
     }
+
+    #[test]
+    pub fn test_decode_concatenated_stream() {
+        let geometries = [
+            EWKBGeometry::new(Point::new([1.0, 2.0])),
+            EWKBGeometry::new(LineString::new([[0.0, 0.0], [1.0, 1.0]])),
+        ];
+
+        let mut bytes = Vec::default();
+        for geometry in &geometries {
+            geometry.encode(&mut bytes).expect("cannot encode geometry");
+        }
+
+        let (first, consumed) =
+            EWKBGeometry::decode_partial(&bytes).expect("cannot decode first geometry");
+        let (second, rest) = EWKBGeometry::decode_partial(&bytes[consumed..])
+            .expect("cannot decode second geometry");
+
+        assert_eq!([first, second], geometries);
+        assert_eq!(consumed + rest, bytes.len());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    pub fn test_parallel_batch() {
+        let geometries = (0..16)
+            .map(|i| Geometry::from(Point::new([i as f64, 2.0 * i as f64])))
+            .collect::<Vec<_>>();
+
+        let encoded = par_encode_geometries(&geometries).expect("cannot encode geometries");
+        let decoded = par_decode_geometries(&encoded).expect("cannot decode geometries");
+
+        assert_eq!(decoded, geometries);
+    }
+
+    #[cfg(feature = "arrow")]
+    #[test]
+    pub fn test_arrow_array() {
+        let geometries = [
+            Some(Geometry::from(Point::new([1.0, 2.0]))),
+            None,
+            Some(LineStringZ::new([[0.0, 0.0, 0.0], [1.0, 1.0, 1.0]]).into()),
+        ];
+
+        let array = encode_arrow_array(geometries.iter().map(Option::as_ref))
+            .expect("cannot encode geometries");
+        let decoded = decode_arrow_array(&array).expect("cannot decode geometries");
+
+        assert_eq!(decoded, geometries);
+    }
+
+    /// Colonne WKB écrite par un autre producteur (GDAL, GeoParquet).
+    #[cfg(feature = "arrow")]
+    #[test]
+    pub fn test_arrow_standard_wkb() {
+        // MULTIPOINT((1 2),(3 4)) en NDR, MULTILINESTRING((0 0,1 1)) en XDR.
+        let multi_point = decode_hex(
+            "0104000000020000000101000000000000000000F03F00000000000000400101000000000000\
+             00000008400000000000001040",
+        )
+        .unwrap();
+        let multi_line_string = decode_hex(
+            "000000000500000001000000000200000002000000000000000000000000000000003FF00000\
+             000000003FF0000000000000",
+        )
+        .unwrap();
+        let array = arrow_array::BinaryArray::from(vec![
+            Some(multi_point.as_slice()),
+            None,
+            Some(multi_line_string.as_slice()),
+        ]);
+
+        let decoded = decode_arrow_array(&array).expect("cannot decode standard WKB");
+        assert_eq!(
+            decoded,
+            [
+                Some(MultiPoint::new([[1.0, 2.0], [3.0, 4.0]]).into()),
+                None,
+                Some(
+                    MultiLineString::new(
+                        [vec![[0.0, 0.0], [1.0, 1.0]]]
+                            .into_iter()
+                            .collect::<VectorMatrix<2, f64>>(),
+                    )
+                    .into(),
+                ),
+            ]
+        );
+
+        // Réencodée, la colonne reste lisible par ces producteurs.
+        let encoded = encode_arrow_array(decoded.iter().map(Option::as_ref)).unwrap();
+        if cfg!(target_endian = "little") {
+            assert_eq!(encoded.value(0), multi_point.as_slice());
+        }
+    }
+
+    #[test]
+    pub fn test_corrupt_input_is_an_error() {
+        let bytes = EWKBGeometry::new(Point::new([10.0, 20.0]))
+            .encode_to_vec()
+            .expect("cannot encode geometry");
+
+        let mut bad_endianness = bytes.clone();
+        bad_endianness[0] = 7;
+        assert!(matches!(
+            decode_geometry(&mut bad_endianness.as_slice()).map_err(Error::into_inner),
+            Err(Error::InvalidByteOrder(7))
+        ));
+
+        let mut bad_code = bytes.clone();
+        bad_code[1..5].copy_from_slice(&42u32.to_ne_bytes());
+        assert!(matches!(
+            decode_geometry(&mut bad_code.as_slice()).map_err(Error::into_inner),
+            Err(Error::UnknownGeometryCode(42))
+        ));
+
+        assert!(matches!(
+            decode_geometry(&mut &bytes[..bytes.len() - 1]).map_err(Error::into_inner),
+            Err(Error::UnexpectedEof)
+        ));
+
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(matches!(
+            EWKBGeometry::decode_from_slice(&trailing),
+            Err(Error::TrailingBytes {
+                consumed: 21,
+                remaining: 1
+            })
+        ));
+    }
+
+    #[test]
+    pub fn test_nesting_limit() {
+        let bytes = EWKBGeometry::new(Point::new([10.0, 20.0]))
+            .encode_to_vec()
+            .expect("cannot encode geometry");
+
+        let options = DecodeOptions {
+            limits: DecodeLimits {
+                max_nesting: 0,
+                ..DecodeLimits::default()
+            },
+            ..DecodeOptions::default()
+        };
+        assert!(matches!(
+            decode_geometry_with(&mut bytes.as_slice(), &options).map_err(Error::into_inner),
+            Err(Error::LimitExceeded {
+                limit: "nesting",
+                max: 0,
+                got: 1
+            })
+        ));
+
+        // L'en-tête de chaque membre d'une multi-géométrie ajoute un niveau.
+        let bytes = EWKBGeometry::new(MultiPoint::new([[1.0, 2.0], [3.0, 4.0]]))
+            .encode_to_vec()
+            .expect("cannot encode geometry");
+        let limits = |max_nesting| DecodeOptions {
+            limits: DecodeLimits {
+                max_nesting,
+                ..DecodeLimits::default()
+            },
+            ..DecodeOptions::default()
+        };
+        assert!(matches!(
+            decode_geometry_with(&mut bytes.as_slice(), &limits(1)).map_err(Error::into_inner),
+            Err(Error::LimitExceeded {
+                limit: "nesting",
+                max: 1,
+                got: 2
+            })
+        ));
+        assert!(decode_geometry_with(&mut bytes.as_slice(), &limits(2)).is_ok());
+    }
+
+    #[test]
+    pub fn test_decode_vertices() {
+        let line = LineString::new([[0.0, 0.0], [3.0, 4.0], [3.0, 8.0]]);
+        let bytes = EWKBGeometry::new(line.clone())
+            .encode_to_vec()
+            .expect("cannot encode geometry");
+
+        let vertices = decode_vertices(bytes.as_slice())
+            .collect::<Result<Vec<_>, _>>()
+            .expect("cannot decode vertices");
+        assert_eq!(vertices.as_slice(), line.coordinates.deref());
+
+        let mut truncated = decode_vertices(&bytes[..bytes.len() - 4]);
+        assert!(truncated.next().is_some_and(|vertex| vertex.is_ok()));
+        assert!(truncated.next().is_some_and(|vertex| vertex.is_ok()));
+        assert!(matches!(truncated.next(), Some(Err(Error::UnexpectedEof))));
+        assert!(truncated.next().is_none());
+
+        let point = EWKBGeometry::new(Point::new([1.0, 2.0]))
+            .encode_to_vec()
+            .expect("cannot encode geometry");
+        assert!(matches!(
+            decode_vertices(point.as_slice()).next(),
+            Some(Err(Error::InvalidGeometryKind { .. }))
+        ));
+    }
+
+    #[test]
+    pub fn test_error_is_located() {
+        let polygon = Polygon::new((
+            [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0]],
+            [[1.0, 1.0], [2.0, 1.0], [2.0, 2.0]],
+        ));
+        let bytes = EWKBGeometry::new(polygon)
+            .encode_to_vec()
+            .expect("cannot encode geometry");
+
+        // En-tête et nombre d'anneaux (9 octets), anneau 0 (68 octets), puis le compteur
+        // et deux points de l'anneau 1.
+        let error = decode_geometry(&mut &bytes[..120]).expect_err("truncated stream");
+        assert_eq!(
+            error.to_string(),
+            "at byte 120 (ring 1, vertex 2): unexpected end of stream"
+        );
+        assert!(matches!(
+            error,
+            Error::Located { offset: 120, ref path, .. } if path == "ring 1, vertex 2"
+        ));
+    }
+
+    #[test]
+    pub fn test_forged_count_is_rejected() {
+        // Une ligne annonçant u32::MAX points, sans les fournir.
+        let mut bytes = vec![LITTLE_ENDIAN];
+        bytes.extend_from_slice(&2u32.to_le_bytes());
+        bytes.extend_from_slice(&u32::MAX.to_le_bytes());
+
+        assert!(matches!(
+            decode_geometry(&mut bytes.as_slice()).map_err(Error::into_inner),
+            Err(Error::LimitExceeded {
+                limit: "vertices",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    pub fn test_truncated_count_within_limits() {
+        // Un compteur sous les limites mais sans les points qu'il annonce : le
+        // décodage échoue sur la fin du flux, sans avoir réservé 5 millions de points.
+        let mut bytes = vec![LITTLE_ENDIAN];
+        bytes.extend_from_slice(&2u32.to_le_bytes());
+        bytes.extend_from_slice(&5_000_000u32.to_le_bytes());
+        bytes.extend_from_slice(&[0; 32]);
+
+        let error = decode_geometry(&mut bytes.as_slice()).unwrap_err();
+        assert!(error.is_unexpected_eof());
+    }
+
+    #[test]
+    pub fn test_custom_limits() {
+        let bytes = EWKBGeometry::new(LineString::new([[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]]))
+            .encode_to_vec()
+            .expect("cannot encode geometry");
+
+        let options = DecodeOptions {
+            limits: DecodeLimits {
+                max_vertices: 2,
+                ..DecodeLimits::default()
+            },
+            ..DecodeOptions::default()
+        };
+        assert!(matches!(
+            decode_geometry_with(&mut bytes.as_slice(), &options).map_err(Error::into_inner),
+            Err(Error::LimitExceeded {
+                limit: "vertices",
+                max: 2,
+                got: 3
+            })
+        ));
+
+        let options = DecodeOptions {
+            limits: DecodeLimits {
+                max_bytes: bytes.len() - 1,
+                ..DecodeLimits::default()
+            },
+            ..DecodeOptions::default()
+        };
+        assert!(matches!(
+            decode_geometry_with(&mut bytes.as_slice(), &options).map_err(Error::into_inner),
+            Err(Error::LimitExceeded { limit: "bytes", .. })
+        ));
+
+        let options = DecodeOptions {
+            limits: DecodeLimits {
+                max_bytes: bytes.len(),
+                ..DecodeLimits::default()
+            },
+            ..DecodeOptions::default()
+        };
+        assert!(decode_geometry_with(&mut bytes.as_slice(), &options).is_ok());
+    }
+
+    #[test]
+    pub fn test_decode_options() {
+        let bytes = EWKBGeometry::new(Point::new([f64::NAN, 20.0]))
+            .encode_to_vec()
+            .expect("cannot encode geometry");
+
+        let options = DecodeOptions {
+            srid: SridPolicy::Fallback(4326),
+            ..DecodeOptions::default()
+        };
+        let geometry =
+            decode_geometry_with(&mut bytes.as_slice(), &options).expect("cannot decode geometry");
+        assert_eq!(geometry.srid(), Some(4326));
+
+        assert!(matches!(
+            decode_geometry_with(&mut bytes.as_slice(), &DecodeOptions::strict()),
+            Err(Error::InvalidCoordinates { .. })
+        ));
+    }
+
+    #[cfg(feature = "sea-query")]
+    #[test]
+    pub fn test_sea_query_value() {
+        let geometry = Geometry::from(Point::new([10.0, 20.0]));
+        let expected = EWKBGeometry::new(geometry.clone())
+            .encode_to_vec()
+            .expect("cannot encode geometry");
+
+        assert_eq!(
+            ::sea_query::Value::from(geometry),
+            ::sea_query::Value::Bytes(Some(Box::new(expected)))
+        );
+        assert_eq!(
+            ::sea_query::Value::from(None::<EWKBPoint>),
+            ::sea_query::Value::Bytes(None)
+        );
+    }
+
+    #[cfg(feature = "sea-query")]
+    #[test]
+    pub fn test_sea_query_value_type() {
+        let expected = Point::new([10.0, 20.0]);
+        let value = ::sea_query::Value::from(EWKBPoint::from(expected.clone()));
+
+        assert_eq!(value.clone().unwrap::<Geometry>(), expected.clone().into());
+        assert_eq!(value.unwrap::<EWKBPoint>().0, expected);
+        assert!(
+            <EWKBLineString as ::sea_query::ValueType>::try_from(::sea_query::Value::from(
+                Geometry::from(Point::new([1.0, 2.0]))
+            ))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_encoded_size() {
+        let geometries: [Geometry; 5] = [
+            Point::new([10.0, 20.0]).into(),
+            PointZ::new([1.0, 2.0, 3.0]).into(),
+            LineString::new([[0.0, 0.0], [1.0, 1.0], [2.0, 0.0]]).into(),
+            Polygon::new((
+                [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 0.0]],
+                [[1.0, 1.0], [2.0, 1.0], [2.0, 2.0], [1.0, 1.0]],
+            ))
+            .into(),
+            MultiPolygon::new(VectorTensor::from_iter([
+                vec![vec![[0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 0.0]]],
+                vec![vec![[10.0, -2.0], [12.0, -2.0], [12.0, 1.0], [10.0, -2.0]]],
+            ]))
+            .into(),
+        ];
+
+        for geometry in geometries {
+            let mut geometry = EWKBGeometry::new(geometry);
+            geometry.set_srid(Some(4326));
+
+            let bytes = geometry.encode_to_vec().expect("cannot encode geometry");
+            assert_eq!(geometry.encoded_size(), Some(bytes.len()));
+        }
+    }
+
+    #[cfg(feature = "arena")]
+    #[test]
+    fn test_decode_geometry_in() {
+        let mut expected: Geometry = Polygon::new((
+            [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 0.0]],
+            [[1.0, 1.0], [2.0, 1.0], [2.0, 2.0], [1.0, 1.0]],
+        ))
+        .into();
+        expected.set_srid(Some(4326));
+
+        let mut bytes = Vec::default();
+        encode_geometry(&expected, &mut bytes).expect("cannot encode geometry");
+
+        let mut arena = Bump::new();
+        for _ in 0..2 {
+            let geometry =
+                decode_geometry_in(&arena, &mut bytes.as_slice()).expect("cannot decode geometry");
+
+            assert_eq!(geometry.kind(), GeometryKind::Polygon);
+            assert_eq!(geometry.srid(), Some(4326));
+            assert!(matches!(
+                geometry.coordinates(),
+                ArenaCoordinates::VectorMatrix2D([exterior, _]) if exterior.len() == 4
+            ));
+            assert_eq!(geometry.to_geometry(), expected);
+
+            arena.reset();
+        }
+
+        assert!(decode_geometry_in(&arena, &mut &bytes[..bytes.len() - 8]).is_err());
+    }
+
+    #[test]
+    fn test_byte_orders() {
+        let point = Point::new([1.0, 2.0]);
+        let mut bytes = Vec::default();
+        encode_geometry_with_endianess::<BigEndian, _>((&point).into(), &mut bytes)
+            .expect("cannot encode geometry");
+        assert_eq!(
+            encode_hex(&bytes),
+            "00000000013FF00000000000004000000000000000"
+        );
+
+        let mut expected: Geometry = Polygon::new((
+            [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 0.0]],
+            [[1.0, 1.0], [2.0, 1.0], [2.0, 2.0], [1.0, 1.0]],
+        ))
+        .into();
+        expected.set_srid(Some(2154));
+
+        let mut big = Vec::default();
+        encode_geometry_with_endianess::<BigEndian, _>((&expected).into(), &mut big)
+            .expect("cannot encode geometry");
+        let mut little = Vec::default();
+        encode_geometry_with_endianess::<LittleEndian, _>((&expected).into(), &mut little)
+            .expect("cannot encode geometry");
+
+        assert_ne!(big, little);
+        assert_eq!(decode_geometry(&mut big.as_slice()).unwrap(), expected);
+        assert_eq!(decode_geometry(&mut little.as_slice()).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_encoder() {
+        let geometries: [Geometry; 3] = [
+            Point::new([10.0, 20.0]).into(),
+            LineString::new([[0.0, 0.0], [1.0, 1.0], [2.0, 0.0]]).into(),
+            PointZ::new([1.0, 2.0, 3.0]).into(),
+        ];
+
+        let mut encoder = EWKBEncoder::default();
+        let mut batch = Vec::default();
+        for geometry in &geometries {
+            encoder
+                .encode_into(geometry, &mut batch)
+                .expect("cannot encode geometry");
+
+            let expected = EWKBGeometry::new(geometry.clone()).encode_to_vec().unwrap();
+            assert_eq!(encoder.encode(geometry).unwrap(), expected);
+        }
+
+        let mut remaining = batch.as_slice();
+        for geometry in &geometries {
+            let (decoded, consumed) = EWKBGeometry::decode_partial(remaining).unwrap();
+            assert_eq!(&*decoded, geometry);
+            remaining = &remaining[consumed..];
+        }
+        assert!(remaining.is_empty());
+
+        let mut big = EWKBEncoder::new(Endianess::BigEndian);
+        let encoded = big.encode(&geometries[0]).unwrap();
+        assert_eq!(encoded[0], BIG_ENDIAN);
+        assert_eq!(decode_geometry(&mut &encoded[..]).unwrap(), geometries[0]);
+    }
+
+    #[test]
+    fn test_encoder_orientation() {
+        let clockwise: Geometry =
+            Polygon::new([[0.0, 0.0], [0.0, 1.0], [1.0, 1.0], [1.0, 0.0]]).into();
+        let counter_clockwise: Geometry =
+            Polygon::new([[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]).into();
+
+        let mut native = EWKBEncoder::default();
+        let encoded = native.encode(&clockwise).unwrap();
+        assert_eq!(decode_geometry(&mut &encoded[..]).unwrap(), clockwise);
+
+        let mut ogc = EWKBEncoder::default().with_orientation(RingOrientation::OGC);
+        for geometry in [&clockwise, &counter_clockwise] {
+            let encoded = ogc.encode(geometry).unwrap();
+            assert_eq!(
+                decode_geometry(&mut &encoded[..]).unwrap(),
+                counter_clockwise
+            );
+        }
+    }
+
+    /// Les liaisons écrivent les anneaux tels quels : le sens se choisit avant.
+    #[cfg(feature = "sea-query")]
+    #[test]
+    fn test_bind_orientation() {
+        let clockwise = Polygon::new([[0.0, 0.0], [0.0, 1.0], [1.0, 1.0], [1.0, 0.0]]);
+
+        for (polygon, orientation) in [
+            (clockwise.clone(), RingOrientation::Clockwise),
+            (
+                clockwise.oriented(RingOrientation::OGC),
+                RingOrientation::OGC,
+            ),
+        ] {
+            let ::sea_query::Value::Bytes(Some(bytes)) =
+                ::sea_query::Value::from(EWKBPolygon::from(polygon))
+            else {
+                panic!("expecting a bytes value");
+            };
+            let bound = decode_geometry(&mut bytes.as_slice()).unwrap();
+            assert!(bound.is_oriented(orientation));
+        }
+    }
+
+    #[test]
+    fn test_encode_wkb() {
+        let mut polygon = Polygon::new([[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 0.0]]);
+        polygon.srid = Some(4326);
+        let geometry = Geometry::from(polygon);
+
+        let mut wkb = Vec::new();
+        encode_wkb((&geometry).into(), &mut wkb).unwrap();
+        assert_eq!(wkb.len(), encoded_size(&geometry) - 4);
+
+        let mut decoded = decode_geometry(&mut wkb.as_slice()).unwrap();
+        assert_eq!(decoded.srid(), None);
+        decoded.set_srid(Some(4326));
+        assert_eq!(decoded, geometry);
+
+        // Chaque polygone porte son propre en-tête, comme l'attendent GDAL et GEOS.
+        let geometry = Geometry::from(MultiPolygon::new(
+            [vec![vec![[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 0.0]]]]
+                .into_iter()
+                .collect::<VectorTensor<2, f64>>(),
+        ));
+        let mut wkb = Vec::new();
+        encode_wkb((&geometry).into(), &mut wkb).unwrap();
+        assert_eq!(
+            wkb[9],
+            u8::from(Endianess::from(PhantomData::<NativeEndian>))
+        );
+        assert_eq!(NativeEndian::read_u32(&wkb[10..14]), 3);
+        assert_eq!(decode_geometry(&mut wkb.as_slice()).unwrap(), geometry);
+    }
+
+    #[test]
+    fn test_encode_all() {
+        let geometries: Vec<Geometry> = vec![
+            Point::new([10.0, 20.0]).into(),
+            LineString::new([[0.0, 0.0], [1.0, 1.0], [2.0, 0.0]]).into(),
+            PointZ::new([1.0, 2.0, 3.0]).into(),
+        ];
+
+        let mut bytes = Vec::default();
+        encode_all(&geometries, &mut bytes).expect("cannot encode geometries");
+
+        assert_eq!(
+            decode_all(&bytes, 3).expect("cannot decode geometries"),
+            geometries
+        );
+        assert!(matches!(
+            decode_all(&bytes, 2),
+            Err(Error::TrailingBytes { .. })
+        ));
+        assert!(decode_all(&bytes, 4).is_err());
+        assert!(decode_all(&bytes[..bytes.len() - 1], 3).is_err());
+        assert_eq!(decode_all(&[], 0).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_peek_header() {
+        let mut geometry = Geometry::from(PointZ::new([1.0, 2.0, 3.0]));
+        geometry.set_srid(Some(4326));
+
+        let mut bytes = Vec::default();
+        encode_geometry(&geometry, &mut bytes).unwrap();
+
+        assert_eq!(peek_kind(&bytes).unwrap(), GeometryKind::PointZ);
+        assert_eq!(peek_srid(&bytes).unwrap(), Some(4326));
+
+        // Les coordonnées ne sont pas lues.
+        assert_eq!(peek_kind(&bytes[..9]).unwrap(), GeometryKind::PointZ);
+        assert!(peek_srid(&bytes[..6]).is_err());
+        assert!(peek_kind(&[]).is_err());
+    }
+
+    #[test]
+    fn test_point_proxy_accessors() {
+        let point = EWKBPoint::from((2.35, 48.85));
+        assert_eq!((point.lng(), point.lat(), point.z()), (2.35, 48.85, None));
+
+        let point = EWKBPointZ::from((1.0, 2.0, 3.0));
+        assert_eq!(point.z(), Some(3.0));
+    }
+
+    /// Multi-géométries telles qu'écrites par PostGIS : chaque membre porte son propre
+    /// en-tête (boutisme et type).
+    #[test]
+    fn test_postgis_multi_geometries() {
+        // ST_AsEWKB('SRID=4326;MULTIPOINT((1 2),(3 4))'::geometry, 'NDR')
+        let hex = "0104000020E6100000020000000101000000000000000000F03F000000000000004001010000\
+                   0000000000000008400000000000001040";
+        let bytes = decode_hex(hex).unwrap();
+        let geometry = EWKBGeometry::decode_from_slice(&bytes).expect("cannot decode MULTIPOINT");
+
+        let mut expected = Geometry::from(MultiPoint::new([[1.0, 2.0], [3.0, 4.0]]));
+        expected.set_srid(Some(4326));
+        assert_eq!(*geometry, expected);
+
+        let mut encoded = Vec::new();
+        encode_geometry_with_endianess::<LittleEndian, _>(geometry.as_geometry_ref(), &mut encoded)
+            .unwrap();
+        assert_eq!(encode_hex(&encoded), hex);
+        assert_eq!(encoded_size(&expected), bytes.len());
+
+        // ST_AsBinary('MULTIPOLYGON(((0 0,1 0,1 1,0 0)),((2 2,3 2,3 3,2 2)))'::geometry, 'XDR')
+        let hex = "0000000006000000020000000003000000010000000400000000000000000000000000000000\
+                   3FF000000000000000000000000000003FF00000000000003FF0000000000000000000000000\
+                   0000000000000000000000000000030000000100000004400000000000000040000000000000\
+                   0040080000000000004000000000000000400800000000000040080000000000004000000000\
+                   0000004000000000000000";
+        let geometry = EWKBGeometry::decode_from_slice(&decode_hex(hex).unwrap())
+            .expect("cannot decode MULTIPOLYGON");
+        let expected = MultiPolygon::new(
+            [
+                vec![vec![[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 0.0]]],
+                vec![vec![[2.0, 2.0], [3.0, 2.0], [3.0, 3.0], [2.0, 2.0]]],
+            ]
+            .into_iter()
+            .collect::<VectorTensor<2, f64>>(),
+        );
+        assert_eq!(*geometry, Geometry::from(expected.clone()));
+
+        let mut encoded = Vec::new();
+        encode_geometry_with_endianess::<BigEndian, _>((&expected).into(), &mut encoded).unwrap();
+        assert_eq!(encode_hex(&encoded), hex);
+
+        // Un membre d'un autre type que celui annoncé par la multi-géométrie.
+        let mut bytes = decode_hex(hex).unwrap();
+        bytes[9 + 4] = 2;
+        assert!(EWKBGeometry::decode_from_slice(&bytes).is_err());
+
+        // Format historique, sans en-tête par membre.
+        let bytes = decode_hex(
+            "010400000002000000000000000000F03F000000000000004000000000000008400000000000001040",
+        )
+        .unwrap();
+        assert!(EWKBGeometry::decode_from_slice(&bytes).is_err());
+    }
+
+    #[cfg(feature = "arena")]
+    #[test]
+    fn test_arena_multi_geometries() {
+        let arena = Bump::new();
+        let expected = MultiLineStringZ::new(
+            [
+                vec![[0.0, 0.0, 1.0], [1.0, 1.0, 2.0]],
+                vec![[2.0, 2.0, 3.0], [3.0, 3.0, 4.0]],
+            ]
+            .into_iter()
+            .collect::<VectorMatrix<3, f64>>(),
+        );
+        let bytes = EWKBGeometry::new(expected.clone()).encode_to_vec().unwrap();
+
+        let geometry = decode_geometry_in(&arena, &mut bytes.as_slice()).unwrap();
+        assert_eq!(geometry.to_geometry(), Geometry::from(expected));
+    }
+
+    #[test]
+    fn test_proxy_default_and_hash() {
+        assert_eq!(EWKBPoint::default(), EWKBPoint::from((0.0, 0.0)));
+        assert!(EWKBPolygon::default().coordinates.is_empty());
+
+        // `0.0 == -0.0` : les deux points doivent avoir la même empreinte.
+        use std::hash::BuildHasher as _;
+        let hasher = std::collections::hash_map::RandomState::new();
+        assert_eq!(
+            hasher.hash_one(EWKBPoint::from((0.0, 1.0))),
+            hasher.hash_one(EWKBPoint::from((-0.0, 1.0)))
+        );
+        assert_ne!(
+            hasher.hash_one(EWKBPoint::from((0.0, 1.0))),
+            hasher.hash_one(EWKBPoint::from((1.0, 0.0)))
+        );
+    }
+
+    #[test]
+    fn test_dyn_encodable() {
+        let geometries: Vec<Box<dyn crate::DynEncodable>> = vec![
+            Box::new(EWKBGeometry::new(Point::new([1.0, 2.0]))),
+            Box::new(EWKBGeometry::new(PointZ::new([1.0, 2.0, 3.0]))),
+        ];
+
+        for geometry in &geometries {
+            let bytes = geometry.encode_dyn_to_vec().unwrap();
+            assert_eq!(Some(bytes.len()), geometry.dyn_encoded_size());
+            assert!(EWKBGeometry::decode_from_slice(&bytes).is_ok());
+        }
+    }
 }
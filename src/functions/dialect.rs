@@ -0,0 +1,186 @@
+//! Noms des fonctions spatiales selon la base de données ciblée.
+use sea_query::{Alias, Func, FunctionCall, SimpleExpr};
+
+use super::{geom, BoolExpr, GeomExpr};
+use crate::types::MBR2D;
+
+/// Dialecte SQL spatial ciblé par les constructeurs de fonctions.
+///
+/// La plupart des fonctions `ST_*` portent le même nom dans PostGIS et SpatiaLite,
+/// seules celles qui diffèrent sont renommées. Les constructeurs libres du module
+/// [functions](super) ciblent PostGIS ; les méthodes de [Dialect] construisent les
+/// fonctions dont le nom dépend de la base de données.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Dialect {
+    #[default]
+    PostGis,
+    SpatiaLite,
+}
+
+impl Dialect {
+    /// Renvoie le nom d'une fonction dans ce dialecte, à partir de son nom PostGIS.
+    pub fn function_name(self, postgis_name: &'static str) -> &'static str {
+        match (self, postgis_name) {
+            (Dialect::PostGis, name) => name,
+            (Dialect::SpatiaLite, "ST_GeomFromGeoJSON") => "GeomFromGeoJSON",
+            (Dialect::SpatiaLite, "ST_MakePoint") => "MakePoint",
+            (Dialect::SpatiaLite, "ST_AsGeoJSON") => "AsGeoJSON",
+            (Dialect::SpatiaLite, "ST_AsEWKT") => "AsEWKT",
+            (Dialect::SpatiaLite, "ST_SetSRID") => "SetSRID",
+            (Dialect::SpatiaLite, "ST_MakeEnvelope") => "BuildMbr",
+            (Dialect::SpatiaLite, "ST_Extent") => "Extent",
+            (Dialect::SpatiaLite, "ST_DWithin") => "PtDistWithin",
+            (Dialect::SpatiaLite, name) => name,
+        }
+    }
+
+    /// Appel d'une fonction, désignée par son nom PostGIS, dans ce dialecte.
+    pub fn call(self, postgis_name: &'static str) -> FunctionCall {
+        Func::cust(Alias::new(self.function_name(postgis_name)))
+    }
+
+    /// `ST_GeomFromGeoJSON(geojson)`, construit une géométrie depuis un texte GeoJSON.
+    pub fn st_geomfromgeojson<G>(self, geojson: G) -> GeomExpr
+    where
+        G: Into<SimpleExpr>,
+    {
+        self.call("ST_GeomFromGeoJSON").arg(geojson).into()
+    }
+
+    /// `ST_MakePoint(x, y)`, construit un point 2D.
+    pub fn st_makepoint<X, Y>(self, x: X, y: Y) -> GeomExpr
+    where
+        X: Into<SimpleExpr>,
+        Y: Into<SimpleExpr>,
+    {
+        self.call("ST_MakePoint").arg(x).arg(y).into()
+    }
+
+    /// `ST_SetSRID(geom, srid)`, affecte un SRID à une géométrie sans la reprojeter.
+    pub fn st_setsrid<G>(self, geom: G, srid: u32) -> GeomExpr
+    where
+        G: Into<GeomExpr>,
+    {
+        self.call("ST_SetSRID")
+            .arg(self::geom(geom))
+            .arg(srid)
+            .into()
+    }
+
+    /// `ST_AsEWKT(geom)`, représentation EWKT (WKT préfixé du SRID) d'une géométrie.
+    pub fn st_asewkt<G>(self, geom: G) -> FunctionCall
+    where
+        G: Into<GeomExpr>,
+    {
+        self.call("ST_AsEWKT").arg(self::geom(geom))
+    }
+
+    /// `ST_AsGeoJSON(geom)`, représentation GeoJSON d'une géométrie.
+    pub fn st_asgeojson<G>(self, geom: G) -> FunctionCall
+    where
+        G: Into<GeomExpr>,
+    {
+        self.call("ST_AsGeoJSON").arg(self::geom(geom))
+    }
+
+    /// `ST_MakeEnvelope(xmin, ymin, xmax, ymax[, srid])`, rectangle construit à partir
+    /// d'une emprise (`BuildMbr` pour SpatiaLite).
+    pub fn st_makeenvelope(self, mbr: &MBR2D, srid: Option<u32>) -> GeomExpr {
+        let call = self
+            .call("ST_MakeEnvelope")
+            .arg(mbr.min_x())
+            .arg(mbr.min_y())
+            .arg(mbr.max_x())
+            .arg(mbr.max_y());
+
+        match srid {
+            Some(srid) => call.arg(srid).into(),
+            None => call.into(),
+        }
+    }
+
+    /// Agrégat `ST_Extent(geom)`, rectangle englobant les géométries d'un groupe
+    /// (`Extent` pour SpatiaLite).
+    pub fn st_extent<G>(self, geom: G) -> FunctionCall
+    where
+        G: Into<GeomExpr>,
+    {
+        self.call("ST_Extent").arg(self::geom(geom))
+    }
+
+    /// `ST_DWithin(geom1, geom2, distance)`, vrai si les géométries sont à moins de
+    /// `distance` l'une de l'autre (`PtDistWithin` pour SpatiaLite).
+    pub fn st_dwithin<A, B>(self, geom1: A, geom2: B, distance: f64) -> BoolExpr
+    where
+        A: Into<GeomExpr>,
+        B: Into<GeomExpr>,
+    {
+        self.call("ST_DWithin")
+            .arg(geom(geom1))
+            .arg(geom(geom2))
+            .arg(distance)
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_query::{Alias, Expr, PostgresQueryBuilder, Query, SqliteQueryBuilder};
+
+    use super::*;
+
+    #[test]
+    fn test_spatialite_names() {
+        let query = Query::select()
+            .expr(Dialect::SpatiaLite.st_asgeojson(Expr::col(Alias::new("geom"))))
+            .expr(
+                Dialect::SpatiaLite
+                    .call("ST_Area")
+                    .arg(Expr::col(Alias::new("geom"))),
+            )
+            .from(Alias::new("parcels"))
+            .to_string(SqliteQueryBuilder);
+
+        assert_eq!(
+            query,
+            r#"SELECT AsGeoJSON("geom"), ST_Area("geom") FROM "parcels""#
+        );
+    }
+
+    #[test]
+    fn test_dialect_builders() {
+        let mbr = MBR2D::new([0.0, 0.5], [10.0, 10.5]);
+        let query = |dialect: Dialect| {
+            let geom = || Expr::col(Alias::new("geom"));
+            let query = Query::select()
+                .expr(dialect.st_extent(geom()))
+                .from(Alias::new("parcels"))
+                .and_where(
+                    dialect
+                        .st_dwithin(geom(), dialect.st_makeenvelope(&mbr, Some(4326)), 5.0)
+                        .into(),
+                )
+                .to_owned();
+
+            match dialect {
+                Dialect::PostGis => query.to_string(PostgresQueryBuilder),
+                Dialect::SpatiaLite => query.to_string(SqliteQueryBuilder),
+            }
+        };
+
+        assert_eq!(
+            query(Dialect::PostGis),
+            concat!(
+                r#"SELECT ST_Extent("geom") FROM "parcels" "#,
+                r#"WHERE ST_DWithin("geom", ST_MakeEnvelope(0, 0.5, 10, 10.5, 4326), 5)"#
+            )
+        );
+        assert_eq!(
+            query(Dialect::SpatiaLite),
+            concat!(
+                r#"SELECT Extent("geom") FROM "parcels" "#,
+                r#"WHERE PtDistWithin("geom", BuildMbr(0, 0.5, 10, 10.5, 4326), 5)"#
+            )
+        );
+    }
+}
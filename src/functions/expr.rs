@@ -0,0 +1,136 @@
+//! Expressions typées renvoyées par les constructeurs de fonctions spatiales.
+//!
+//! Elles permettent de vérifier à la compilation que l'on passe bien une géométrie
+//! là où une géométrie est attendue, et de comparer un résultat numérique.
+use sea_query::{Expr, ExprTrait as _, FunctionCall, SimpleExpr, Value};
+
+use crate::types::{Geometry, MBR2D};
+
+macro_rules! impl_typed_expr {
+    ($name:ident) => {
+        impl $name {
+            pub fn into_simple_expr(self) -> SimpleExpr {
+                self.0
+            }
+        }
+
+        impl From<$name> for SimpleExpr {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl From<FunctionCall> for $name {
+            fn from(value: FunctionCall) -> Self {
+                Self(value.into())
+            }
+        }
+
+        impl From<SimpleExpr> for $name {
+            fn from(value: SimpleExpr) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<Expr> for $name {
+            fn from(value: Expr) -> Self {
+                Self(value.into())
+            }
+        }
+    };
+}
+
+/// Expression booléenne, typiquement un prédicat spatial.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoolExpr(SimpleExpr);
+
+impl_typed_expr!(BoolExpr);
+
+impl BoolExpr {
+    pub fn and<E: Into<BoolExpr>>(self, other: E) -> BoolExpr {
+        Self(self.0.and(other.into().0))
+    }
+
+    pub fn or<E: Into<BoolExpr>>(self, other: E) -> BoolExpr {
+        Self(self.0.or(other.into().0))
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(self) -> BoolExpr {
+        Self(self.0.not())
+    }
+}
+
+/// Expression numérique, typiquement une mesure (aire, longueur, distance).
+#[derive(Debug, Clone, PartialEq)]
+pub struct F64Expr(SimpleExpr);
+
+impl_typed_expr!(F64Expr);
+
+impl From<f64> for F64Expr {
+    fn from(value: f64) -> Self {
+        Self(value.into())
+    }
+}
+
+impl F64Expr {
+    /// `self > other`
+    pub fn gt<E: Into<F64Expr>>(self, other: E) -> BoolExpr {
+        BoolExpr(self.0.gt(other.into().0))
+    }
+
+    /// `self >= other`
+    pub fn gte<E: Into<F64Expr>>(self, other: E) -> BoolExpr {
+        BoolExpr(self.0.gte(other.into().0))
+    }
+
+    /// `self < other`
+    pub fn lt<E: Into<F64Expr>>(self, other: E) -> BoolExpr {
+        BoolExpr(self.0.lt(other.into().0))
+    }
+
+    /// `self <= other`
+    pub fn lte<E: Into<F64Expr>>(self, other: E) -> BoolExpr {
+        BoolExpr(self.0.lte(other.into().0))
+    }
+
+    /// `self = other`
+    pub fn equals<E: Into<F64Expr>>(self, other: E) -> BoolExpr {
+        BoolExpr(self.0.eq(other.into().0))
+    }
+}
+
+/// Expression désignant une géométrie (colonne, valeur liée ou résultat de fonction).
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeomExpr(SimpleExpr);
+
+impl_typed_expr!(GeomExpr);
+
+impl From<Value> for GeomExpr {
+    fn from(value: Value) -> Self {
+        Self(value.into())
+    }
+}
+
+impl From<Geometry> for GeomExpr {
+    fn from(value: Geometry) -> Self {
+        Self(Value::from(value).into())
+    }
+}
+
+/// Une emprise est liée sous la forme `ST_MakeEnvelope(xmin, ymin, xmax, ymax, srid)`.
+///
+/// Le SRID est obligatoire : sans lui, PostGIS refuse de comparer l'enveloppe à des
+/// géométries qui en ont un. Voir [st_makeenvelope](super::st_makeenvelope) pour
+/// une enveloppe sans SRID.
+impl From<(MBR2D, u32)> for GeomExpr {
+    fn from((mbr, srid): (MBR2D, u32)) -> Self {
+        super::st_makeenvelope(&mbr, Some(srid))
+    }
+}
+
+impl From<(&MBR2D, u32)> for GeomExpr {
+    fn from((mbr, srid): (&MBR2D, u32)) -> Self {
+        super::st_makeenvelope(mbr, Some(srid))
+    }
+}
@@ -0,0 +1,81 @@
+//! Prédicats spatiaux chaînables sur les expressions sea-query.
+use super::{
+    st_covers, st_crosses, st_disjoint, st_dwithin, st_intersects, st_overlaps, st_touches,
+    st_within, BoolExpr, GeomExpr,
+};
+
+/// Écriture fluide des filtres spatiaux, par exemple `Expr::col(geom).within(zone)`.
+///
+/// Chaque méthode appelle le constructeur de fonction correspondant, avec `self`
+/// comme première géométrie. `ST_Contains` n'est pas exposée ici pour ne pas entrer
+/// en conflit avec `PgExpr::contains` de sea-query, voir [super::st_contains].
+pub trait SpatialExprExt: Into<GeomExpr> + Sized {
+    /// `ST_Within(self, other)`
+    fn within<G: Into<GeomExpr>>(self, other: G) -> BoolExpr {
+        st_within(self, other)
+    }
+
+    /// `ST_Intersects(self, other)`
+    fn intersects<G: Into<GeomExpr>>(self, other: G) -> BoolExpr {
+        st_intersects(self, other)
+    }
+
+    /// `ST_Overlaps(self, other)`
+    fn overlaps<G: Into<GeomExpr>>(self, other: G) -> BoolExpr {
+        st_overlaps(self, other)
+    }
+
+    /// `ST_Touches(self, other)`
+    fn touches<G: Into<GeomExpr>>(self, other: G) -> BoolExpr {
+        st_touches(self, other)
+    }
+
+    /// `ST_Crosses(self, other)`
+    fn crosses<G: Into<GeomExpr>>(self, other: G) -> BoolExpr {
+        st_crosses(self, other)
+    }
+
+    /// `ST_Disjoint(self, other)`
+    fn disjoint<G: Into<GeomExpr>>(self, other: G) -> BoolExpr {
+        st_disjoint(self, other)
+    }
+
+    /// `ST_Covers(self, other)`
+    fn covers<G: Into<GeomExpr>>(self, other: G) -> BoolExpr {
+        st_covers(self, other)
+    }
+
+    /// `ST_DWithin(self, other, distance)`
+    fn dwithin<G: Into<GeomExpr>>(self, other: G, distance: f64) -> BoolExpr {
+        st_dwithin(self, other, distance)
+    }
+}
+
+impl<T> SpatialExprExt for T where T: Into<GeomExpr> {}
+
+#[cfg(test)]
+mod tests {
+    use sea_query::{Alias, Expr, PostgresQueryBuilder, Query};
+
+    use super::*;
+    use crate::functions::st_makepoint;
+
+    #[test]
+    fn test_fluent_predicates() {
+        let query = Query::select()
+            .column(Alias::new("id"))
+            .from(Alias::new("shops"))
+            .and_where(
+                Expr::col(Alias::new("geom"))
+                    .within(Expr::col(Alias::new("zone")))
+                    .and(Expr::col(Alias::new("geom")).dwithin(st_makepoint(1.0, 2.0), 500.0))
+                    .into(),
+            )
+            .to_string(PostgresQueryBuilder);
+
+        assert_eq!(
+            query,
+            r#"SELECT "id" FROM "shops" WHERE ST_Within("geom", "zone") AND ST_DWithin("geom", ST_MakePoint(1, 2), 500)"#
+        );
+    }
+}
@@ -0,0 +1,905 @@
+//! Fonctions spatiales SQL pour construire des requêtes avec sea-query.
+//!
+//! Chaque fonction produit une expression typée ([BoolExpr], [F64Expr], [GeomExpr])
+//! utilisable comme n'importe quelle expression sea-query, sans avoir à écrire de
+//! `Func::cust` à la main.
+use sea_query::{Alias, Expr, Func, FunctionCall, SimpleExpr};
+
+use crate::{error::Error, types::MBR2D};
+
+mod dialect;
+mod expr;
+mod ext;
+pub mod operators;
+pub mod spatialite;
+
+pub use dialect::Dialect;
+pub use expr::{BoolExpr, F64Expr, GeomExpr};
+pub use ext::SpatialExprExt;
+
+#[doc(hidden)]
+pub use sea_query;
+
+/// Déclare une fonction spatiale supplémentaire produisant un [FunctionCall].
+///
+/// ```
+/// use sea_query::{Alias, Expr};
+/// use sql_gis::define_st_function;
+///
+/// define_st_function!(
+///     /// `ST_LineInterpolatePoint(line, fraction)`
+///     st_lineinterpolatepoint, "ST_LineInterpolatePoint", 2
+/// );
+///
+/// let call = st_lineinterpolatepoint(Expr::col(Alias::new("geom")), 0.5);
+/// ```
+#[macro_export]
+macro_rules! define_st_function {
+    ($(#[$meta:meta])* $name:ident, $sql:literal, 0) => {
+        $(#[$meta])*
+        pub fn $name() -> $crate::functions::sea_query::FunctionCall {
+            $crate::functions::sea_query::Func::cust($crate::functions::sea_query::Alias::new($sql))
+        }
+    };
+    ($(#[$meta:meta])* $name:ident, $sql:literal, 1) => {
+        $crate::define_st_function!(@args $(#[$meta])* $name, $sql, arg1: A1);
+    };
+    ($(#[$meta:meta])* $name:ident, $sql:literal, 2) => {
+        $crate::define_st_function!(@args $(#[$meta])* $name, $sql, arg1: A1, arg2: A2);
+    };
+    ($(#[$meta:meta])* $name:ident, $sql:literal, 3) => {
+        $crate::define_st_function!(@args $(#[$meta])* $name, $sql, arg1: A1, arg2: A2, arg3: A3);
+    };
+    ($(#[$meta:meta])* $name:ident, $sql:literal, 4) => {
+        $crate::define_st_function!(
+            @args $(#[$meta])* $name, $sql, arg1: A1, arg2: A2, arg3: A3, arg4: A4
+        );
+    };
+    ($(#[$meta:meta])* $name:ident, $sql:literal, 5) => {
+        $crate::define_st_function!(
+            @args $(#[$meta])* $name, $sql, arg1: A1, arg2: A2, arg3: A3, arg4: A4, arg5: A5
+        );
+    };
+    (@args $(#[$meta:meta])* $name:ident, $sql:literal, $($arg:ident: $ty:ident),+) => {
+        $(#[$meta])*
+        pub fn $name<$($ty),+>($($arg: $ty),+) -> $crate::functions::sea_query::FunctionCall
+        where
+            $($ty: Into<$crate::functions::sea_query::SimpleExpr>),+
+        {
+            $crate::functions::sea_query::Func::cust($crate::functions::sea_query::Alias::new($sql))
+                $(.arg($arg))+
+        }
+    };
+}
+
+/// Nombre maximal d'arguments accepté par [st_raw].
+pub const ST_RAW_MAX_ARITY: usize = 10;
+
+/// Nombre d'arguments, minimal et maximal, des fonctions PostGIS connues de [st_raw].
+const ST_RAW_ARITIES: &[(&str, usize, usize)] = &[
+    ("ST_Area", 1, 2),
+    ("ST_Boundary", 1, 1),
+    ("ST_Buffer", 2, 3),
+    ("ST_Centroid", 1, 2),
+    ("ST_ConvexHull", 1, 1),
+    ("ST_CoveredBy", 2, 2),
+    ("ST_Covers", 2, 2),
+    ("ST_Crosses", 2, 2),
+    ("ST_Difference", 2, 3),
+    ("ST_Disjoint", 2, 2),
+    ("ST_EndPoint", 1, 1),
+    ("ST_Envelope", 1, 1),
+    ("ST_Equals", 2, 2),
+    ("ST_Force2D", 1, 1),
+    ("ST_GeometryN", 2, 2),
+    ("ST_Intersection", 2, 3),
+    ("ST_IsValid", 1, 2),
+    ("ST_Length", 1, 2),
+    ("ST_LineInterpolatePoint", 2, 3),
+    ("ST_LineLocatePoint", 2, 3),
+    ("ST_LineSubstring", 3, 3),
+    ("ST_MakeValid", 1, 2),
+    ("ST_NPoints", 1, 1),
+    ("ST_NumGeometries", 1, 1),
+    ("ST_Overlaps", 2, 2),
+    ("ST_Perimeter", 1, 2),
+    ("ST_PointOnSurface", 1, 1),
+    ("ST_Reverse", 1, 1),
+    ("ST_Segmentize", 2, 2),
+    ("ST_SetSRID", 2, 2),
+    ("ST_Simplify", 2, 3),
+    ("ST_SimplifyPreserveTopology", 2, 2),
+    ("ST_Snap", 3, 3),
+    ("ST_SRID", 1, 1),
+    ("ST_StartPoint", 1, 1),
+    ("ST_SymDifference", 2, 3),
+    ("ST_Touches", 2, 2),
+    ("ST_Transform", 2, 3),
+    ("ST_Union", 1, 3),
+    ("ST_X", 1, 1),
+    ("ST_Y", 1, 1),
+    ("ST_Z", 1, 1),
+];
+
+/// Appel brut d'une fonction spatiale non encore couverte par ce module.
+///
+/// Le nom doit être de la forme `ST_[A-Za-z0-9_]+`, préfixe compris sans tenir compte
+/// de la casse (au plus 63 caractères, la limite des identifiants Postgres), afin qu'une entrée utilisateur ne puisse pas injecter
+/// du SQL. Pour les fonctions PostGIS courantes, le nombre d'arguments est vérifié
+/// contre leur signature ; pour les autres, seul le nom est validé et le nombre
+/// d'arguments ne doit pas dépasser [ST_RAW_MAX_ARITY].
+pub fn st_raw<I, E>(name: &str, args: I) -> Result<FunctionCall, Error>
+where
+    I: IntoIterator<Item = E>,
+    E: Into<SimpleExpr>,
+{
+    // Les identifiants Postgres non protégés ne sont pas sensibles à la casse.
+    let is_valid_name = name.len() <= 63
+        && name
+            .get(..3)
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case("ST_"))
+        && name.get(3..).is_some_and(|suffix| {
+            !suffix.is_empty()
+                && suffix
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_')
+        });
+
+    if !is_valid_name {
+        return Err(Error::invalid_function_call(
+            name,
+            "the name must match ST_[A-Za-z0-9_]+",
+        ));
+    }
+
+    let args = args
+        .into_iter()
+        .map(Into::into)
+        .collect::<Vec<SimpleExpr>>();
+
+    if args.len() > ST_RAW_MAX_ARITY {
+        return Err(Error::invalid_function_call(name, "too many arguments"));
+    }
+
+    let arity = ST_RAW_ARITIES
+        .iter()
+        .find(|(known, ..)| known.eq_ignore_ascii_case(name));
+
+    if let Some((_, min, max)) = arity {
+        if args.len() < *min || args.len() > *max {
+            return Err(Error::invalid_function_call(
+                name,
+                "wrong number of arguments",
+            ));
+        }
+    }
+
+    Ok(Func::cust(Alias::new(name)).args(args))
+}
+
+/// `ST_Distance(geom1, geom2)`, distance minimale entre deux géométries.
+pub fn st_distance<A, B>(geom1: A, geom2: B) -> F64Expr
+where
+    A: Into<GeomExpr>,
+    B: Into<GeomExpr>,
+{
+    Func::cust(Alias::new("ST_Distance"))
+        .arg(geom(geom1))
+        .arg(geom(geom2))
+        .into()
+}
+
+/// `ST_Within(geom1, geom2)`, vrai si `geom1` est entièrement contenue dans `geom2`.
+pub fn st_within<A, B>(geom1: A, geom2: B) -> BoolExpr
+where
+    A: Into<GeomExpr>,
+    B: Into<GeomExpr>,
+{
+    Func::cust(Alias::new("ST_Within"))
+        .arg(geom(geom1))
+        .arg(geom(geom2))
+        .into()
+}
+
+/// `ST_Intersects(geom1, geom2)`, vrai si les deux géométries partagent au moins un point.
+pub fn st_intersects<A, B>(geom1: A, geom2: B) -> BoolExpr
+where
+    A: Into<GeomExpr>,
+    B: Into<GeomExpr>,
+{
+    Func::cust(Alias::new("ST_Intersects"))
+        .arg(geom(geom1))
+        .arg(geom(geom2))
+        .into()
+}
+
+/// `ST_Contains(geom1, geom2)`, vrai si `geom1` contient entièrement `geom2`.
+pub fn st_contains<A, B>(geom1: A, geom2: B) -> BoolExpr
+where
+    A: Into<GeomExpr>,
+    B: Into<GeomExpr>,
+{
+    Func::cust(Alias::new("ST_Contains"))
+        .arg(geom(geom1))
+        .arg(geom(geom2))
+        .into()
+}
+
+/// `ST_Overlaps(geom1, geom2)`, vrai si les géométries se chevauchent partiellement, à dimension égale.
+pub fn st_overlaps<A, B>(geom1: A, geom2: B) -> BoolExpr
+where
+    A: Into<GeomExpr>,
+    B: Into<GeomExpr>,
+{
+    Func::cust(Alias::new("ST_Overlaps"))
+        .arg(geom(geom1))
+        .arg(geom(geom2))
+        .into()
+}
+
+/// `ST_Touches(geom1, geom2)`, vrai si les géométries ne se touchent que par leurs bords.
+pub fn st_touches<A, B>(geom1: A, geom2: B) -> BoolExpr
+where
+    A: Into<GeomExpr>,
+    B: Into<GeomExpr>,
+{
+    Func::cust(Alias::new("ST_Touches"))
+        .arg(geom(geom1))
+        .arg(geom(geom2))
+        .into()
+}
+
+/// `ST_Crosses(geom1, geom2)`, vrai si les géométries se croisent.
+pub fn st_crosses<A, B>(geom1: A, geom2: B) -> BoolExpr
+where
+    A: Into<GeomExpr>,
+    B: Into<GeomExpr>,
+{
+    Func::cust(Alias::new("ST_Crosses"))
+        .arg(geom(geom1))
+        .arg(geom(geom2))
+        .into()
+}
+
+/// `ST_Disjoint(geom1, geom2)`, vrai si les géométries n'ont aucun point commun.
+pub fn st_disjoint<A, B>(geom1: A, geom2: B) -> BoolExpr
+where
+    A: Into<GeomExpr>,
+    B: Into<GeomExpr>,
+{
+    Func::cust(Alias::new("ST_Disjoint"))
+        .arg(geom(geom1))
+        .arg(geom(geom2))
+        .into()
+}
+
+/// `ST_Equals(geom1, geom2)`, vrai si les géométries sont spatialement égales.
+pub fn st_equals<A, B>(geom1: A, geom2: B) -> BoolExpr
+where
+    A: Into<GeomExpr>,
+    B: Into<GeomExpr>,
+{
+    Func::cust(Alias::new("ST_Equals"))
+        .arg(geom(geom1))
+        .arg(geom(geom2))
+        .into()
+}
+
+/// `ST_Covers(geom1, geom2)`, vrai si aucun point de `geom2` n'est à l'extérieur de `geom1`.
+pub fn st_covers<A, B>(geom1: A, geom2: B) -> BoolExpr
+where
+    A: Into<GeomExpr>,
+    B: Into<GeomExpr>,
+{
+    Func::cust(Alias::new("ST_Covers"))
+        .arg(geom(geom1))
+        .arg(geom(geom2))
+        .into()
+}
+
+/// `ST_DWithin(geom1, geom2, distance)`, vrai si les géométries sont à moins de
+/// `distance` l'une de l'autre, exprimée dans l'unité du système de référence.
+///
+/// Voir [Dialect::st_dwithin] pour SpatiaLite.
+pub fn st_dwithin<A, B>(geom1: A, geom2: B, distance: f64) -> BoolExpr
+where
+    A: Into<GeomExpr>,
+    B: Into<GeomExpr>,
+{
+    Dialect::PostGis.st_dwithin(geom1, geom2, distance)
+}
+
+/// `ST_DWithin(geog1, geog2, distance, use_spheroid)` sur des géographies,
+/// la distance étant exprimée en mètres.
+pub fn st_dwithin_geography<A, B>(geog1: A, geog2: B, distance: f64, use_spheroid: bool) -> BoolExpr
+where
+    A: Into<GeomExpr>,
+    B: Into<GeomExpr>,
+{
+    Func::cust(Alias::new("ST_DWithin"))
+        .arg(geom(geog1))
+        .arg(geom(geog2))
+        .arg(distance)
+        .arg(use_spheroid)
+        .into()
+}
+
+/// `geom::geography`, nécessaire pour mesurer en mètres sur des coordonnées lon/lat.
+pub fn as_geography<G>(geom: G) -> GeomExpr
+where
+    G: Into<GeomExpr>,
+{
+    Expr::cust_with_expr("$1::geography", self::geom(geom)).into()
+}
+
+/// `geog::geometry`, l'inverse de [as_geography].
+pub fn as_geometry<G>(geog: G) -> GeomExpr
+where
+    G: Into<GeomExpr>,
+{
+    Expr::cust_with_expr("$1::geometry", geom(geog)).into()
+}
+
+/// `ST_Area(geom)`, aire d'une surface.
+pub fn st_area<G>(geom: G) -> F64Expr
+where
+    G: Into<GeomExpr>,
+{
+    Func::cust(Alias::new("ST_Area"))
+        .arg(self::geom(geom))
+        .into()
+}
+
+/// `ST_Length(geom)`, longueur d'une géométrie linéaire.
+pub fn st_length<G>(geom: G) -> F64Expr
+where
+    G: Into<GeomExpr>,
+{
+    Func::cust(Alias::new("ST_Length"))
+        .arg(self::geom(geom))
+        .into()
+}
+
+/// `ST_Perimeter(geom)`, périmètre d'une surface.
+pub fn st_perimeter<G>(geom: G) -> F64Expr
+where
+    G: Into<GeomExpr>,
+{
+    Func::cust(Alias::new("ST_Perimeter"))
+        .arg(self::geom(geom))
+        .into()
+}
+
+/// `ST_3DDistance(geom1, geom2)`, distance cartésienne minimale en 3D.
+pub fn st_3ddistance<A, B>(geom1: A, geom2: B) -> F64Expr
+where
+    A: Into<GeomExpr>,
+    B: Into<GeomExpr>,
+{
+    Func::cust(Alias::new("ST_3DDistance"))
+        .arg(geom(geom1))
+        .arg(geom(geom2))
+        .into()
+}
+
+/// `ST_Centroid(geom)`, centre géométrique d'une géométrie.
+pub fn st_centroid<G>(geom: G) -> GeomExpr
+where
+    G: Into<GeomExpr>,
+{
+    Func::cust(Alias::new("ST_Centroid"))
+        .arg(self::geom(geom))
+        .into()
+}
+
+/// `ST_Buffer(geom, radius)`, zone tampon autour d'une géométrie.
+pub fn st_buffer<G>(geom: G, radius: f64) -> GeomExpr
+where
+    G: Into<GeomExpr>,
+{
+    Func::cust(Alias::new("ST_Buffer"))
+        .arg(self::geom(geom))
+        .arg(radius)
+        .into()
+}
+
+/// Agrégat `ST_Collect(geom)`, rassemble les géométries d'un groupe en une collection.
+pub fn st_collect<G>(geom: G) -> GeomExpr
+where
+    G: Into<GeomExpr>,
+{
+    Func::cust(Alias::new("ST_Collect"))
+        .arg(self::geom(geom))
+        .into()
+}
+
+/// Agrégat `ST_Union(geom)`, fusionne les géométries d'un groupe.
+pub fn st_union_agg<G>(geom: G) -> GeomExpr
+where
+    G: Into<GeomExpr>,
+{
+    Func::cust(Alias::new("ST_Union"))
+        .arg(self::geom(geom))
+        .into()
+}
+
+/// Agrégat `ST_Extent(geom)`, rectangle englobant les géométries d'un groupe.
+///
+/// Le résultat est un `box2d`, à convertir en texte pour être décodé avec
+/// [crate::sql_types::Box2D] :
+///
+/// ```
+/// use sea_query::{Alias, Expr, PostgresQueryBuilder, Query};
+/// use sql_gis::functions::st_extent;
+///
+/// let query = Query::select()
+///     .expr(Expr::expr(st_extent(Expr::col(Alias::new("geom")))).cast_as(Alias::new("text")))
+///     .from(Alias::new("parcels"))
+///     .to_string(PostgresQueryBuilder);
+///
+/// assert_eq!(query, r#"SELECT CAST(ST_Extent("geom") AS text) FROM "parcels""#);
+/// ```
+///
+/// Voir [Dialect::st_extent] pour SpatiaLite.
+pub fn st_extent<G>(geom: G) -> FunctionCall
+where
+    G: Into<GeomExpr>,
+{
+    Dialect::PostGis.st_extent(geom)
+}
+
+/// `ST_Transform(geom, srid)`, reprojette une géométrie dans un autre système de référence.
+pub fn st_transform<G>(geom: G, srid: u32) -> GeomExpr
+where
+    G: Into<GeomExpr>,
+{
+    Func::cust(Alias::new("ST_Transform"))
+        .arg(self::geom(geom))
+        .arg(srid)
+        .into()
+}
+
+/// `ST_SetSRID(geom, srid)`, affecte un SRID à une géométrie sans la reprojeter.
+pub fn st_setsrid<G>(geom: G, srid: u32) -> GeomExpr
+where
+    G: Into<GeomExpr>,
+{
+    Dialect::PostGis.st_setsrid(geom, srid)
+}
+
+/// `ST_GeomFromGeoJSON(geojson)`, construit une géométrie depuis un texte GeoJSON.
+///
+/// Voir [Dialect::st_geomfromgeojson] pour SpatiaLite.
+pub fn st_geomfromgeojson<G>(geojson: G) -> GeomExpr
+where
+    G: Into<SimpleExpr>,
+{
+    Dialect::PostGis.st_geomfromgeojson(geojson)
+}
+
+/// `ST_MakePoint(x, y)`, construit un point 2D.
+///
+/// Voir [Dialect::st_makepoint] pour SpatiaLite.
+pub fn st_makepoint<X, Y>(x: X, y: Y) -> GeomExpr
+where
+    X: Into<SimpleExpr>,
+    Y: Into<SimpleExpr>,
+{
+    Dialect::PostGis.st_makepoint(x, y)
+}
+
+/// `ST_MakeEnvelope(xmin, ymin, xmax, ymax[, srid])`, rectangle construit à partir d'une emprise.
+///
+/// Voir [Dialect::st_makeenvelope] pour SpatiaLite.
+pub fn st_makeenvelope(mbr: &MBR2D, srid: Option<u32>) -> GeomExpr {
+    Dialect::PostGis.st_makeenvelope(mbr, srid)
+}
+
+/// `ST_TileEnvelope(z, x, y)`, emprise d'une tuile en Web Mercator (EPSG:3857).
+pub fn st_tileenvelope(z: u32, x: u32, y: u32) -> GeomExpr {
+    Func::cust(Alias::new("ST_TileEnvelope"))
+        .arg(z)
+        .arg(x)
+        .arg(y)
+        .into()
+}
+
+/// `ST_AsMVTGeom(geom, bounds)`, projette une géométrie dans l'espace de coordonnées
+/// d'une tuile vectorielle.
+pub fn st_asmvtgeom<G, B>(geom: G, bounds: B) -> GeomExpr
+where
+    G: Into<GeomExpr>,
+    B: Into<GeomExpr>,
+{
+    Func::cust(Alias::new("ST_AsMVTGeom"))
+        .arg(self::geom(geom))
+        .arg(self::geom(bounds))
+        .into()
+}
+
+/// `ST_AsMVTGeom(geom, bounds, extent, buffer, clip_geom)`.
+pub fn st_asmvtgeom_with<G, B>(geom: G, bounds: B, extent: u32, buffer: u32, clip: bool) -> GeomExpr
+where
+    G: Into<GeomExpr>,
+    B: Into<GeomExpr>,
+{
+    Func::cust(Alias::new("ST_AsMVTGeom"))
+        .arg(self::geom(geom))
+        .arg(self::geom(bounds))
+        .arg(extent)
+        .arg(buffer)
+        .arg(clip)
+        .into()
+}
+
+/// Agrégat `ST_AsMVT(row, name)`, encode les lignes d'un groupe en une couche
+/// de tuile vectorielle (Mapbox Vector Tile).
+///
+/// `row` désigne la ligne entière, typiquement l'alias d'une sous-requête.
+pub fn st_asmvt<R>(row: R, layer_name: &str) -> FunctionCall
+where
+    R: Into<SimpleExpr>,
+{
+    Func::cust(Alias::new("ST_AsMVT")).arg(row).arg(layer_name)
+}
+
+/// `ST_AsText(geom)`, représentation WKT d'une géométrie.
+pub fn st_astext<G>(geom: G) -> FunctionCall
+where
+    G: Into<GeomExpr>,
+{
+    Func::cust(Alias::new("ST_AsText")).arg(self::geom(geom))
+}
+
+/// `ST_AsEWKT(geom)`, représentation EWKT (WKT préfixé du SRID) d'une géométrie.
+pub fn st_asewkt<G>(geom: G) -> FunctionCall
+where
+    G: Into<GeomExpr>,
+{
+    Dialect::PostGis.st_asewkt(geom)
+}
+
+/// `ST_AsBinary(geom)`, représentation WKB d'une géométrie.
+///
+/// Le résultat peut être décodé avec [crate::sql_types::WkbBytes].
+pub fn st_asbinary<G>(geom: G) -> FunctionCall
+where
+    G: Into<GeomExpr>,
+{
+    Func::cust(Alias::new("ST_AsBinary")).arg(self::geom(geom))
+}
+
+/// `ST_AsGeoJSON(geom)`, représentation GeoJSON d'une géométrie.
+///
+/// Le résultat peut être décodé avec [crate::sql_types::GeoJsonText].
+pub fn st_asgeojson<G>(geom: G) -> FunctionCall
+where
+    G: Into<GeomExpr>,
+{
+    Dialect::PostGis.st_asgeojson(geom)
+}
+
+/// `ST_AsGeoJSON(geom, max_decimal_digits, options)`.
+pub fn st_asgeojson_with<G>(
+    geom: G,
+    max_decimal_digits: u32,
+    options: GeoJsonOptions,
+) -> FunctionCall
+where
+    G: Into<GeomExpr>,
+{
+    Dialect::PostGis
+        .st_asgeojson(geom)
+        .arg(max_decimal_digits)
+        .arg(options.bits())
+}
+
+/// Convertit un argument géométrique en expression sea-query.
+pub(crate) fn geom<G: Into<GeomExpr>>(geom: G) -> SimpleExpr {
+    geom.into().into_simple_expr()
+}
+
+/// Options de `ST_AsGeoJSON`, combinables avec `|`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GeoJsonOptions(i32);
+
+impl GeoJsonOptions {
+    /// Aucune option.
+    pub const NONE: Self = Self(0);
+    /// Ajoute la boîte englobante (`bbox`).
+    pub const BBOX: Self = Self(1);
+    /// Ajoute le CRS au format court (`EPSG:4326`).
+    pub const SHORT_CRS: Self = Self(2);
+    /// Ajoute le CRS au format long (`urn:ogc:def:crs:EPSG::4326`).
+    pub const LONG_CRS: Self = Self(4);
+    /// Ajoute le CRS au format court s'il ne s'agit pas d'EPSG:4326.
+    pub const SHORT_CRS_IF_NOT_4326: Self = Self(8);
+
+    pub fn bits(&self) -> i32 {
+        self.0
+    }
+}
+
+impl Default for GeoJsonOptions {
+    /// Valeur par défaut de PostGIS.
+    fn default() -> Self {
+        Self::SHORT_CRS_IF_NOT_4326
+    }
+}
+
+impl std::ops::BitOr for GeoJsonOptions {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_query::{Alias, Expr, PostgresQueryBuilder, Query};
+
+    use super::{operators::bbox_overlaps, *};
+
+    #[test]
+    fn test_measurement_functions() {
+        let query = Query::select()
+            .expr(st_area(Expr::col(Alias::new("geom"))))
+            .expr(st_3ddistance(
+                Expr::col(Alias::new("a")),
+                Expr::col(Alias::new("b")),
+            ))
+            .from(Alias::new("parcels"))
+            .to_string(PostgresQueryBuilder);
+
+        assert_eq!(
+            query,
+            r#"SELECT ST_Area("geom"), ST_3DDistance("a", "b") FROM "parcels""#
+        );
+    }
+
+    #[test]
+    fn test_predicate_functions() {
+        let query = Query::select()
+            .column(Alias::new("id"))
+            .from(Alias::new("parcels"))
+            .and_where(
+                Expr::expr(st_intersects(
+                    Expr::col(Alias::new("geom")),
+                    Expr::col(Alias::new("zone")),
+                ))
+                .into(),
+            )
+            .to_string(PostgresQueryBuilder);
+
+        assert_eq!(
+            query,
+            r#"SELECT "id" FROM "parcels" WHERE ST_Intersects("geom", "zone")"#
+        );
+    }
+
+    #[test]
+    fn test_envelope_filter() {
+        let extent = MBR2D::new([2.0, 48.0], [3.0, 49.0]);
+
+        let query = Query::select()
+            .column(Alias::new("id"))
+            .from(Alias::new("parcels"))
+            .and_where(st_intersects(Expr::col(Alias::new("geom")), (extent, 2154)).into())
+            .and_where(
+                st_within(
+                    Expr::col(Alias::new("geom")),
+                    st_makeenvelope(&extent, Some(4326)),
+                )
+                .into(),
+            )
+            .to_string(PostgresQueryBuilder);
+
+        assert_eq!(
+            query,
+            r#"SELECT "id" FROM "parcels" WHERE ST_Intersects("geom", ST_MakeEnvelope(2, 48, 3, 49, 2154)) AND ST_Within("geom", ST_MakeEnvelope(2, 48, 3, 49, 4326))"#
+        );
+    }
+
+    #[test]
+    fn test_geography_cast() {
+        let query = Query::select()
+            .column(Alias::new("id"))
+            .from(Alias::new("shops"))
+            .and_where(
+                st_dwithin_geography(
+                    as_geography(Expr::col(Alias::new("location"))),
+                    as_geography(st_makepoint(2.35, 48.85)),
+                    500.0,
+                    true,
+                )
+                .into(),
+            )
+            .to_string(PostgresQueryBuilder);
+
+        assert_eq!(
+            query,
+            r#"SELECT "id" FROM "shops" WHERE ST_DWithin("location"::geography, ST_MakePoint(2.35, 48.85)::geography, 500, TRUE)"#
+        );
+    }
+
+    #[test]
+    fn test_vector_tile() {
+        let bounds = st_tileenvelope(12, 2074, 1409);
+
+        let tile = Query::select()
+            .expr_as(
+                st_asmvtgeom(
+                    st_transform(Expr::col(Alias::new("geom")), 3857),
+                    bounds.clone(),
+                ),
+                Alias::new("geom"),
+            )
+            .column(Alias::new("name"))
+            .from(Alias::new("roads"))
+            .and_where(
+                bbox_overlaps(Expr::col(Alias::new("geom")), st_transform(bounds, 4326)).into(),
+            )
+            .to_owned();
+
+        let query = Query::select()
+            .expr(st_asmvt(Expr::col(Alias::new("mvtgeom")), "roads"))
+            .from_subquery(tile, Alias::new("mvtgeom"))
+            .to_string(PostgresQueryBuilder);
+
+        assert_eq!(
+            query,
+            concat!(
+                r#"SELECT ST_AsMVT("mvtgeom", 'roads') FROM "#,
+                r#"(SELECT ST_AsMVTGeom(ST_Transform("geom", 3857), ST_TileEnvelope(12, 2074, 1409)) AS "geom", "name" "#,
+                r#"FROM "roads" WHERE "geom" && ST_Transform(ST_TileEnvelope(12, 2074, 1409), 4326)) AS "mvtgeom""#
+            )
+        );
+    }
+
+    #[test]
+    fn test_aggregate_functions() {
+        let query = Query::select()
+            .expr(st_union_agg(Expr::col(Alias::new("geom"))))
+            .expr(st_collect(Expr::col(Alias::new("geom"))))
+            .from(Alias::new("parcels"))
+            .group_by_col(Alias::new("owner"))
+            .to_string(PostgresQueryBuilder);
+
+        assert_eq!(
+            query,
+            r#"SELECT ST_Union("geom"), ST_Collect("geom") FROM "parcels" GROUP BY "owner""#
+        );
+    }
+
+    #[test]
+    fn test_typed_composition() {
+        let query = Query::select()
+            .expr(st_buffer(st_centroid(Expr::col(Alias::new("geom"))), 5.0))
+            .from(Alias::new("parcels"))
+            .and_where(
+                st_area(Expr::col(Alias::new("geom")))
+                    .gt(1000.0)
+                    .and(st_within(
+                        Expr::col(Alias::new("geom")),
+                        Expr::col(Alias::new("zone")),
+                    ))
+                    .into(),
+            )
+            .to_string(PostgresQueryBuilder);
+
+        assert_eq!(
+            query,
+            r#"SELECT ST_Buffer(ST_Centroid("geom"), 5) FROM "parcels" WHERE ST_Area("geom") > 1000 AND ST_Within("geom", "zone")"#
+        );
+    }
+
+    #[test]
+    fn test_dwithin() {
+        let query = Query::select()
+            .column(Alias::new("id"))
+            .from(Alias::new("shops"))
+            .and_where(
+                st_dwithin_geography(
+                    Expr::col(Alias::new("geog")),
+                    Expr::col(Alias::new("origin")),
+                    500.5,
+                    true,
+                )
+                .into(),
+            )
+            .to_string(PostgresQueryBuilder);
+
+        assert_eq!(
+            query,
+            r#"SELECT "id" FROM "shops" WHERE ST_DWithin("geog", "origin", 500.5, TRUE)"#
+        );
+    }
+
+    #[test]
+    fn test_asgeojson_with_options() {
+        let query = Query::select()
+            .expr(st_asgeojson_with(
+                Expr::col(Alias::new("geom")),
+                6,
+                GeoJsonOptions::BBOX | GeoJsonOptions::SHORT_CRS,
+            ))
+            .from(Alias::new("parcels"))
+            .to_string(PostgresQueryBuilder);
+
+        assert_eq!(query, r#"SELECT ST_AsGeoJSON("geom", 6, 3) FROM "parcels""#);
+    }
+
+    #[test]
+    fn test_transform() {
+        let query = Query::select()
+            .expr(st_transform(
+                st_setsrid(Expr::col(Alias::new("geom")), 2154),
+                4326,
+            ))
+            .from(Alias::new("parcels"))
+            .to_string(PostgresQueryBuilder);
+
+        assert_eq!(
+            query,
+            r#"SELECT ST_Transform(ST_SetSRID("geom", 2154), 4326) FROM "parcels""#
+        );
+    }
+
+    #[test]
+    fn test_st_raw() {
+        let call = st_raw(
+            "ST_LineSubstring",
+            [
+                Expr::col(Alias::new("geom")).into(),
+                SimpleExpr::from(0.25),
+                SimpleExpr::from(0.75),
+            ],
+        )
+        .expect("valid function call");
+
+        assert_eq!(
+            Query::select().expr(call).to_string(PostgresQueryBuilder),
+            r#"SELECT ST_LineSubstring("geom", 0.25, 0.75)"#
+        );
+
+        assert!(st_raw("pg_sleep", [SimpleExpr::from(10)]).is_err());
+        assert!(st_raw(
+            "ST_Area(geom); DROP TABLE parcels; --",
+            [SimpleExpr::from(1)]
+        )
+        .is_err());
+        assert!(st_raw("ST_", [SimpleExpr::from(1)]).is_err());
+        assert!(st_raw(
+            "ST_Area",
+            (0..=ST_RAW_MAX_ARITY as i32).map(SimpleExpr::from)
+        )
+        .is_err());
+
+        // Fonctions connues : le nombre d'arguments suit leur signature.
+        assert!(st_raw("ST_SetSRID", [SimpleExpr::from(1)]).is_err());
+        assert!(st_raw("ST_Area", (0..3).map(SimpleExpr::from)).is_err());
+        assert!(st_raw("st_area", (0..3).map(SimpleExpr::from)).is_err());
+        assert!(st_raw("ST_Area", [SimpleExpr::from(1)]).is_ok());
+        assert!(st_raw("st_area", (0..2).map(SimpleExpr::from)).is_ok());
+        assert!(st_raw("ST_SetSRID", (0..2).map(SimpleExpr::from)).is_ok());
+
+        // Fonctions inconnues : seul le maximum global s'applique.
+        assert!(st_raw("ST_Foo", (0..4).map(SimpleExpr::from)).is_ok());
+    }
+
+    crate::define_st_function!(st_makeline, "ST_MakeLine", 2);
+
+    #[test]
+    fn test_define_st_function() {
+        let query = Query::select()
+            .expr(st_makeline(
+                Expr::col(Alias::new("a")),
+                Expr::col(Alias::new("b")),
+            ))
+            .from(Alias::new("points"))
+            .to_string(PostgresQueryBuilder);
+
+        assert_eq!(query, r#"SELECT ST_MakeLine("a", "b") FROM "points""#);
+    }
+}
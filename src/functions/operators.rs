@@ -0,0 +1,72 @@
+//! Opérateurs spatiaux de PostGIS, qui ne peuvent pas s'exprimer sous forme de fonction.
+use sea_query::BinOper;
+
+use super::{geom, BoolExpr, F64Expr, GeomExpr};
+
+/// `geom1 && geom2`, vrai si les boîtes englobantes des géométries se chevauchent.
+///
+/// Contrairement aux prédicats `ST_*`, l'opérateur est directement résolu par l'index spatial.
+pub fn bbox_overlaps<A, B>(geom1: A, geom2: B) -> BoolExpr
+where
+    A: Into<GeomExpr>,
+    B: Into<GeomExpr>,
+{
+    geom(geom1)
+        .binary(BinOper::Custom("&&"), geom(geom2))
+        .into()
+}
+
+/// `geom1 <-> geom2`, distance entre deux géométries utilisée pour le tri KNN
+/// (`ORDER BY geom <-> point`).
+pub fn knn_distance<A, B>(geom1: A, geom2: B) -> F64Expr
+where
+    A: Into<GeomExpr>,
+    B: Into<GeomExpr>,
+{
+    geom(geom1)
+        .binary(BinOper::Custom("<->"), geom(geom2))
+        .into()
+}
+
+/// `geom1 <#> geom2`, distance entre les boîtes englobantes de deux géométries.
+pub fn bbox_distance<A, B>(geom1: A, geom2: B) -> F64Expr
+where
+    A: Into<GeomExpr>,
+    B: Into<GeomExpr>,
+{
+    geom(geom1)
+        .binary(BinOper::Custom("<#>"), geom(geom2))
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_query::{Alias, Expr, Order, PostgresQueryBuilder, Query};
+
+    use super::*;
+
+    #[test]
+    fn test_knn_ordering() {
+        let query = Query::select()
+            .column(Alias::new("id"))
+            .from(Alias::new("shops"))
+            .and_where(
+                bbox_overlaps(Expr::col(Alias::new("geom")), Expr::col(Alias::new("zone"))).into(),
+            )
+            .order_by_expr(
+                knn_distance(
+                    Expr::col(Alias::new("geom")),
+                    Expr::col(Alias::new("origin")),
+                )
+                .into(),
+                Order::Asc,
+            )
+            .limit(5)
+            .to_string(PostgresQueryBuilder);
+
+        assert_eq!(
+            query,
+            r#"SELECT "id" FROM "shops" WHERE "geom" && "zone" ORDER BY "geom" <-> "origin" ASC LIMIT 5"#
+        );
+    }
+}
@@ -0,0 +1,60 @@
+//! Constructeurs propres à SpatiaLite.
+//!
+//! Les fonctions que SpatiaLite nomme autrement que PostGIS sont construites par
+//! [Dialect::SpatiaLite](super::Dialect::SpatiaLite) ; ce module réunit ce qui
+//! n'a pas d'équivalent dans PostGIS, comme la table virtuelle `KNN`.
+use sea_query::{Alias, Expr, Query, SelectStatement};
+
+use super::{geom, GeomExpr};
+
+/// Requête sur la table virtuelle `KNN`, renvoyant les `max_items` plus proches
+/// voisins de `ref_geometry` dans `table.geometry_column`.
+///
+/// Les colonnes sélectionnées sont `pos`, `fid` (le rowid de l'entité) et `distance`.
+pub fn knn<G>(
+    table: &str,
+    geometry_column: &str,
+    ref_geometry: G,
+    max_items: u32,
+) -> SelectStatement
+where
+    G: Into<GeomExpr>,
+{
+    Query::select()
+        .columns([Alias::new("pos"), Alias::new("fid"), Alias::new("distance")])
+        .from(Alias::new("KNN"))
+        .and_where(Expr::col(Alias::new("f_table_name")).eq(table))
+        .and_where(Expr::col(Alias::new("f_geometry_column")).eq(geometry_column))
+        .and_where(Expr::col(Alias::new("ref_geometry")).eq(geom(ref_geometry)))
+        .and_where(Expr::col(Alias::new("max_items")).eq(max_items))
+        .to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_query::SqliteQueryBuilder;
+
+    use super::*;
+    use crate::functions::Dialect;
+
+    #[test]
+    fn test_knn() {
+        let point = Dialect::SpatiaLite.st_makepoint(2.5, 48.5);
+        let query = knn(
+            "shops",
+            "geom",
+            Dialect::SpatiaLite.st_setsrid(point, 4326),
+            5,
+        )
+        .to_string(SqliteQueryBuilder);
+
+        assert_eq!(
+            query,
+            concat!(
+                r#"SELECT "pos", "fid", "distance" FROM "KNN" "#,
+                r#"WHERE "f_table_name" = 'shops' AND "f_geometry_column" = 'geom' "#,
+                r#"AND "ref_geometry" = SetSRID(MakePoint(2.5, 48.5), 4326) AND "max_items" = 5"#
+            )
+        );
+    }
+}
@@ -1,15 +1,17 @@
+use core::ops::Deref;
 use serde::{
     de::{self, Error as _, Visitor},
     ser::{SerializeMap as _, SerializeSeq},
     Deserialize, Serialize,
 };
-use std::ops::Deref;
+
+use crate::prelude::*;
 
 use crate::types::{
-    self, Coordinates, Geometry, GeometryImpl as _, GeometryKind, LineString, LineStringZ,
-    MultiLineString, MultiLineStringZ, MultiPoint, MultiPointZ, MultiPolygon, MultiPolygonZ, Point,
-    PointZ, Polygon, PolygonZ, Vector, VectorArray, VectorMatrix, VectorTensor,
-    GEOMETRY_COLLECTION_KIND_STR, LINE_STRING_KIND_STR, MULTI_LINE_STRING_KIND_STR,
+    self, Coordinates, Geometry, GeometryImpl as _, GeometryKind, GeometryRef, LineString,
+    LineStringZ, MultiLineString, MultiLineStringZ, MultiPoint, MultiPointZ, MultiPolygon,
+    MultiPolygonZ, Point, PointZ, Polygon, PolygonZ, Vector, VectorArray, VectorMatrix,
+    VectorTensor, GEOMETRY_COLLECTION_KIND_STR, LINE_STRING_KIND_STR, MULTI_LINE_STRING_KIND_STR,
     MULTI_POINT_KIND_STR, MULTI_POLYGON_KIND_STR, POINT_KIND_STR, POLYGON_KIND_STR,
 };
 
@@ -23,6 +25,12 @@ impl GeoJsonGeometry {
     }
 }
 
+impl From<GeoJsonGeometry> for Geometry {
+    fn from(value: GeoJsonGeometry) -> Self {
+        value.0
+    }
+}
+
 impl Deref for GeoJsonGeometry {
     type Target = types::Geometry;
 
@@ -36,15 +44,7 @@ impl Serialize for GeoJsonGeometry {
     where
         S: serde::Serializer,
     {
-        let mut map = serializer.serialize_map(Some(2))?;
-
-        map.serialize_entry("type", self.kind().as_ref())?;
-        map.serialize_entry(
-            "coordinates",
-            &GeoJsonCoordinatesRef(self.borrow_coordinates()),
-        )?;
-
-        map.end()
+        serialize(&self.0, serializer)
     }
 }
 
@@ -53,16 +53,47 @@ impl<'de> Deserialize<'de> for GeoJsonGeometry {
     where
         D: de::Deserializer<'de>,
     {
-        Ok(Self(deserializer.deserialize_map(GeometryVisitor {})?))
+        deserialize(deserializer).map(Self)
     }
 }
 
+/// Sérialise une géométrie en objet GeoJSON, à utiliser via `#[serde(with = "sql_gis::geojson")]`.
+pub fn serialize<S>(geometry: &Geometry, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serialize_ref(geometry.into(), serializer)
+}
+
+fn serialize_ref<S>(geometry: GeometryRef<'_>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    let mut map = serializer.serialize_map(Some(2))?;
+
+    map.serialize_entry("type", geometry.kind().as_ref())?;
+    map.serialize_entry(
+        "coordinates",
+        &GeoJsonCoordinatesRef(geometry.borrow_coordinates()),
+    )?;
+
+    map.end()
+}
+
+/// Désérialise une géométrie depuis un objet GeoJSON, voir [serialize].
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Geometry, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    deserializer.deserialize_map(GeometryVisitor {})
+}
+
 struct GeometryVisitor {}
 
 impl<'de> Visitor<'de> for GeometryVisitor {
     type Value = Geometry;
 
-    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
         formatter.write_str("struct GeoJsonGeometry")
     }
 
@@ -73,8 +104,9 @@ impl<'de> Visitor<'de> for GeometryVisitor {
         let mut kind: Option<GeometryKind> = None;
         let mut coords: Option<Coordinates> = None;
 
-        while let Some(key) = map.next_key()? {
-            match key {
+        // Les clés ne sont pas forcément empruntables (ex. : `serde_json::Value`).
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
                 "type" => {
                     kind = Some(map.next_value::<GeoJsonGeometryKind>()?.0);
                 }
@@ -132,7 +164,7 @@ macro_rules! impl_geometry_geojson {
                 where
                     S: serde::Serializer,
                 {
-                    GeoJsonGeometry::new(Geometry::from(self.0.clone())).serialize(serializer)
+                    serialize_ref((&self.0).into(), serializer)
                 }
             }
 
@@ -177,7 +209,7 @@ struct GeometryKindVisitor {}
 impl<'de> Visitor<'de> for GeometryKindVisitor {
     type Value = GeometryKind;
 
-    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
         write!(formatter, "Point, LineString, Polgygon, MultiPoint, MultiLineString, MultiPolygon, or GeometryCollection")
     }
 
@@ -1,21 +1,635 @@
+//! Traits des codecs, et options de décodage des formats binaires.
+//!
+//! [Encodable] et [Decodable] sont le point d'extension pour un format propre à
+//! l'application ; [DynEncodable] en est la variante utilisable derrière un `dyn`.
+
+// Les outils propres aux formats binaires ne servent pas sans eux.
+#![cfg_attr(
+    not(any(feature = "ewkb", feature = "spatialite-format")),
+    allow(dead_code)
+)]
 use std::io::{Read, Write};
 
+use crate::{
+    error::Error,
+    types::{
+        CoordinatesRef, Geometry, GeometryKind, GeometryRef, VectorMatrix, VectorTensor, MBR2D,
+    },
+};
+
+/// A value which can be written in a binary format.
+///
+/// Implemented by the codec wrappers, such as `EWKBGeometry` or `SpatiaLiteGeometry`.
 pub trait Encodable {
+    /// Write the encoded value at the current position of the stream.
     fn encode<W: Write>(&self, stream: &mut W) -> Result<(), std::io::Error>;
 
-    /// Encode into a vector of bytes.
+    /// Size of the encoded value in bytes, if known without encoding it.
+    ///
+    /// Used to reserve the output buffer up front.
+    fn encoded_size(&self) -> Option<usize> {
+        None
+    }
+
+    /// Encode into a new vector of bytes, allocated once if [Encodable::encoded_size] is known.
     fn encode_to_vec(&self) -> Result<Vec<u8>, std::io::Error> {
-        let mut bytes = Vec::<u8>::default();
+        let mut bytes = Vec::<u8>::with_capacity(self.encoded_size().unwrap_or_default());
         self.encode(&mut bytes)?;
         Ok(bytes)
     }
 }
 
+/// Object-safe counterpart of [Encodable], to hold values of several formats
+/// in a single collection (`Vec<Box<dyn DynEncodable>>`).
+///
+/// Implemented for every [Encodable].
+pub trait DynEncodable {
+    /// See [Encodable::encode].
+    fn encode_dyn(&self, stream: &mut dyn Write) -> Result<(), std::io::Error>;
+
+    /// See [Encodable::encoded_size].
+    fn dyn_encoded_size(&self) -> Option<usize>;
+
+    /// See [Encodable::encode_to_vec].
+    fn encode_dyn_to_vec(&self) -> Result<Vec<u8>, std::io::Error> {
+        let mut bytes = Vec::<u8>::with_capacity(self.dyn_encoded_size().unwrap_or_default());
+        self.encode_dyn(&mut bytes)?;
+        Ok(bytes)
+    }
+}
+
+impl<T: Encodable> DynEncodable for T {
+    fn encode_dyn(&self, mut stream: &mut dyn Write) -> Result<(), std::io::Error> {
+        self.encode(&mut stream)
+    }
+
+    fn dyn_encoded_size(&self) -> Option<usize> {
+        self.encoded_size()
+    }
+}
+
+/// Taille des coordonnées encodées : un compteur `u32` par niveau d'imbrication,
+/// puis les scalaires `f64`. La disposition est commune aux formats EWKB et SpatiaLite.
+pub(crate) fn coordinates_size(coordinates: CoordinatesRef<'_>) -> usize {
+    const COUNT: usize = 4;
+    const SCALAR: usize = 8;
+
+    fn array(n: usize, len: usize) -> usize {
+        COUNT + len * n * SCALAR
+    }
+
+    fn matrix<const N: usize>(matrix: &VectorMatrix<N, f64>) -> usize {
+        COUNT + matrix.iter().map(|a| array(N, a.len())).sum::<usize>()
+    }
+
+    fn tensor<const N: usize>(tensor: &VectorTensor<N, f64>) -> usize {
+        COUNT + tensor.iter().map(matrix).sum::<usize>()
+    }
+
+    match coordinates {
+        CoordinatesRef::Vector2D(_) => 2 * SCALAR,
+        CoordinatesRef::VectorArray2D(a) => array(2, a.len()),
+        CoordinatesRef::VectorMatrix2D(m) => matrix(m),
+        CoordinatesRef::VectorTensor2D(t) => tensor(t),
+        CoordinatesRef::Vector3D(_) => 3 * SCALAR,
+        CoordinatesRef::VectorArray3D(a) => array(3, a.len()),
+        CoordinatesRef::VectorMatrix3D(m) => matrix(m),
+        CoordinatesRef::VectorTensor3D(t) => tensor(t),
+    }
+}
+
+/// A value which can be read from a binary format.
+///
+/// Decoding never panics: a corrupt or truncated input is an [Error].
 pub trait Decodable: Sized {
-    fn decode<R: Read>(stream: &mut R) -> Result<Self, std::io::Error>;
+    /// Read one value from the current position of the stream.
+    ///
+    /// The stream is left right after the value.
+    fn decode<R: Read>(stream: &mut R) -> Result<Self, Error>;
+
+    /// Decode from a slice of bytes, which must hold exactly one value.
+    ///
+    /// Use [Decodable::decode_partial] to allow trailing bytes.
+    fn decode_from_slice(slice: &[u8]) -> Result<Self, Error> {
+        let (value, consumed) = Self::decode_partial(slice)?;
+
+        match slice.len() - consumed {
+            0 => Ok(value),
+            remaining => Err(Error::TrailingBytes {
+                consumed,
+                remaining,
+            }),
+        }
+    }
+
+    /// Decode the first value of a slice of bytes, and return how many bytes it
+    /// consumed, so that concatenated values can be decoded one after the other.
+    fn decode_partial(bytes: &[u8]) -> Result<(Self, usize), Error> {
+        let mut remaining = bytes;
+        let value = Self::decode(&mut remaining)?;
+        Ok((value, bytes.len() - remaining.len()))
+    }
+}
+
+/// Limites appliquées lors du décodage d'une géométrie binaire.
+///
+/// Les compteurs d'un format binaire ne sont pas fiables : un BLOB forgé peut
+/// annoncer des milliards de points. Les décodeurs vérifient chaque compteur
+/// contre ces limites avant toute allocation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecodeLimits {
+    /// Nombre maximal de points, toutes parties confondues.
+    pub max_vertices: usize,
+    /// Nombre maximal d'anneaux, de lignes ou de polygones, toutes parties confondues.
+    pub max_rings: usize,
+    /// Profondeur maximale d'imbrication des géométries.
+    ///
+    /// Chaque en-tête de géométrie compte pour un niveau, avant la lecture de son
+    /// contenu : une multi-géométrie EWKB occupe deux niveaux, le sien et celui de
+    /// ses membres. Les collections n'étant pas prises en charge, il n'y en a pas
+    /// davantage. Le décodeur SpatiaLite ne compte que l'en-tête du BLOB.
+    pub max_nesting: usize,
+    /// Taille maximale du flux lu, en octets.
+    pub max_bytes: usize,
+}
+
+impl Default for DecodeLimits {
+    fn default() -> Self {
+        Self {
+            max_vertices: 10_000_000,
+            max_rings: 1_000_000,
+            max_nesting: 32,
+            max_bytes: 256 * 1024 * 1024,
+        }
+    }
+}
+
+impl DecodeLimits {
+    /// Aucune limite, à réserver aux données de confiance.
+    pub fn unlimited() -> Self {
+        Self {
+            max_vertices: usize::MAX,
+            max_rings: usize::MAX,
+            max_nesting: usize::MAX,
+            max_bytes: usize::MAX,
+        }
+    }
+}
+
+/// Niveau d'exigence du décodage.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Strictness {
+    /// Accepte toute géométrie bien formée au niveau binaire.
+    #[default]
+    Lenient,
+    /// Refuse en plus les coordonnées non finies et les anneaux non fermés.
+    Strict,
+}
+
+/// Traitement du SRID porté par le flux.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SridPolicy {
+    /// Conserve le SRID du flux, y compris son absence.
+    #[default]
+    Keep,
+    /// Attribue ce SRID aux géométries qui n'en portent pas.
+    Fallback(u32),
+    /// Exige ce SRID : une géométrie sans SRID le reçoit, une géométrie
+    /// d'un autre SRID est refusée.
+    Expect(u32),
+}
+
+/// Options de décodage communes aux formats binaires (EWKB, SpatiaLite).
+///
+/// Les options par défaut privilégient la vitesse : seules les limites
+/// d'allocation sont vérifiées.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecodeOptions {
+    pub strictness: Strictness,
+    pub limits: DecodeLimits,
+    pub srid: SridPolicy,
+    /// Si faux, l'emprise embarquée (SpatiaLite) est comparée à celle des coordonnées.
+    pub trust_mbr: bool,
+}
+
+impl Default for DecodeOptions {
+    fn default() -> Self {
+        Self {
+            strictness: Strictness::Lenient,
+            limits: DecodeLimits::default(),
+            srid: SridPolicy::Keep,
+            trust_mbr: true,
+        }
+    }
+}
+
+impl DecodeOptions {
+    /// Options pour des données non fiables : toutes les vérifications sont actives.
+    pub fn strict() -> Self {
+        Self {
+            strictness: Strictness::Strict,
+            trust_mbr: false,
+            ..Self::default()
+        }
+    }
+
+    /// Vérifie et complète une géométrie décodée.
+    pub(crate) fn finish(
+        &self,
+        mut geometry: Geometry,
+        embedded_mbr: Option<&MBR2D>,
+    ) -> Result<Geometry, Error> {
+        match (self.srid, geometry.srid()) {
+            (SridPolicy::Keep, _) => {}
+            (SridPolicy::Fallback(srid) | SridPolicy::Expect(srid), None) => {
+                geometry.set_srid(Some(srid))
+            }
+            (SridPolicy::Fallback(_), Some(_)) => {}
+            (SridPolicy::Expect(srid), Some(got)) if srid == got => {}
+            (SridPolicy::Expect(srid), got) => return Err(Error::srid_mismatch(Some(srid), got)),
+        }
+
+        if self.strictness == Strictness::Strict {
+            let mut finite = true;
+            geometry.map_coordinates(|c| finite &= c.iter().all(|v| v.is_finite()));
+
+            if !finite {
+                return Err(Error::invalid_coordinates("non-finite coordinate"));
+            }
+
+            if !rings_are_closed(&geometry) {
+                return Err(Error::invalid_coordinates("unclosed ring"));
+            }
+        }
+
+        if let (false, Some(embedded)) = (self.trust_mbr, embedded_mbr) {
+            // Une géométrie vide est encodée avec une emprise nulle.
+            let computed = geometry
+                .mbr()
+                .unwrap_or_else(|| MBR2D::new([0.0, 0.0], [0.0, 0.0]));
+
+            if &computed != embedded {
+                return Err(Error::invalid_coordinates(
+                    "embedded MBR does not match the coordinates",
+                ));
+            }
+        }
+
+        Ok(geometry)
+    }
+}
+
+fn rings_are_closed(geometry: &Geometry) -> bool {
+    fn closed<const N: usize>(rings: &VectorMatrix<N, f64>) -> bool {
+        rings
+            .iter()
+            .all(|ring| ring.iter().next() == ring.iter().last())
+    }
+
+    match geometry {
+        Geometry::Polygon(a) => closed(&a.coordinates),
+        Geometry::MultiPolygon(a) => a.coordinates.iter().all(closed),
+        Geometry::PolygonZ(a) => closed(&a.coordinates),
+        Geometry::MultiPolygonZ(a) => a.coordinates.iter().all(closed),
+        _ => true,
+    }
+}
+
+/// Flux comptant les octets lus, pour situer les erreurs de décodage.
+pub(crate) struct CountingReader<'a, R> {
+    inner: &'a mut R,
+    offset: usize,
+}
+
+impl<'a, R: Read> CountingReader<'a, R> {
+    pub fn new(inner: &'a mut R) -> Self {
+        Self { inner, offset: 0 }
+    }
+
+    /// Nombre d'octets lus jusqu'ici.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl<R: Read> Read for CountingReader<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.offset += read;
+        Ok(read)
+    }
+}
+
+/// Observe l'encodage d'une géométrie (fonctionnalité `tracing`).
+///
+/// L'encodage s'exécute dans un span `encode_geometry` ; un évènement rapporte ensuite
+/// le type, le SRID, la taille en octets et la durée, ou l'erreur.
+#[cfg(feature = "tracing")]
+pub(crate) fn trace_encode(
+    format: &'static str,
+    geometry: GeometryRef<'_>,
+    size: impl FnOnce() -> usize,
+    encode: impl FnOnce() -> std::io::Result<()>,
+) -> std::io::Result<()> {
+    let span = tracing::debug_span!("encode_geometry", format);
+    let _entered = span.enter();
+
+    let started = std::time::Instant::now();
+    let encoded = encode();
+    let elapsed = started.elapsed();
+
+    let kind = geometry.kind();
+    let srid = geometry.srid();
+    match &encoded {
+        Ok(()) => {
+            tracing::debug!(%kind, ?srid, bytes = size(), ?elapsed, "geometry encoded")
+        }
+        Err(error) => tracing::warn!(%kind, ?srid, %error, ?elapsed, "cannot encode geometry"),
+    }
+
+    encoded
+}
+
+#[cfg(not(feature = "tracing"))]
+#[inline(always)]
+pub(crate) fn trace_encode(
+    _format: &'static str,
+    _geometry: GeometryRef<'_>,
+    _size: impl FnOnce() -> usize,
+    encode: impl FnOnce() -> std::io::Result<()>,
+) -> std::io::Result<()> {
+    encode()
+}
+
+/// Observe le décodage d'une géométrie (fonctionnalité `tracing`).
+///
+/// Le décodage s'exécute dans un span `decode_geometry` ; un évènement rapporte ensuite
+/// le type, le SRID, la taille en octets et la durée, ou l'erreur.
+#[cfg(feature = "tracing")]
+pub(crate) fn trace_decode<T>(
+    format: &'static str,
+    describe: impl FnOnce(&T) -> (GeometryKind, Option<u32>, usize),
+    decode: impl FnOnce() -> Result<T, Error>,
+) -> Result<T, Error> {
+    let span = tracing::debug_span!("decode_geometry", format);
+    let _entered = span.enter();
+
+    let started = std::time::Instant::now();
+    let decoded = decode();
+    let elapsed = started.elapsed();
+
+    match &decoded {
+        Ok(value) => {
+            let (kind, srid, bytes) = describe(value);
+            tracing::debug!(%kind, ?srid, bytes, ?elapsed, "geometry decoded")
+        }
+        Err(error) => tracing::warn!(%error, ?elapsed, "cannot decode geometry"),
+    }
+
+    decoded
+}
+
+#[cfg(not(feature = "tracing"))]
+#[inline(always)]
+pub(crate) fn trace_decode<T>(
+    _format: &'static str,
+    _describe: impl FnOnce(&T) -> (GeometryKind, Option<u32>, usize),
+    decode: impl FnOnce() -> Result<T, Error>,
+) -> Result<T, Error> {
+    decode()
+}
+
+/// Écrit une trame précédée de sa longueur en octets (`u32` petit-boutiste).
+pub(crate) fn write_frame<W: Write>(frame: &[u8], stream: &mut W) -> std::io::Result<()> {
+    let len = u32::try_from(frame.len()).map_err(|_| {
+        std::io::Error::new(std::io::ErrorKind::InvalidInput, "frame exceeds 4 GiB")
+    })?;
+
+    Endian::Little.write_u32(len, stream)?;
+    stream.write_all(frame)
+}
+
+/// Décode `count` trames écrites par [write_frame], qui doivent couvrir tous les octets.
+pub(crate) fn read_frames<T>(
+    bytes: &[u8],
+    count: usize,
+    decode: impl Fn(&[u8]) -> Result<T, Error>,
+) -> Result<Vec<T>, Error> {
+    let mut remaining = bytes;
+    // Le compteur n'est pas fiable : chaque trame occupe au moins 4 octets.
+    let mut values = Vec::with_capacity(count.min(bytes.len() / 4));
+
+    for _ in 0..count {
+        let len = Endian::Little.read_u32(&mut remaining)? as usize;
+        let frame = remaining.get(..len).ok_or(Error::UnexpectedEof)?;
+        values.push(decode(frame)?);
+        remaining = &remaining[len..];
+    }
+
+    match remaining.len() {
+        0 => Ok(values),
+        len => Err(Error::TrailingBytes {
+            consumed: bytes.len() - len,
+            remaining: len,
+        }),
+    }
+}
+
+/// Boutisme d'un flux, connu à l'exécution.
+///
+/// Les codecs lisent le boutisme une seule fois dans l'en-tête, puis passent cette valeur
+/// aux fonctions internes plutôt que d'être génériques sur `byteorder::ByteOrder` :
+/// le code n'est ainsi généré qu'une fois, et non une fois par boutisme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Endian {
+    Big,
+    Little,
+}
+
+impl Endian {
+    pub fn read_u32<R: Read>(self, stream: &mut R) -> std::io::Result<u32> {
+        let mut buf = [0u8; 4];
+        stream.read_exact(&mut buf)?;
+
+        Ok(match self {
+            Self::Big => u32::from_be_bytes(buf),
+            Self::Little => u32::from_le_bytes(buf),
+        })
+    }
+
+    pub fn write_u32<W: Write>(self, value: u32, stream: &mut W) -> std::io::Result<()> {
+        match self {
+            Self::Big => stream.write_all(&value.to_be_bytes()),
+            Self::Little => stream.write_all(&value.to_le_bytes()),
+        }
+    }
+
+    /// Lit `N` scalaires consécutifs en une seule lecture.
+    pub fn read_f64s<const N: usize, R: Read>(self, stream: &mut R) -> std::io::Result<[f64; N]> {
+        let mut buf = [[0u8; 8]; N];
+        stream.read_exact(buf.as_flattened_mut())?;
+
+        Ok(buf.map(|bytes| match self {
+            Self::Big => f64::from_be_bytes(bytes),
+            Self::Little => f64::from_le_bytes(bytes),
+        }))
+    }
+
+    /// Écrit `N` scalaires consécutifs en une seule écriture.
+    pub fn write_f64s<const N: usize, W: Write>(
+        self,
+        scalars: &[f64; N],
+        stream: &mut W,
+    ) -> std::io::Result<()> {
+        let buf = scalars.map(|scalar| match self {
+            Self::Big => scalar.to_be_bytes(),
+            Self::Little => scalar.to_le_bytes(),
+        });
 
-    /// Decode from a slice of bytes.
-    fn decode_from_slice(mut slice: &[u8]) -> Result<Self, std::io::Error> {
-        Self::decode(&mut slice)
+        stream.write_all(buf.as_flattened())
     }
-}
\ No newline at end of file
+}
+
+/// Nombre maximal d'éléments alloués d'avance, voir [DecodeBudget::capacity].
+const MAX_PREALLOCATED: usize = 4096;
+
+/// Consommation des limites au cours d'un décodage, et position courante
+/// dans la géométrie décodée.
+pub(crate) struct DecodeBudget<'a> {
+    limits: &'a DecodeLimits,
+    vertices: usize,
+    rings: usize,
+    nesting: usize,
+    bytes: usize,
+    kind: Option<GeometryKind>,
+    path: Vec<usize>,
+}
+
+impl<'a> DecodeBudget<'a> {
+    pub fn new(limits: &'a DecodeLimits) -> Self {
+        Self {
+            limits,
+            vertices: 0,
+            rings: 0,
+            nesting: 0,
+            bytes: 0,
+            kind: None,
+            path: Vec::new(),
+        }
+    }
+
+    /// Classe de la géométrie en cours de décodage, qui nomme les niveaux du chemin.
+    pub fn set_kind(&mut self, kind: GeometryKind) {
+        self.kind = Some(kind);
+    }
+
+    /// Descend d'un niveau d'imbrication des coordonnées.
+    pub fn enter_part(&mut self) {
+        self.path.push(0);
+    }
+
+    /// Indique l'indice de l'élément décodé au niveau courant.
+    pub fn at_part(&mut self, index: usize) {
+        if let Some(last) = self.path.last_mut() {
+            *last = index;
+        }
+    }
+
+    /// Remonte d'un niveau d'imbrication des coordonnées.
+    pub fn leave_part(&mut self) {
+        self.path.pop();
+    }
+
+    /// Situe une erreur survenue après `offset` octets lus, à la position courante.
+    pub fn locate(&self, error: Error, offset: usize) -> Error {
+        let labels = self.kind.map(part_labels).unwrap_or_default();
+        let path = labels
+            .iter()
+            .zip(&self.path)
+            .map(|(label, index)| format!("{label} {index}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        Error::Located {
+            offset,
+            path,
+            error: Box::new(error),
+        }
+    }
+
+    /// Réserve des octets à lire dans le flux.
+    pub fn bytes(&mut self, count: usize) -> Result<(), Error> {
+        self.bytes = charge("bytes", self.bytes, count, self.limits.max_bytes)?;
+        Ok(())
+    }
+
+    /// Réserve `count` points de dimension `N`, et les octets qui les encodent.
+    ///
+    /// Renvoie le nombre de points ; voir [DecodeBudget::capacity] pour l'allocation.
+    pub fn vertices<const N: usize>(&mut self, count: u32) -> Result<usize, Error> {
+        let count = count as usize;
+        self.vertices = charge("vertices", self.vertices, count, self.limits.max_vertices)?;
+        self.bytes(count.saturating_mul(N * std::mem::size_of::<f64>()))?;
+        Ok(count)
+    }
+
+    /// Réserve `count` anneaux (ou lignes, ou polygones).
+    ///
+    /// Renvoie le nombre d'éléments ; voir [DecodeBudget::capacity] pour l'allocation.
+    pub fn rings(&mut self, count: u32) -> Result<usize, Error> {
+        let count = count as usize;
+        self.rings = charge("rings", self.rings, count, self.limits.max_rings)?;
+        // Chaque élément porte au minimum son propre compteur, que le flux
+        // restant doit pouvoir contenir ; il sera décompté à sa lecture.
+        charge(
+            "bytes",
+            self.bytes,
+            count.saturating_mul(std::mem::size_of::<u32>()),
+            self.limits.max_bytes,
+        )?;
+        Ok(count)
+    }
+
+    /// Capacité à réserver pour `count` éléments annoncés par le flux.
+    ///
+    /// Un compteur respectant les limites peut encore mentir : au-delà de
+    /// [MAX_PREALLOCATED] éléments, le vecteur grandit au fil de la lecture plutôt
+    /// que d'être alloué d'avance pour des octets que le flux ne contient pas.
+    pub fn capacity(&self, count: usize) -> usize {
+        count.min(MAX_PREALLOCATED)
+    }
+
+    /// Entre dans une géométrie imbriquée.
+    pub fn enter(&mut self) -> Result<(), Error> {
+        self.nesting = charge("nesting", self.nesting, 1, self.limits.max_nesting)?;
+        Ok(())
+    }
+
+    /// Sort d'une géométrie imbriquée.
+    pub fn leave(&mut self) {
+        self.nesting = self.nesting.saturating_sub(1);
+    }
+}
+
+/// Nom des niveaux d'imbrication des coordonnées d'une classe de géométrie.
+fn part_labels(kind: GeometryKind) -> &'static [&'static str] {
+    match kind.base_kind() {
+        GeometryKind::LineString => &["vertex"],
+        GeometryKind::MultiPoint => &["point"],
+        GeometryKind::Polygon => &["ring", "vertex"],
+        GeometryKind::MultiLineString => &["line string", "vertex"],
+        GeometryKind::MultiPolygon => &["polygon", "ring", "vertex"],
+        _ => &[],
+    }
+}
+
+fn charge(limit: &'static str, used: usize, count: usize, max: usize) -> Result<usize, Error> {
+    match used.checked_add(count) {
+        Some(total) if total <= max => Ok(total),
+        _ => Err(Error::LimitExceeded {
+            limit,
+            max,
+            got: used.saturating_add(count),
+        }),
+    }
+}
@@ -1,9 +1,38 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+/// Éléments du prélude de `std` qui manquent à `core` ; les modules qui en ont
+/// besoin l'importent pour compiler aussi sans `std`.
+#[allow(unused_imports)]
+mod prelude {
+    pub(crate) use alloc::{
+        borrow::ToOwned,
+        boxed::Box,
+        format,
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
+
+    // Sans `std`, les fonctions mathématiques de `f64` viennent de `libm`.
+    #[cfg(not(feature = "std"))]
+    pub(crate) use num_traits::Float;
+}
+
+#[cfg(any(feature = "ewkb", feature = "spatialite-format", feature = "geojson"))]
 macro_rules! impl_geometry_proxy {
     ($ns:ident, $geometry_type:ident) => {
         ::paste::paste! {
-            #[derive(Debug, Clone, PartialEq)]
+            #[derive(Debug, Clone, Default, PartialEq)]
             pub struct [<$ns $geometry_type>] (pub(crate) crate::types::$geometry_type);
 
+            impl core::hash::Hash for [<$ns $geometry_type>] {
+                fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                    self.as_geometry_ref().hash_into(state)
+                }
+            }
+
             impl crate::types::GeometryImpl for [<$ns $geometry_type>] {
                 type Coordinates = <crate::types::$geometry_type as crate::types::GeometryImpl>::Coordinates;
 
@@ -13,6 +42,19 @@ macro_rules! impl_geometry_proxy {
                 }
             }
 
+            impl [<$ns $geometry_type>] {
+                /// Emprunte la géométrie, sans la cloner.
+                pub fn as_geometry_ref(&self) -> crate::types::GeometryRef<'_> {
+                    (&self.0).into()
+                }
+            }
+
+            impl<'a> From<&'a [<$ns $geometry_type>]> for crate::types::GeometryRef<'a> {
+                fn from(value: &'a [<$ns $geometry_type>]) -> Self {
+                    (&value.0).into()
+                }
+            }
+
             impl From<[<$ns $geometry_type>]> for crate::types::Geometry  {
                 fn from(value: [<$ns $geometry_type>]) -> Self {
                     value.0.into()
@@ -31,7 +73,13 @@ macro_rules! impl_geometry_proxy {
                 }
             }
 
-            impl std::ops::Deref for [<$ns $geometry_type>] {
+            impl core::fmt::Display for [<$ns $geometry_type>] {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    self.as_geometry_ref().fmt(f)
+                }
+            }
+
+            impl core::ops::Deref for [<$ns $geometry_type>] {
                 type Target = crate::types::$geometry_type;
 
                 fn deref(&self) -> &Self::Target {
@@ -40,7 +88,7 @@ macro_rules! impl_geometry_proxy {
             }
 
 
-            impl ::std::ops::DerefMut for [<$ns $geometry_type>] {
+            impl ::core::ops::DerefMut for [<$ns $geometry_type>] {
                 fn deref_mut(&mut self) -> &mut Self::Target {
                     &mut self.0
                 }
@@ -49,6 +97,7 @@ macro_rules! impl_geometry_proxy {
     };
 }
 
+#[cfg(any(feature = "ewkb", feature = "spatialite-format", feature = "geojson"))]
 macro_rules! impl_geometry_proxies {
     ($ns: ident) => {
         impl_geometry_proxy!($ns, Point);
@@ -64,14 +113,102 @@ macro_rules! impl_geometry_proxies {
         impl_geometry_proxy!($ns, MultiLineStringZ);
         impl_geometry_proxy!($ns, PolygonZ);
         impl_geometry_proxy!($ns, MultiPolygonZ);
+
+        ::paste::paste! {
+            impl From<(f64, f64)> for [<$ns Point>] {
+                fn from(value: (f64, f64)) -> Self {
+                    Self(value.into())
+                }
+            }
+
+            impl From<(f64, f64, f64)> for [<$ns PointZ>] {
+                fn from(value: (f64, f64, f64)) -> Self {
+                    Self(value.into())
+                }
+            }
+        }
+    };
+}
+
+#[cfg(all(
+    feature = "sea-query",
+    any(feature = "ewkb", feature = "spatialite-format")
+))]
+macro_rules! impl_geometry_sea_query_value {
+    ($ns:ident, $geometry_type:ident) => {
+        ::paste::paste! {
+            impl From<[<$ns $geometry_type>]> for ::sea_query::Value {
+                fn from(value: [<$ns $geometry_type>]) -> Self {
+                    [<$ns Geometry>]::from(crate::types::Geometry::from(value.0)).into()
+                }
+            }
+
+            impl ::sea_query::Nullable for [<$ns $geometry_type>] {
+                fn null() -> ::sea_query::Value {
+                    <[<$ns Geometry>] as ::sea_query::Nullable>::null()
+                }
+            }
+
+            impl ::sea_query::ValueType for [<$ns $geometry_type>] {
+                fn try_from(v: ::sea_query::Value) -> Result<Self, ::sea_query::ValueTypeErr> {
+                    let geom = <[<$ns Geometry>] as ::sea_query::ValueType>::try_from(v)?.0;
+                    geom.try_into().map(Self).map_err(|_| ::sea_query::ValueTypeErr)
+                }
+
+                fn type_name() -> String {
+                    stringify!([<$ns $geometry_type>]).to_owned()
+                }
+
+                fn array_type() -> ::sea_query::ArrayType {
+                    <[<$ns Geometry>] as ::sea_query::ValueType>::array_type()
+                }
+
+                fn column_type() -> ::sea_query::ColumnType {
+                    <[<$ns Geometry>] as ::sea_query::ValueType>::column_type()
+                }
+            }
+        }
     };
 }
 
-mod io;
+#[cfg(all(
+    feature = "sea-query",
+    any(feature = "ewkb", feature = "spatialite-format")
+))]
+macro_rules! impl_geometry_sea_query_values {
+    ($ns: ident) => {
+        impl_geometry_sea_query_value!($ns, Point);
+        impl_geometry_sea_query_value!($ns, MultiPoint);
+        impl_geometry_sea_query_value!($ns, LineString);
+        impl_geometry_sea_query_value!($ns, MultiLineString);
+        impl_geometry_sea_query_value!($ns, Polygon);
+        impl_geometry_sea_query_value!($ns, MultiPolygon);
+
+        impl_geometry_sea_query_value!($ns, PointZ);
+        impl_geometry_sea_query_value!($ns, MultiPointZ);
+        impl_geometry_sea_query_value!($ns, LineStringZ);
+        impl_geometry_sea_query_value!($ns, MultiLineStringZ);
+        impl_geometry_sea_query_value!($ns, PolygonZ);
+        impl_geometry_sea_query_value!($ns, MultiPolygonZ);
+    };
+}
+
+#[cfg(feature = "std")]
+pub mod io;
+
+#[cfg(feature = "std")]
+pub use io::{
+    Decodable, DecodeLimits, DecodeOptions, DynEncodable, Encodable, SridPolicy, Strictness,
+};
 
 pub mod error;
+
+#[cfg(feature = "ewkb")]
 pub mod ewkb;
 
+#[cfg(feature = "functions")]
+pub mod functions;
+
 #[cfg(feature = "geojson")]
 pub mod geojson;
 
@@ -79,4 +216,6 @@ pub mod sql_types;
 
 pub mod types;
 
+/// SRID écrit par SpatiaLite lorsque la géométrie n'en porte pas.
+#[cfg(feature = "spatialite-format")]
 const DEFAULT_SRID: u32 = 4326;
@@ -57,16 +57,16 @@ mod sqlx {
     impl<'q> Encode<'q, Postgres> for AutoGeometry {
         fn encode_by_ref(
             &self,
-            buf: &mut <Postgres as ::sqlx::database::HasArguments<'q>>::ArgumentBuffer,
-        ) -> ::sqlx::encode::IsNull {
+            buf: &mut <Postgres as Database>::ArgumentBuffer<'q>,
+        ) -> Result<::sqlx::encode::IsNull, ::sqlx::error::BoxDynError> {
             PgGeometry::new(self.0.clone()).encode_by_ref(buf)
         }
     }
     impl<'q> Encode<'q, Sqlite> for AutoGeometry {
         fn encode_by_ref(
             &self,
-            buf: &mut <Sqlite as ::sqlx::database::HasArguments<'q>>::ArgumentBuffer,
-        ) -> ::sqlx::encode::IsNull {
+            buf: &mut <Sqlite as Database>::ArgumentBuffer<'q>,
+        ) -> Result<::sqlx::encode::IsNull, ::sqlx::error::BoxDynError> {
             <SpatiaLiteGeometry as Encode<'q, Sqlite>>::encode_by_ref(
                 &SpatiaLiteGeometry::new(self.0.clone()),
                 buf,
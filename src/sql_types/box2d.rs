@@ -0,0 +1,131 @@
+//! Encodage et décodage du type `box2d` de PostGIS, notamment le résultat de `ST_Extent(geom)`.
+use std::ops::{Deref, DerefMut};
+
+use ::sqlx::{encode::IsNull, error::BoxDynError, Database, Decode, Encode, Type};
+
+use crate::types::MBR2D;
+
+#[derive(Debug, Clone, PartialEq)]
+/// Rectangle englobant au format texte `BOX(xmin ymin,xmax ymax)` de PostGIS.
+///
+/// Le type `box2d` n'a pas de représentation binaire, il faut donc le convertir
+/// en texte dans la requête, par exemple `ST_Extent(geom)::text` en lecture et
+/// `$1::box2d` en écriture.
+pub struct Box2D(pub(crate) MBR2D);
+
+impl Box2D {
+    pub fn into_mbr(self) -> MBR2D {
+        self.0
+    }
+}
+
+impl Deref for Box2D {
+    type Target = MBR2D;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Box2D {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<MBR2D> for Box2D {
+    fn from(value: MBR2D) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Box2D> for MBR2D {
+    fn from(value: Box2D) -> Self {
+        value.0
+    }
+}
+
+/// Lit un rectangle au format `BOX(xmin ymin,xmax ymax)`.
+fn parse_box2d(encoded: &str) -> Option<MBR2D> {
+    let inner = encoded.trim().strip_prefix("BOX(")?.strip_suffix(')')?;
+    let (min, max) = inner.split_once(',')?;
+    let (min_x, min_y) = min.trim().split_once(' ')?;
+    let (max_x, max_y) = max.trim().split_once(' ')?;
+
+    Some(MBR2D::new(
+        [min_x.parse().ok()?, min_y.parse().ok()?],
+        [max_x.parse().ok()?, max_y.parse().ok()?],
+    ))
+}
+
+/// Écrit un rectangle au format `BOX(xmin ymin,xmax ymax)`.
+fn format_box2d(mbr: &MBR2D) -> String {
+    format!(
+        "BOX({} {},{} {})",
+        mbr.min_x(),
+        mbr.min_y(),
+        mbr.max_x(),
+        mbr.max_y()
+    )
+}
+
+impl<DB> Type<DB> for Box2D
+where
+    DB: Database,
+    String: Type<DB>,
+{
+    fn type_info() -> <DB as Database>::TypeInfo {
+        <String as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &<DB as Database>::TypeInfo) -> bool {
+        <String as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'r, DB> Decode<'r, DB> for Box2D
+where
+    DB: Database,
+    &'r str: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        let encoded = <&'r str as Decode<'r, DB>>::decode(value)?;
+        let mbr = parse_box2d(encoded).ok_or_else(|| format!("invalid box2d: {encoded}"))?;
+        Ok(Self(mbr))
+    }
+}
+
+impl<'q, DB> Encode<'q, DB> for Box2D
+where
+    DB: Database,
+    String: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        <String as Encode<'q, DB>>::encode(format_box2d(&self.0), buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_box2d() {
+        let mbr = parse_box2d("BOX(-1.5 2,10 20.25)").expect("cannot parse box2d");
+
+        assert_eq!(mbr, MBR2D::new([-1.5, 2.0], [10.0, 20.25]));
+        assert_eq!(parse_box2d("POINT(1 2)"), None);
+    }
+
+    #[test]
+    fn test_format_box2d() {
+        let mbr = MBR2D::new([-1.5, 2.0], [10.0, 20.25]);
+        let encoded = format_box2d(&mbr);
+
+        assert_eq!(encoded, "BOX(-1.5 2,10 20.25)");
+        assert_eq!(parse_box2d(&encoded), Some(mbr));
+    }
+}
@@ -1,11 +1,18 @@
-#[cfg(feature = "sqlx")]
 /// Implémente l'encodage / décodage depuis sqlx
 mod sqlx {
     use crate::{
         ewkb::*,
-        io::{Decodable, Encodable as _},
+        io::{Decodable, Encodable},
+        sql_types::EncodeGeometryRef,
+        types::GeometryRef,
     };
-    use ::sqlx::{postgres::PgTypeInfo, Database, Decode, Encode, Postgres, Type};
+    use ::sqlx::{
+        encode::IsNull,
+        error::BoxDynError,
+        postgres::{PgArgumentBuffer, PgTypeInfo},
+        Database, Decode, Encode, Postgres, Type,
+    };
+    use std::ops::DerefMut as _;
 
     impl Type<Postgres> for EWKBGeometry {
         fn type_info() -> PgTypeInfo {
@@ -18,26 +25,29 @@ mod sqlx {
         DB: Database,
         &'r [u8]: Decode<'r, DB>,
     {
-        fn decode(
-            value: <DB as ::sqlx::database::HasValueRef<'r>>::ValueRef,
-        ) -> Result<Self, ::sqlx::error::BoxDynError> {
+        fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
             let encoded = <&'r [u8] as Decode<DB>>::decode(value)?;
-            let decoded = <Self as Decodable>::decode_from_slice(encoded).unwrap();
+            let decoded = <Self as Decodable>::decode_from_slice(encoded)?;
             Ok(decoded)
         }
     }
 
-    impl<'q, DB> Encode<'q, DB> for EWKBGeometry
-    where
-        DB: Database,
-        Vec<u8>: Encode<'q, DB>,
-    {
-        fn encode_by_ref(
-            &self,
-            buf: &mut <DB as ::sqlx::database::HasArguments<'q>>::ArgumentBuffer,
-        ) -> ::sqlx::encode::IsNull {
-            let encoded = self.encode_to_vec().unwrap();
-            encoded.encode_by_ref(buf)
+    impl<'q> EncodeGeometryRef<'q, Postgres> for EWKBGeometry {
+        fn encode_geometry_ref(
+            geometry: GeometryRef<'_>,
+            buf: &mut PgArgumentBuffer,
+        ) -> Result<IsNull, BoxDynError> {
+            buf.reserve(encoded_size(geometry));
+            encode_geometry_ref(geometry, buf.deref_mut())?;
+            Ok(IsNull::No)
+        }
+    }
+
+    impl<'q> Encode<'q, Postgres> for EWKBGeometry {
+        fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+            buf.reserve(self.encoded_size().unwrap_or_default());
+            Encodable::encode(self, buf.deref_mut())?;
+            Ok(IsNull::No)
         }
     }
 
@@ -0,0 +1,94 @@
+//! Décodage du résultat de `ST_AsGeoJSON(geom)`.
+use std::ops::{Deref, DerefMut};
+
+use ::sqlx::{error::BoxDynError, Database, Decode, Type};
+
+use crate::{
+    geojson::GeoJsonGeometry,
+    io::{DecodeBudget, DecodeLimits},
+    types::Geometry,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+/// Géométrie sélectionnée au format GeoJSON, typiquement via `ST_AsGeoJSON(geom)`.
+pub struct GeoJsonText(pub(crate) Geometry);
+
+impl GeoJsonText {
+    pub fn into_geometry(self) -> Geometry {
+        self.0
+    }
+}
+
+impl Deref for GeoJsonText {
+    type Target = Geometry;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for GeoJsonText {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<GeoJsonText> for Geometry {
+    fn from(value: GeoJsonText) -> Self {
+        value.0
+    }
+}
+
+/// Décode un texte GeoJSON, après en avoir vérifié la taille.
+fn parse_geojson(encoded: &str) -> Result<Geometry, BoxDynError> {
+    DecodeBudget::new(&DecodeLimits::default()).bytes(encoded.len())?;
+    let geometry = serde_json::from_str::<GeoJsonGeometry>(encoded)?;
+    Ok(geometry.into())
+}
+
+impl<DB> Type<DB> for GeoJsonText
+where
+    DB: Database,
+    String: Type<DB>,
+{
+    fn type_info() -> <DB as Database>::TypeInfo {
+        <String as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &<DB as Database>::TypeInfo) -> bool {
+        <String as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'r, DB> Decode<'r, DB> for GeoJsonText
+where
+    DB: Database,
+    &'r str: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        let encoded = <&'r str as Decode<'r, DB>>::decode(value)?;
+        Ok(Self(parse_geojson(encoded)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{GeometryImpl as _, Polygon};
+
+    #[test]
+    fn test_geojson_text_round_trip() {
+        let expected = Geometry::from(Polygon::new([
+            [0.0, 0.0],
+            [1.0, 0.0],
+            [1.0, 1.0],
+            [0.0, 0.0],
+        ]));
+        let encoded =
+            serde_json::to_string(&GeoJsonGeometry::new(expected.clone())).expect("cannot encode");
+
+        let geometry = parse_geojson(&encoded).expect("cannot decode GeoJSON");
+        assert_eq!(GeoJsonText(geometry).into_geometry(), expected);
+        assert!(parse_geojson(r#"{"type": "Point"}"#).is_err());
+    }
+}
@@ -1,3 +1,4 @@
+#[cfg(any(feature = "postgis", feature = "spatialite"))]
 macro_rules! impl_geometry_sqlx_codec {
     ($ns:ident, $geometry_type:ident) => {
         ::paste::paste! {
@@ -15,7 +16,7 @@ macro_rules! impl_geometry_sqlx_codec {
                 [<$ns Geometry>]: ::sqlx::Decode<'r, DB>,
             {
                 fn decode(
-                    value: <DB as ::sqlx::database::HasValueRef<'r>>::ValueRef,
+                    value: <DB as ::sqlx::Database>::ValueRef<'r>,
                 ) -> Result<Self, ::sqlx::error::BoxDynError> {
                     let geom = <[<$ns Geometry>] as ::sqlx::Decode<'r, DB>>::decode(value)?.0;
                     Ok(Self(geom.try_into()?))
@@ -25,19 +26,23 @@ macro_rules! impl_geometry_sqlx_codec {
             impl<'q, DB> ::sqlx::Encode<'q, DB> for [<$ns $geometry_type>]
             where
                 DB: ::sqlx::Database,
-                [<$ns Geometry>]: ::sqlx::Encode<'q, DB>,
+                [<$ns Geometry>]: crate::sql_types::EncodeGeometryRef<'q, DB>,
             {
                 fn encode_by_ref(
                     &self,
-                    buf: &mut <DB as ::sqlx::database::HasArguments<'q>>::ArgumentBuffer,
-                ) -> ::sqlx::encode::IsNull {
-                    [<$ns Geometry>](self.0.clone().into()).encode_by_ref(buf)
+                    buf: &mut <DB as ::sqlx::Database>::ArgumentBuffer<'q>,
+                ) -> Result<::sqlx::encode::IsNull, ::sqlx::error::BoxDynError> {
+                    <[<$ns Geometry>] as crate::sql_types::EncodeGeometryRef<'q, DB>>::encode_geometry_ref(
+                        (&self.0).into(),
+                        buf,
+                    )
                 }
             }
         }
     };
 }
 
+#[cfg(any(feature = "postgis", feature = "spatialite"))]
 macro_rules! impl_geometry_sqlx_codecs {
     ($ns: ident) => {
         impl_geometry_sqlx_codec!($ns, Point);
@@ -56,16 +61,53 @@ macro_rules! impl_geometry_sqlx_codecs {
     };
 }
 
+/// Encode une géométrie empruntée dans le buffer d'arguments d'une base de données.
+///
+/// Implémenté par l'objet intermédiaire de chaque codec, afin que les géométries
+/// typées puissent être liées à une requête sans être clonées.
+#[cfg(feature = "sqlx")]
+pub trait EncodeGeometryRef<'q, DB: ::sqlx::Database> {
+    fn encode_geometry_ref(
+        geometry: crate::types::GeometryRef<'_>,
+        buf: &mut <DB as ::sqlx::Database>::ArgumentBuffer<'q>,
+    ) -> Result<::sqlx::encode::IsNull, ::sqlx::error::BoxDynError>;
+}
+
+#[cfg(feature = "postgis")]
 mod ewkb;
 
+#[cfg(feature = "sqlx")]
+mod box2d;
+
+#[cfg(all(feature = "sqlx", feature = "geojson"))]
+mod geojson;
+
+#[cfg(all(feature = "sqlx", feature = "ewkb"))]
+mod wkb;
+
+#[cfg(feature = "postgis")]
+mod pg_native;
+
 #[cfg(feature = "postgis")]
 mod postgis;
 
-#[cfg(feature = "spatialite")]
+#[cfg(feature = "spatialite-format")]
 mod spatialite;
 
+#[cfg(feature = "postgis")]
+pub use pg_native::*;
+
 #[cfg(feature = "postgis")]
 pub use postgis::*;
 
-#[cfg(feature = "spatialite")]
+#[cfg(feature = "spatialite-format")]
 pub use spatialite::*;
+
+#[cfg(all(feature = "sqlx", feature = "geojson"))]
+pub use geojson::GeoJsonText;
+
+#[cfg(all(feature = "sqlx", feature = "ewkb"))]
+pub use wkb::WkbBytes;
+
+#[cfg(feature = "sqlx")]
+pub use box2d::Box2D;
@@ -0,0 +1,260 @@
+//! Conversions entre nos géométries et les types géométriques natifs de Postgres
+//! (`point`, `path`, `polygon`, `box`), utilisables sans l'extension PostGIS.
+use std::ops::{Deref, DerefMut};
+
+use ::sqlx::postgres::types as pg;
+
+use crate::{
+    error::Error,
+    types::{
+        GeometryImpl as _, GeometryKind, LineString, Point, Polygon, Vector, VectorArray2D, MBR2D,
+    },
+};
+
+impl From<&Point> for pg::PgPoint {
+    fn from(value: &Point) -> Self {
+        Self {
+            x: value.coordinates.x(),
+            y: value.coordinates.y(),
+        }
+    }
+}
+
+impl From<pg::PgPoint> for Point {
+    fn from(value: pg::PgPoint) -> Self {
+        Point::new([value.x, value.y])
+    }
+}
+
+impl From<&LineString> for pg::PgPath {
+    /// Une ligne dont le premier et le dernier point sont égaux devient un chemin fermé,
+    /// sans répéter le point de fermeture.
+    fn from(value: &LineString) -> Self {
+        let coordinates = &value.coordinates;
+        let closed = coordinates.len() > 2 && coordinates.first() == coordinates.last();
+        let nb_points = coordinates.len() - usize::from(closed);
+
+        Self {
+            closed,
+            points: coordinates.iter().take(nb_points).map(pg_point).collect(),
+        }
+    }
+}
+
+impl From<pg::PgPath> for LineString {
+    fn from(value: pg::PgPath) -> Self {
+        let mut coordinates = value.points.into_iter().map(vector).collect::<Vec<_>>();
+
+        // Un chemin fermé est représenté par un anneau.
+        if value.closed && coordinates.first() != coordinates.last() {
+            coordinates.extend(coordinates.first().cloned());
+        }
+
+        LineString::new(coordinates.into_iter().collect::<VectorArray2D>())
+    }
+}
+
+impl TryFrom<&Polygon> for pg::PgPolygon {
+    type Error = Error;
+
+    /// Le type `polygon` de Postgres ne supporte pas les anneaux intérieurs.
+    fn try_from(value: &Polygon) -> Result<Self, Self::Error> {
+        match value.coordinates.deref() {
+            [exterior] => {
+                // Postgres ne répète pas le premier point pour fermer l'anneau.
+                let nb_points = exterior.len().saturating_sub(1);
+
+                Ok(Self {
+                    points: exterior.iter().take(nb_points).map(pg_point).collect(),
+                })
+            }
+            _ => Err(Error::unsupported_geometry(
+                GeometryKind::Polygon,
+                "a Postgres polygon has exactly one ring",
+            )),
+        }
+    }
+}
+
+impl From<pg::PgPolygon> for Polygon {
+    fn from(value: pg::PgPolygon) -> Self {
+        Polygon::new(
+            value
+                .points
+                .into_iter()
+                .map(vector)
+                .collect::<VectorArray2D>(),
+        )
+    }
+}
+
+impl From<&MBR2D> for pg::PgBox {
+    fn from(value: &MBR2D) -> Self {
+        Self {
+            upper_right_x: value.max_x(),
+            upper_right_y: value.max_y(),
+            lower_left_x: value.min_x(),
+            lower_left_y: value.min_y(),
+        }
+    }
+}
+
+impl From<pg::PgBox> for MBR2D {
+    fn from(value: pg::PgBox) -> Self {
+        Self::new(
+            [
+                value.lower_left_x.min(value.upper_right_x),
+                value.lower_left_y.min(value.upper_right_y),
+            ],
+            [
+                value.lower_left_x.max(value.upper_right_x),
+                value.lower_left_y.max(value.upper_right_y),
+            ],
+        )
+    }
+}
+
+fn pg_point(vector: &Vector<2, f64>) -> pg::PgPoint {
+    pg::PgPoint {
+        x: vector.x(),
+        y: vector.y(),
+    }
+}
+
+fn vector(point: pg::PgPoint) -> Vector<2, f64> {
+    Vector::new([point.x, point.y])
+}
+
+/// Implémente un objet intermédiaire encodant une de nos géométries
+/// via un type géométrique natif de Postgres.
+macro_rules! impl_pg_native_codec {
+    ($name:ident, $inner:ty, $native:ty, $doc:literal) => {
+        #[doc = $doc]
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct $name(pub(crate) $inner);
+
+        impl From<$inner> for $name {
+            fn from(value: $inner) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<$name> for $inner {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl Deref for $name {
+            type Target = $inner;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl DerefMut for $name {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+
+        impl ::sqlx::Type<::sqlx::Postgres> for $name {
+            fn type_info() -> ::sqlx::postgres::PgTypeInfo {
+                <$native as ::sqlx::Type<::sqlx::Postgres>>::type_info()
+            }
+        }
+
+        impl<'r> ::sqlx::Decode<'r, ::sqlx::Postgres> for $name {
+            fn decode(
+                value: ::sqlx::postgres::PgValueRef<'r>,
+            ) -> Result<Self, ::sqlx::error::BoxDynError> {
+                let native = <$native as ::sqlx::Decode<'r, ::sqlx::Postgres>>::decode(value)?;
+                Ok(Self(native.into()))
+            }
+        }
+
+        impl<'q> ::sqlx::Encode<'q, ::sqlx::Postgres> for $name {
+            fn encode_by_ref(
+                &self,
+                buf: &mut ::sqlx::postgres::PgArgumentBuffer,
+            ) -> Result<::sqlx::encode::IsNull, ::sqlx::error::BoxDynError> {
+                let native = <$native>::try_from(&self.0)?;
+                <$native as ::sqlx::Encode<'q, ::sqlx::Postgres>>::encode(native, buf)
+            }
+        }
+    };
+}
+
+impl_pg_native_codec!(
+    PgNativePoint,
+    Point,
+    pg::PgPoint,
+    "Point encodé avec le type natif `point` de Postgres."
+);
+impl_pg_native_codec!(
+    PgNativePath,
+    LineString,
+    pg::PgPath,
+    "Ligne brisée encodée avec le type natif `path` de Postgres."
+);
+impl_pg_native_codec!(
+    PgNativePolygon,
+    Polygon,
+    pg::PgPolygon,
+    "Polygone (sans anneau intérieur) encodé avec le type natif `polygon` de Postgres."
+);
+impl_pg_native_codec!(
+    PgNativeBox,
+    MBR2D,
+    pg::PgBox,
+    "Rectangle englobant encodé avec le type natif `box` de Postgres."
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_polygon_drops_closing_point() {
+        let polygon = Polygon::new([[0.0, 0.0], [1.0, 0.0], [1.0, 1.0]]);
+        let native = pg::PgPolygon::try_from(&polygon).expect("cannot convert polygon");
+
+        assert_eq!(native.points.len(), 3);
+        assert_eq!(Polygon::from(native), polygon);
+    }
+
+    #[test]
+    fn test_closed_path_round_trip() {
+        let native = pg::PgPath {
+            closed: true,
+            points: vec![
+                pg::PgPoint { x: 0.0, y: 0.0 },
+                pg::PgPoint { x: 1.0, y: 0.0 },
+                pg::PgPoint { x: 1.0, y: 1.0 },
+            ],
+        };
+
+        let line = LineString::from(native.clone());
+        assert_eq!(
+            line,
+            LineString::new([[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 0.0]])
+        );
+        assert_eq!(pg::PgPath::from(&line), native);
+
+        let open = LineString::new([[0.0, 0.0], [1.0, 0.0], [1.0, 1.0]]);
+        let native = pg::PgPath::from(&open);
+        assert!(!native.closed);
+        assert_eq!(LineString::from(native), open);
+    }
+
+    #[test]
+    fn test_polygon_with_holes_is_rejected() {
+        let polygon = Polygon::new((
+            [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0]],
+            [[1.0, 1.0], [2.0, 1.0], [2.0, 2.0]],
+        ));
+
+        assert!(pg::PgPolygon::try_from(&polygon).is_err());
+    }
+}
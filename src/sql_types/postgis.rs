@@ -1,6 +1,14 @@
-use std::ops::{Deref, DerefMut};
+use std::{
+    borrow::Cow,
+    ops::{Deref, DerefMut},
+};
 
-use crate::types::Geometry;
+use crate::{
+    error::Error,
+    ewkb::{self, EWKBGeometry},
+    io::Decodable as _,
+    types::{Geometry, GeometryKind},
+};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct PgGeometry(Geometry);
@@ -33,11 +41,116 @@ impl DerefMut for PgGeometry {
 
 impl_geometry_proxies!(Pg);
 
+/// Géométrie PostGIS dont seul l'en-tête EWKB est décodé.
+///
+/// Le blob est emprunté au résultat de la requête et réécrit tel quel à l'encodage :
+/// adapté aux services qui relaient les géométries sans les modifier.
+/// Les coordonnées ne sont décodées qu'à la demande, voir [PgRawGeometry::materialize].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PgRawGeometry<'a> {
+    bytes: Cow<'a, [u8]>,
+    kind: GeometryKind,
+    srid: Option<u32>,
+}
+
+impl<'a> PgRawGeometry<'a> {
+    /// Décode l'en-tête d'un blob EWKB.
+    ///
+    /// Le reste du blob n'est pas validé avant l'appel à [PgRawGeometry::materialize].
+    pub fn from_bytes<B: Into<Cow<'a, [u8]>>>(bytes: B) -> Result<Self, Error> {
+        let bytes = bytes.into();
+        let (kind, srid) = ewkb::decode_header(&mut bytes.as_ref())?;
+
+        Ok(Self { bytes, kind, srid })
+    }
+
+    pub fn kind(&self) -> GeometryKind {
+        self.kind
+    }
+
+    pub fn srid(&self) -> Option<u32> {
+        self.srid
+    }
+
+    /// Blob EWKB, tel que reçu.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Détache le blob du résultat de la requête.
+    pub fn into_owned(self) -> PgRawGeometry<'static> {
+        PgRawGeometry {
+            bytes: Cow::Owned(self.bytes.into_owned()),
+            kind: self.kind,
+            srid: self.srid,
+        }
+    }
+
+    /// Décode entièrement la géométrie.
+    pub fn materialize(&self) -> Result<Geometry, Error> {
+        EWKBGeometry::decode_from_slice(&self.bytes).map(Geometry::from)
+    }
+}
+
+/// Lie une géométrie dans une requête sea-query, encodée en EWKB.
+#[cfg(feature = "sea-query")]
+mod sea_query {
+    use ::sea_query::{ArrayType, ColumnType, Nullable, Value, ValueType, ValueTypeErr};
+
+    use crate::ewkb;
+
+    use super::*;
+
+    impl From<PgGeometry> for Value {
+        fn from(value: PgGeometry) -> Self {
+            let mut encoded = Vec::<u8>::with_capacity(ewkb::encoded_size(&value.0));
+            ewkb::encode_geometry(&value.0, &mut encoded).expect("encoding into a Vec cannot fail");
+            Value::Bytes(Some(Box::new(encoded)))
+        }
+    }
+
+    impl Nullable for PgGeometry {
+        fn null() -> Value {
+            Value::Bytes(None)
+        }
+    }
+
+    impl ValueType for PgGeometry {
+        fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+            match v {
+                Value::Bytes(Some(bytes)) => ewkb::decode_geometry(&mut bytes.as_slice())
+                    .map(Self::new)
+                    .map_err(|_| ValueTypeErr),
+                _ => Err(ValueTypeErr),
+            }
+        }
+
+        fn type_name() -> String {
+            stringify!(PgGeometry).to_owned()
+        }
+
+        fn array_type() -> ArrayType {
+            ArrayType::Bytes
+        }
+
+        fn column_type() -> ColumnType {
+            <crate::types::Geometry as ValueType>::column_type()
+        }
+    }
+
+    impl_geometry_sea_query_values!(Pg);
+}
+
 #[cfg(feature = "sqlx")]
 mod sqlx {
-    use ::sqlx::{postgres::PgTypeInfo, Decode, Encode, Postgres, Type};
+    use ::sqlx::{
+        encode::IsNull,
+        error::BoxDynError,
+        postgres::{PgArgumentBuffer, PgTypeInfo, PgValueRef},
+        Decode, Encode, Postgres, Type,
+    };
 
-    use crate::ewkb;
+    use crate::{sql_types::EncodeGeometryRef, types::GeometryRef};
 
     use super::*;
 
@@ -48,23 +161,76 @@ mod sqlx {
     }
 
     impl<'r> Decode<'r, Postgres> for PgGeometry {
-        fn decode(
-            value: <Postgres as ::sqlx::database::HasValueRef<'r>>::ValueRef,
-        ) -> Result<Self, ::sqlx::error::BoxDynError> {
+        fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
             let ewkb = ewkb::decode_geometry(&mut value.as_bytes()?)?;
             Ok(Self::new(ewkb))
         }
     }
 
+    impl<'q> EncodeGeometryRef<'q, Postgres> for PgGeometry {
+        fn encode_geometry_ref(
+            geometry: GeometryRef<'_>,
+            buf: &mut PgArgumentBuffer,
+        ) -> Result<IsNull, BoxDynError> {
+            // Le buffer d'arguments de Postgres est un Vec<u8>, on y écrit directement.
+            buf.reserve(ewkb::encoded_size(geometry));
+            ewkb::encode_geometry_ref(geometry, buf.deref_mut())?;
+            Ok(IsNull::No)
+        }
+    }
+
     impl<'q> Encode<'q, Postgres> for PgGeometry {
-        fn encode_by_ref(
-            &self,
-            buf: &mut <Postgres as ::sqlx::database::HasArguments<'q>>::ArgumentBuffer,
-        ) -> ::sqlx::encode::IsNull {
-            ewkb::encode_geometry(self.deref(), buf.deref_mut()).unwrap();
-            ::sqlx::encode::IsNull::No
+        fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+            Self::encode_geometry_ref(self.deref().into(), buf)
         }
     }
 
     impl_geometry_sqlx_codecs!(Pg);
+
+    impl Type<Postgres> for PgRawGeometry<'_> {
+        fn type_info() -> <Postgres as ::sqlx::Database>::TypeInfo {
+            PgGeometry::type_info()
+        }
+    }
+
+    impl<'r> Decode<'r, Postgres> for PgRawGeometry<'r> {
+        fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+            Ok(Self::from_bytes(value.as_bytes()?)?)
+        }
+    }
+
+    impl<'q> Encode<'q, Postgres> for PgRawGeometry<'_> {
+        fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+            buf.extend_from_slice(&self.bytes);
+            Ok(IsNull::No)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        io::Encodable as _,
+        types::{GeometryImpl as _, LineString},
+    };
+
+    #[test]
+    fn test_raw_geometry() {
+        let mut line = LineString::new([[0.0, 0.0], [1.0, 1.0]]);
+        line.srid = Some(2154);
+
+        let bytes = EWKBGeometry::new(line.clone())
+            .encode_to_vec()
+            .expect("cannot encode geometry");
+
+        let raw = PgRawGeometry::from_bytes(bytes.as_slice()).expect("cannot decode header");
+
+        assert_eq!(raw.kind(), GeometryKind::LineString);
+        assert_eq!(raw.srid(), Some(2154));
+        assert_eq!(raw.as_bytes(), bytes.as_slice());
+        assert_eq!(raw.materialize().ok(), Some(Geometry::from(line)));
+
+        assert!(PgRawGeometry::from_bytes(&bytes[..3]).is_err());
+    }
 }
@@ -1,17 +1,23 @@
 use byteorder::{BigEndian, ByteOrder, LittleEndian, NativeEndian, ReadBytesExt, WriteBytesExt};
 
 use std::{
+    borrow::Cow,
     io::{Read, Write},
     marker::PhantomData,
     ops::{Deref, DerefMut},
 };
 
 use crate::{
-    io::{Decodable, Encodable},
+    error::Error,
+    io::{
+        coordinates_size, read_frames, trace_decode, trace_encode, write_frame, CountingReader,
+        Decodable, DecodeBudget, DecodeOptions, Encodable, Endian,
+    },
     types::{
-        CoordinatesRef, Geometry, GeometryImpl as _, GeometryKind, LineString, LineStringZ,
-        MultiLineString, MultiLineStringZ, MultiPoint, MultiPointZ, MultiPolygon, MultiPolygonZ,
-        Point, PointZ, Polygon, PolygonZ, Vector, VectorArray, VectorMatrix, VectorTensor, MBR,
+        CoordinatesRef, Geometry, GeometryImpl as _, GeometryKind, GeometryRef, LineString,
+        LineStringZ, MultiLineString, MultiLineStringZ, MultiPoint, MultiPointZ, MultiPolygon,
+        MultiPolygonZ, Point, PointZ, Polygon, PolygonZ, RingOrientation, Vector, VectorArray,
+        VectorMatrix, VectorTensor, MBR2D,
     },
     DEFAULT_SRID,
 };
@@ -27,10 +33,14 @@ impl Encodable for SpatiaLiteGeometry {
     fn encode<W: Write>(&self, stream: &mut W) -> Result<(), std::io::Error> {
         encode_geometry(&self.0, stream)
     }
+
+    fn encoded_size(&self) -> Option<usize> {
+        Some(encoded_size(&self.0))
+    }
 }
 
 impl Decodable for SpatiaLiteGeometry {
-    fn decode<R: Read>(stream: &mut R) -> Result<Self, std::io::Error> {
+    fn decode<R: Read>(stream: &mut R) -> Result<Self, Error> {
         decode_geometry(stream).map(Self::new)
     }
 }
@@ -73,11 +83,128 @@ impl From<SpatiaLiteGeometry> for Geometry {
 
 impl_geometry_proxies!(SpatiaLite);
 
-#[cfg(feature = "sqlx")]
+/// Géométrie SpatiaLite dont seul l'en-tête du BLOB est décodé.
+///
+/// Le BLOB est emprunté au résultat de la requête et réécrit tel quel à l'encodage :
+/// adapté aux services qui relaient les géométries sans les modifier.
+/// Les coordonnées ne sont décodées qu'à la demande, voir [SpatiaLiteRawGeometry::materialize].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpatiaLiteRawGeometry<'a> {
+    bytes: Cow<'a, [u8]>,
+    kind: GeometryKind,
+    srid: u32,
+    mbr: MBR2D,
+}
+
+impl<'a> SpatiaLiteRawGeometry<'a> {
+    /// Décode l'en-tête d'un BLOB SpatiaLite.
+    ///
+    /// Le reste du BLOB n'est pas validé avant l'appel à [SpatiaLiteRawGeometry::materialize].
+    pub fn from_bytes<B: Into<Cow<'a, [u8]>>>(bytes: B) -> Result<Self, Error> {
+        let bytes = bytes.into();
+        let (kind, srid, mbr) = decode_header(&mut bytes.as_ref())?;
+
+        Ok(Self {
+            bytes,
+            kind,
+            srid,
+            mbr,
+        })
+    }
+
+    pub fn kind(&self) -> GeometryKind {
+        self.kind
+    }
+
+    pub fn srid(&self) -> u32 {
+        self.srid
+    }
+
+    /// Emprise stockée dans l'en-tête, sans vérification.
+    pub fn mbr(&self) -> &MBR2D {
+        &self.mbr
+    }
+
+    /// BLOB SpatiaLite, tel que reçu.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Détache le BLOB du résultat de la requête.
+    pub fn into_owned(self) -> SpatiaLiteRawGeometry<'static> {
+        SpatiaLiteRawGeometry {
+            bytes: Cow::Owned(self.bytes.into_owned()),
+            kind: self.kind,
+            srid: self.srid,
+            mbr: self.mbr,
+        }
+    }
+
+    /// Décode entièrement la géométrie.
+    pub fn materialize(&self) -> Result<Geometry, Error> {
+        SpatiaLiteGeometry::decode_from_slice(&self.bytes).map(Geometry::from)
+    }
+}
+
+/// Lie une géométrie dans une requête sea-query, encodée au format natif de SpatiaLite.
+#[cfg(feature = "sea-query")]
+mod sea_query {
+    use ::sea_query::{ArrayType, ColumnType, Nullable, Value, ValueType, ValueTypeErr};
+
+    use super::*;
+
+    impl From<SpatiaLiteGeometry> for Value {
+        fn from(value: SpatiaLiteGeometry) -> Self {
+            let encoded = value
+                .encode_to_vec()
+                .expect("encoding into a Vec cannot fail");
+            Value::Bytes(Some(Box::new(encoded)))
+        }
+    }
+
+    impl Nullable for SpatiaLiteGeometry {
+        fn null() -> Value {
+            Value::Bytes(None)
+        }
+    }
+
+    impl ValueType for SpatiaLiteGeometry {
+        fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+            match v {
+                Value::Bytes(Some(bytes)) => decode_geometry(&mut bytes.as_slice())
+                    .map(Self::new)
+                    .map_err(|_| ValueTypeErr),
+                _ => Err(ValueTypeErr),
+            }
+        }
+
+        fn type_name() -> String {
+            stringify!(SpatiaLiteGeometry).to_owned()
+        }
+
+        fn array_type() -> ArrayType {
+            ArrayType::Bytes
+        }
+
+        fn column_type() -> ColumnType {
+            ColumnType::Blob
+        }
+    }
+
+    impl_geometry_sea_query_values!(SpatiaLite);
+}
+
+#[cfg(feature = "spatialite")]
 /// Implémente l'encodage / décodage depuis sqlx
 mod sqlx {
     use super::*;
-    use ::sqlx::{sqlite::SqliteValueRef, Database, Decode, Encode, Sqlite, Type};
+    use crate::sql_types::EncodeGeometryRef;
+    use ::sqlx::{
+        encode::IsNull,
+        error::BoxDynError,
+        sqlite::{SqliteArgumentValue, SqliteValueRef},
+        Database, Decode, Encode, Sqlite, Type,
+    };
 
     impl<'r, DB> Type<DB> for SpatiaLiteGeometry
     where
@@ -90,305 +217,586 @@ mod sqlx {
     }
 
     impl<'r> Decode<'r, Sqlite> for SpatiaLiteGeometry {
-        fn decode(value: SqliteValueRef<'r>) -> Result<Self, ::sqlx::error::BoxDynError> {
+        fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
             let mut encoded = <&'r [u8] as Decode<'r, Sqlite>>::decode(value)?;
             let geom = decode_geometry(&mut encoded).map(Self::new)?;
             Ok(geom)
         }
     }
 
-    impl<'q, DB> Encode<'q, DB> for SpatiaLiteGeometry
+    impl<'q> EncodeGeometryRef<'q, Sqlite> for SpatiaLiteGeometry {
+        fn encode_geometry_ref(
+            geometry: GeometryRef<'_>,
+            buf: &mut Vec<SqliteArgumentValue<'q>>,
+        ) -> Result<IsNull, BoxDynError> {
+            // Le blob encodé est directement cédé au buffer d'arguments, sans copie intermédiaire.
+            let mut encoded = Vec::<u8>::with_capacity(encoded_size(geometry));
+            encode_geometry_ref(geometry, &mut encoded)?;
+            buf.push(SqliteArgumentValue::Blob(Cow::Owned(encoded)));
+            Ok(IsNull::No)
+        }
+    }
+
+    impl<'q> Encode<'q, Sqlite> for SpatiaLiteGeometry {
+        fn encode_by_ref(
+            &self,
+            buf: &mut Vec<SqliteArgumentValue<'q>>,
+        ) -> Result<IsNull, BoxDynError> {
+            let encoded = self.encode_to_vec()?;
+            buf.push(SqliteArgumentValue::Blob(Cow::Owned(encoded)));
+            Ok(IsNull::No)
+        }
+    }
+
+    impl_geometry_sqlx_codecs!(SpatiaLite);
+
+    impl<'r, DB> Type<DB> for SpatiaLiteRawGeometry<'_>
     where
         DB: Database,
-        Vec<u8>: Encode<'q, DB>,
+        &'r [u8]: Type<DB>,
     {
+        fn type_info() -> <DB as Database>::TypeInfo {
+            <&[u8]>::type_info()
+        }
+    }
+
+    impl<'r> Decode<'r, Sqlite> for SpatiaLiteRawGeometry<'r> {
+        fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
+            let encoded = <&'r [u8] as Decode<'r, Sqlite>>::decode(value)?;
+            Ok(Self::from_bytes(encoded)?)
+        }
+    }
+
+    impl<'q> Encode<'q, Sqlite> for SpatiaLiteRawGeometry<'_> {
         fn encode_by_ref(
             &self,
-            buf: &mut <DB as ::sqlx::database::HasArguments<'q>>::ArgumentBuffer,
-        ) -> ::sqlx::encode::IsNull {
-            let encoded = self.encode_to_vec().unwrap();
-            encoded.encode_by_ref(buf)
+            buf: &mut Vec<SqliteArgumentValue<'q>>,
+        ) -> Result<IsNull, BoxDynError> {
+            buf.push(SqliteArgumentValue::Blob(Cow::Owned(self.bytes.to_vec())));
+            Ok(IsNull::No)
         }
     }
+}
 
-    impl_geometry_sqlx_codecs!(SpatiaLite);
+/// Encode une géométrie, possédée ou empruntée (géométrie typée, proxy, [GeometryRef]).
+pub fn encode_geometry<'a, G, W>(geometry: G, stream: &mut W) -> Result<(), std::io::Error>
+where
+    G: Into<GeometryRef<'a>>,
+    W: Write,
+{
+    encode_geometry_ref(geometry.into(), stream)
 }
 
-pub fn encode_geometry<W: Write>(
-    geometry: &Geometry,
+/// Taille exacte, en octets, du BLOB SpatiaLite de la géométrie.
+pub fn encoded_size<'a, G: Into<GeometryRef<'a>>>(geometry: G) -> usize {
+    let geometry = geometry.into();
+
+    // start, endianness, SRID, MBR, class, coordinates and end
+    1 + 1 + 4 + 33 + 4 + coordinates_size(geometry.borrow_coordinates()) + 1
+}
+
+/// Encode une géométrie empruntée, sans nécessiter de [Geometry] possédée.
+pub fn encode_geometry_ref<W: Write>(
+    geometry: GeometryRef<'_>,
     stream: &mut W,
 ) -> Result<(), std::io::Error> {
     encode_geometry_with_endianess::<NativeEndian, _>(geometry, stream)
 }
 
+/// Encode la géométrie dans le boutisme `E`.
+///
+/// Les anneaux sont écrits tels quels, comme par les liaisons sqlx et sea-query :
+/// voir [SpatiaLiteEncoder::with_orientation], ou [Geometry::oriented] avant la liaison.
 pub fn encode_geometry_with_endianess<E: ByteOrder, W: Write>(
-    geometry: &Geometry,
+    geometry: GeometryRef<'_>,
     stream: &mut W,
 ) -> Result<(), std::io::Error>
 where
     Endianess: From<PhantomData<E>>,
 {
+    encode_geometry_with_endian(Endianess::from(PhantomData::<E>).into(), geometry, stream)
+}
+
+fn encode_geometry_with_endian<W: Write>(
+    endian: Endian,
+    geometry: GeometryRef<'_>,
+    stream: &mut W,
+) -> Result<(), std::io::Error> {
+    trace_encode(
+        "spatialite",
+        geometry,
+        || encoded_size(geometry),
+        || encode_blob(endian, geometry, stream),
+    )
+}
+
+fn encode_blob<W: Write>(
+    endian: Endian,
+    geometry: GeometryRef<'_>,
+    stream: &mut W,
+) -> Result<(), std::io::Error> {
     // encode start byte, always 0x00
     stream.write_u8(0)?;
 
     // encode endianness
-    stream.write_u8(Endianess::from(PhantomData::<E>).into())?;
+    stream.write_u8(match endian {
+        Endian::Big => BIG_ENDIAN,
+        Endian::Little => LITTLE_ENDIAN,
+    })?;
 
     // encode SRID
-    stream.write_u32::<E>(geometry.srid().unwrap_or(DEFAULT_SRID))?;
+    endian.write_u32(geometry.srid().unwrap_or(DEFAULT_SRID), stream)?;
 
     // encode MBR
-    encode_mbr::<E, _>(&geometry.mbr(), stream)?;
+    encode_mbr(endian, geometry.mbr().as_ref(), stream)?;
 
     // encode geometry class
-    encode_geometry_class::<E, _>(&geometry.kind(), stream)?;
+    encode_geometry_class(endian, &geometry.kind(), stream)?;
 
     // encode the coordinates
-    encode_coordinates::<E, _>(geometry.borrow_coordinates(), stream)?;
+    encode_coordinates(endian, geometry.borrow_coordinates(), stream)?;
 
     // a GEOMETRY encoded BLOB value must always end with a 0xFE byte
     stream.write_u8(0xFE)
 }
 
-pub fn decode_geometry<R: Read>(stream: &mut R) -> Result<Geometry, std::io::Error> {
+/// Encodeur SpatiaLite réutilisable, pour encoder des lots de géométries.
+///
+/// Le tampon interne est conservé d'un encodage à l'autre : une fois dimensionné
+/// pour la plus grande géométrie du lot, il n'est plus réalloué.
+#[derive(Debug, Clone)]
+pub struct SpatiaLiteEncoder {
+    endian: Endian,
+    orientation: RingOrientation,
+    scratch: Vec<u8>,
+}
+
+impl Default for SpatiaLiteEncoder {
+    /// Encodeur dans le boutisme natif.
+    fn default() -> Self {
+        Self::new(Endianess::from(PhantomData::<NativeEndian>))
+    }
+}
+
+impl SpatiaLiteEncoder {
+    pub fn new(endianess: Endianess) -> Self {
+        Self {
+            endian: endianess.into(),
+            orientation: RingOrientation::Native,
+            scratch: Vec::default(),
+        }
+    }
+
+    /// Sens de parcours imposé aux anneaux des polygones encodés.
+    ///
+    /// Une géométrie dont un anneau doit être inversé est clonée avant l'encodage ;
+    /// les autres sont encodées sans copie.
+    pub fn with_orientation(mut self, orientation: RingOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Ajoute la géométrie encodée à la fin de `buf`.
+    pub fn encode_into<'a, G: Into<GeometryRef<'a>>>(
+        &self,
+        geometry: G,
+        buf: &mut Vec<u8>,
+    ) -> Result<(), std::io::Error> {
+        let geometry = geometry.into();
+        let oriented = geometry.to_oriented(self.orientation);
+        let geometry = oriented.as_ref().map_or(geometry, GeometryRef::from);
+
+        buf.reserve(encoded_size(geometry));
+        encode_geometry_with_endian(self.endian, geometry, buf)
+    }
+
+    /// Encode la géométrie dans le tampon interne, valable jusqu'au prochain encodage.
+    pub fn encode<'a, G: Into<GeometryRef<'a>>>(
+        &mut self,
+        geometry: G,
+    ) -> Result<&[u8], std::io::Error> {
+        let mut scratch = std::mem::take(&mut self.scratch);
+        scratch.clear();
+
+        let encoded = self.encode_into(geometry, &mut scratch);
+        self.scratch = scratch;
+        encoded?;
+
+        Ok(&self.scratch)
+    }
+}
+
+/// Encode un lot de géométries, chacune précédée de sa longueur en octets (`u32` petit-boutiste).
+///
+/// Le lot se relit avec [decode_all], pour transférer des géométries hors de la base de données.
+pub fn encode_all<'a, I, W>(geometries: I, stream: &mut W) -> Result<(), std::io::Error>
+where
+    I: IntoIterator<Item = &'a Geometry>,
+    W: Write,
+{
+    let mut encoder = SpatiaLiteEncoder::default();
+
+    geometries
+        .into_iter()
+        .try_for_each(|geometry| write_frame(encoder.encode(geometry)?, stream))
+}
+
+/// Décode les `count` géométries d'un lot écrit par [encode_all].
+///
+/// Le lot doit contenir exactement `count` géométries.
+pub fn decode_all(bytes: &[u8], count: usize) -> Result<Vec<Geometry>, Error> {
+    read_frames(bytes, count, |frame| {
+        SpatiaLiteGeometry::decode_from_slice(frame).map(Geometry::from)
+    })
+}
+
+/// Décode une géométrie au format BLOB de SpatiaLite.
+///
+/// Un BLOB corrompu ou tronqué renvoie une erreur, sans jamais paniquer.
+/// Les options par défaut de [DecodeOptions] s'appliquent.
+pub fn decode_geometry<R: Read>(stream: &mut R) -> Result<Geometry, Error> {
+    decode_geometry_with(stream, &DecodeOptions::default())
+}
+
+/// Décode une géométrie au format BLOB de SpatiaLite selon les options données.
+///
+/// Les erreurs de décodage sont situées dans le flux, voir [Error::Located].
+pub fn decode_geometry_with<R: Read>(
+    stream: &mut R,
+    options: &DecodeOptions,
+) -> Result<Geometry, Error> {
+    let mut stream = CountingReader::new(stream);
+    let mut budget = DecodeBudget::new(&options.limits);
+
+    let (geometry, mbr) = trace_decode(
+        "spatialite",
+        |(geometry, _): &(Geometry, MBR2D)| {
+            (geometry.kind(), geometry.srid(), encoded_size(geometry))
+        },
+        || {
+            decode_blob(&mut stream, &mut budget)
+                .map_err(|error| budget.locate(error, stream.offset()))
+        },
+    )?;
+
+    options.finish(geometry, Some(&mbr))
+}
+
+fn decode_blob<R: Read>(
+    stream: &mut R,
+    budget: &mut DecodeBudget<'_>,
+) -> Result<(Geometry, MBR2D), Error> {
+    budget.enter()?;
+
+    // start, endianness, SRID, MBR, class and end.
+    budget.bytes(1 + 1 + 4 + 33 + 4 + 1)?;
+
     // start byte must be 0x00
-    assert_eq!(stream.read_u8()?, 0);
+    expect_marker(stream, "start", 0x00)?;
+
+    let endian = decode_endianess(stream)?;
+    let decoded = decode_geometry_with_endianess(endian, stream, budget)?;
+
+    budget.leave();
+    Ok(decoded)
+}
+
+/// Décode uniquement l'en-tête du BLOB (SRID, emprise et classe), sans lire les coordonnées.
+pub(crate) fn decode_header<R: Read>(stream: &mut R) -> Result<(GeometryKind, u32, MBR2D), Error> {
+    let endian = decode_prefix(stream)?;
+    decode_header_with_endianess(endian, stream)
+}
+
+/// Lit la classe d'une géométrie SpatiaLite, sans lire l'emprise ni les coordonnées.
+pub fn peek_kind(bytes: &[u8]) -> Result<GeometryKind, Error> {
+    let endian = decode_prefix(&mut &bytes[..])?;
+    decode_geometry_class(endian, &mut bytes.get(CLASS_OFFSET..).unwrap_or_default())
+}
+
+/// Lit le SRID d'une géométrie SpatiaLite, sans lire l'emprise ni les coordonnées.
+pub fn peek_srid(bytes: &[u8]) -> Result<u32, Error> {
+    let mut stream = bytes;
+    let endian = decode_prefix(&mut stream)?;
+    Ok(endian.read_u32(&mut stream)?)
+}
 
-    let endian = stream.read_u8()?;
+/// Position de la classe : octet de début, ordre des octets, SRID et emprise.
+const CLASS_OFFSET: usize = 1 + 1 + 4 + 33;
 
-    if endian == BIG_ENDIAN {
-        decode_geometry_with_endianess::<BigEndian, _>(stream)
-    } else if endian == LITTLE_ENDIAN {
-        decode_geometry_with_endianess::<LittleEndian, _>(stream)
-    } else {
-        panic!("wrong value for endianess")
+/// Lit l'octet de début et l'ordre des octets.
+fn decode_prefix<R: Read>(stream: &mut R) -> Result<Endian, Error> {
+    expect_marker(stream, "start", 0x00)?;
+    decode_endianess(stream)
+}
+
+fn decode_endianess<R: Read>(stream: &mut R) -> Result<Endian, Error> {
+    match stream.read_u8()? {
+        BIG_ENDIAN => Ok(Endian::Big),
+        LITTLE_ENDIAN => Ok(Endian::Little),
+        endian => Err(Error::InvalidByteOrder(endian)),
+    }
+}
+
+fn decode_header_with_endianess<R: Read>(
+    endian: Endian,
+    stream: &mut R,
+) -> Result<(GeometryKind, u32, MBR2D), Error> {
+    let srid = endian.read_u32(stream)?;
+    let mbr = decode_mbr(endian, stream)?;
+    let kind = decode_geometry_class(endian, stream)?;
+
+    Ok((kind, srid, mbr))
+}
+
+/// Lit un octet de balisage et vérifie sa valeur.
+fn expect_marker<R: Read>(
+    stream: &mut R,
+    marker: &'static str,
+    expecting: u8,
+) -> Result<(), Error> {
+    match stream.read_u8()? {
+        got if got == expecting => Ok(()),
+        got => Err(Error::InvalidMarker {
+            marker,
+            expecting,
+            got,
+        }),
     }
 }
 
-fn decode_geometry_with_endianess<E: ByteOrder, R: Read>(
+fn decode_geometry_with_endianess<R: Read>(
+    endian: Endian,
     stream: &mut R,
-) -> Result<Geometry, std::io::Error> {
+    budget: &mut DecodeBudget<'_>,
+) -> Result<(Geometry, MBR2D), Error> {
     // Read the SRID
-    let srid: u32 = stream.read_u32::<E>()?;
+    let srid: u32 = endian.read_u32(stream)?;
 
     // Read MBR
-    let _mbr = decode_mbr::<E, _>(stream)?;
+    let mbr = decode_mbr(endian, stream)?;
 
     // Read the geometry class
-    let kind = decode_geometry_class::<E, _>(stream)?;
+    let kind = decode_geometry_class(endian, stream)?;
+    budget.set_kind(kind);
 
     // Decode the coordinates depending of the geometry class.
     let mut geom: Geometry = match kind {
-        GeometryKind::Point => Point::new(decode_vector::<2, E, _>(stream)?).into(),
-        GeometryKind::LineString => LineString::new(decode_array::<2, E, _>(stream)?).into(),
-        GeometryKind::Polygon => Polygon::new(decode_matrix::<2, E, _>(stream)?).into(),
-        GeometryKind::MultiPoint => MultiPoint::new(decode_array::<2, E, _>(stream)?).into(),
+        GeometryKind::Point => Point::new(decode_vector::<2, _>(endian, stream, budget)?).into(),
+        GeometryKind::LineString => {
+            LineString::new(decode_array::<2, _>(endian, stream, budget)?).into()
+        }
+        GeometryKind::Polygon => {
+            Polygon::new(decode_matrix::<2, _>(endian, stream, budget)?).into()
+        }
+        GeometryKind::MultiPoint => {
+            MultiPoint::new(decode_array::<2, _>(endian, stream, budget)?).into()
+        }
         GeometryKind::MultiLineString => {
-            MultiLineString::new(decode_matrix::<2, E, _>(stream)?).into()
+            MultiLineString::new(decode_matrix::<2, _>(endian, stream, budget)?).into()
+        }
+        GeometryKind::MultiPolygon => {
+            MultiPolygon::new(decode_tensor::<2, _>(endian, stream, budget)?).into()
+        }
+        kind @ (GeometryKind::GeometryCollection | GeometryKind::GeometryCollectionZ) => {
+            return Err(Error::unsupported_geometry(
+                kind,
+                "geometry collections are not supported",
+            ))
+        }
+        GeometryKind::PointZ => PointZ::new(decode_vector::<3, _>(endian, stream, budget)?).into(),
+        GeometryKind::LineStringZ => {
+            LineStringZ::new(decode_array::<3, _>(endian, stream, budget)?).into()
+        }
+        GeometryKind::PolygonZ => {
+            PolygonZ::new(decode_matrix::<3, _>(endian, stream, budget)?).into()
+        }
+        GeometryKind::MultiPointZ => {
+            MultiPointZ::new(decode_array::<3, _>(endian, stream, budget)?).into()
         }
-        GeometryKind::MultiPolygon => MultiPolygon::new(decode_tensor::<2, E, _>(stream)?).into(),
-        GeometryKind::GeometryCollection => todo!(),
-        GeometryKind::PointZ => PointZ::new(decode_vector::<3, E, _>(stream)?).into(),
-        GeometryKind::LineStringZ => LineStringZ::new(decode_array::<3, E, _>(stream)?).into(),
-        GeometryKind::PolygonZ => PolygonZ::new(decode_matrix::<3, E, _>(stream)?).into(),
-        GeometryKind::MultiPointZ => MultiPointZ::new(decode_array::<3, E, _>(stream)?).into(),
         GeometryKind::MultiLineStringZ => {
-            MultiLineStringZ::new(decode_matrix::<3, E, _>(stream)?).into()
+            MultiLineStringZ::new(decode_matrix::<3, _>(endian, stream, budget)?).into()
+        }
+        GeometryKind::MultiPolygonZ => {
+            MultiPolygonZ::new(decode_tensor::<3, _>(endian, stream, budget)?).into()
         }
-        GeometryKind::MultiPolygonZ => MultiPolygonZ::new(decode_tensor::<3, E, _>(stream)?).into(),
-        GeometryKind::GeometryCollectionZ => todo!(),
     };
 
     geom.set_srid(Some(srid));
 
-    let end = stream.read_u8()?;
-    assert_eq!(end, 0xFE);
+    expect_marker(stream, "end", 0xFE)?;
 
-    Ok(geom)
+    Ok((geom, mbr))
 }
 
-fn encode_geometry_class<E: ByteOrder, W: Write>(
+fn encode_geometry_class<W: Write>(
+    endian: Endian,
     kind: &GeometryKind,
     stream: &mut W,
 ) -> Result<(), std::io::Error> {
-    let encoded = match kind {
-        GeometryKind::Point => 1,
-        GeometryKind::LineString => 2,
-        GeometryKind::Polygon => 3,
-        GeometryKind::MultiPoint => 4,
-        GeometryKind::MultiLineString => 5,
-        GeometryKind::MultiPolygon => 6,
-        GeometryKind::GeometryCollection => 7,
-        GeometryKind::PointZ => 1001,
-        GeometryKind::LineStringZ => 1002,
-        GeometryKind::PolygonZ => 1003,
-        GeometryKind::MultiPointZ => 1004,
-        GeometryKind::MultiLineStringZ => 10015,
-        GeometryKind::MultiPolygonZ => 1006,
-        GeometryKind::GeometryCollectionZ => 1007,
-    };
+    let encoded = kind.wkb_code();
 
-    stream.write_u32::<E>(encoded)
+    endian.write_u32(encoded, stream)
 }
 
-fn decode_geometry_class<E: ByteOrder, R: Read>(
-    stream: &mut R,
-) -> Result<GeometryKind, std::io::Error> {
-    Ok(match stream.read_u32::<E>()? {
-        1 => GeometryKind::Point,
-        2 => GeometryKind::LineString,
-        3 => GeometryKind::Polygon,
-        4 => GeometryKind::MultiPoint,
-        5 => GeometryKind::MultiLineString,
-        6 => GeometryKind::MultiPolygon,
-        7 => GeometryKind::GeometryCollection,
-
-        1001 => GeometryKind::PointZ,
-        1002 => GeometryKind::LineStringZ,
-        1003 => GeometryKind::PolygonZ,
-        1004 => GeometryKind::MultiPointZ,
-        1005 => GeometryKind::MultiLineStringZ,
-        1006 => GeometryKind::MultiPolygonZ,
-        1007 => GeometryKind::MultiLineStringZ,
-
-        _ => panic!("unknown WKB geometry"),
-    })
+fn decode_geometry_class<R: Read>(endian: Endian, stream: &mut R) -> Result<GeometryKind, Error> {
+    let code = endian.read_u32(stream)?;
+    GeometryKind::from_wkb_code(code).ok_or(Error::UnknownGeometryCode(code))
 }
 
-fn encode_mbr<E: ByteOrder, W: Write>(
-    mbr: &MBR<f64>,
+/// Le format SpatiaLite ne stocke que l'étendue planaire : `min_z` et `max_z` sont ignorés.
+///
+/// Une géométrie vide est encodée avec un rectangle nul.
+fn encode_mbr<W: Write>(
+    endian: Endian,
+    mbr: Option<&MBR2D>,
     stream: &mut W,
 ) -> Result<(), std::io::Error> {
-    stream.write_f64::<E>(mbr.min_x)?;
-    stream.write_f64::<E>(mbr.min_y)?;
-    stream.write_f64::<E>(mbr.max_x)?;
-    stream.write_f64::<E>(mbr.max_y)?;
+    let (min_x, min_y, max_x, max_y) = mbr
+        .map(|mbr| (mbr.min_x(), mbr.min_y(), mbr.max_x(), mbr.max_y()))
+        .unwrap_or_default();
+
+    endian.write_f64s(&[min_x, min_y, max_x, max_y], stream)?;
     stream.write_u8(0x7C)
 }
 
-fn decode_mbr<E: ByteOrder, R: Read>(stream: &mut R) -> Result<MBR<f64>, std::io::Error> {
-    let min_x = stream.read_f64::<E>()?;
-    let min_y = stream.read_f64::<E>()?;
-    let max_x = stream.read_f64::<E>()?;
-    let max_y = stream.read_f64::<E>()?;
-    let mbr_end = stream.read_u8()?;
-
-    assert_eq!(mbr_end, 0x7C);
+fn decode_mbr<R: Read>(endian: Endian, stream: &mut R) -> Result<MBR2D, Error> {
+    let [min_x, min_y, max_x, max_y] = endian.read_f64s(stream)?;
+    expect_marker(stream, "MBR end", 0x7C)?;
 
-    Ok(MBR {
-        min_x,
-        max_x,
-        min_y,
-        max_y,
-    })
+    Ok(MBR2D::new([min_x, min_y], [max_x, max_y]))
 }
 
-fn encode_coordinates<E: ByteOrder, W: Write>(
+fn encode_coordinates<W: Write>(
+    endian: Endian,
     coordinates: CoordinatesRef<'_>,
     stream: &mut W,
 ) -> Result<(), std::io::Error> {
     match coordinates {
-        CoordinatesRef::Vector2D(vector) => encode_vector::<2, E, _>(vector, stream),
-        CoordinatesRef::VectorArray2D(array) => encode_array::<2, E, _>(array, stream),
-        CoordinatesRef::VectorMatrix2D(matrix) => encode_matrix::<2, E, _>(matrix, stream),
-        CoordinatesRef::VectorTensor2D(tensor) => encode_tensor::<2, E, _>(tensor, stream),
-        CoordinatesRef::Vector3D(vector) => encode_vector::<3, E, _>(vector, stream),
-        CoordinatesRef::VectorArray3D(array) => encode_array::<3, E, _>(array, stream),
-        CoordinatesRef::VectorMatrix3D(matrix) => encode_matrix::<3, E, _>(matrix, stream),
-        CoordinatesRef::VectorTensor3D(tensor) => encode_tensor::<3, E, _>(tensor, stream),
+        CoordinatesRef::Vector2D(vector) => encode_vector::<2, _>(endian, vector, stream),
+        CoordinatesRef::VectorArray2D(array) => encode_array::<2, _>(endian, array, stream),
+        CoordinatesRef::VectorMatrix2D(matrix) => encode_matrix::<2, _>(endian, matrix, stream),
+        CoordinatesRef::VectorTensor2D(tensor) => encode_tensor::<2, _>(endian, tensor, stream),
+        CoordinatesRef::Vector3D(vector) => encode_vector::<3, _>(endian, vector, stream),
+        CoordinatesRef::VectorArray3D(array) => encode_array::<3, _>(endian, array, stream),
+        CoordinatesRef::VectorMatrix3D(matrix) => encode_matrix::<3, _>(endian, matrix, stream),
+        CoordinatesRef::VectorTensor3D(tensor) => encode_tensor::<3, _>(endian, tensor, stream),
     }
 }
 
-fn encode_vector<const N: usize, E: ByteOrder, W: Write>(
+fn encode_vector<const N: usize, W: Write>(
+    endian: Endian,
     vector: &Vector<N, f64>,
     stream: &mut W,
 ) -> Result<(), std::io::Error> {
-    vector
-        .iter()
-        .copied()
-        .try_for_each(|scalar| stream.write_f64::<E>(scalar))
+    endian.write_f64s(vector, stream)
 }
 
-fn decode_vector<const N: usize, E: ByteOrder, R: Read>(
+fn decode_vector<const N: usize, R: Read>(
+    endian: Endian,
     stream: &mut R,
-) -> Result<Vector<N, f64>, std::io::Error> {
-    let mut scalars: [f64; N] = [0f64; N];
-
-    for i in 0..N {
-        scalars[i] = stream.read_f64::<E>()?;
-    }
+    budget: &mut DecodeBudget<'_>,
+) -> Result<Vector<N, f64>, Error> {
+    budget.vertices::<N>(1)?;
+    read_vector::<N, _>(endian, stream)
+}
 
-    Ok(Vector::new(scalars))
+fn read_vector<const N: usize, R: Read>(
+    endian: Endian,
+    stream: &mut R,
+) -> Result<Vector<N, f64>, Error> {
+    Ok(Vector::new(endian.read_f64s(stream)?))
 }
 
-fn encode_array<const N: usize, E: ByteOrder, W: Write>(
+fn encode_array<const N: usize, W: Write>(
+    endian: Endian,
     array: &VectorArray<N, f64>,
     stream: &mut W,
 ) -> Result<(), std::io::Error> {
-    stream.write_u32::<E>(array.len() as u32)?;
+    endian.write_u32(array.len() as u32, stream)?;
     array
         .iter()
-        .try_for_each(|vector| encode_vector::<N, E, _>(vector, stream))
+        .try_for_each(|vector| encode_vector::<N, _>(endian, vector, stream))
 }
 
-fn decode_array<const N: usize, E: ByteOrder, R: Read>(
+fn decode_array<const N: usize, R: Read>(
+    endian: Endian,
     stream: &mut R,
-) -> Result<VectorArray<N, f64>, std::io::Error> {
-    let nb_points: u32 = stream.read_u32::<E>()?;
-    let mut coordinates = Vec::<Vector<N, f64>>::with_capacity(nb_points as usize);
-
-    for _ in 0..nb_points {
-        coordinates.push(decode_vector::<N, E, _>(stream)?);
+    budget: &mut DecodeBudget<'_>,
+) -> Result<VectorArray<N, f64>, Error> {
+    budget.bytes(4)?;
+    let nb_points = budget.vertices::<N>(endian.read_u32(stream)?)?;
+    let mut coordinates = Vec::<Vector<N, f64>>::with_capacity(budget.capacity(nb_points));
+
+    budget.enter_part();
+    for i in 0..nb_points {
+        budget.at_part(i);
+        coordinates.push(read_vector::<N, _>(endian, stream)?);
     }
+    budget.leave_part();
 
     Ok(VectorArray::new(coordinates))
 }
 
-fn encode_matrix<const N: usize, E: ByteOrder, W: Write>(
+fn encode_matrix<const N: usize, W: Write>(
+    endian: Endian,
     matrix: &VectorMatrix<N, f64>,
     stream: &mut W,
 ) -> Result<(), std::io::Error> {
-    stream.write_u32::<E>(matrix.len() as u32)?;
+    endian.write_u32(matrix.len() as u32, stream)?;
     matrix
         .iter()
-        .try_for_each(|array| encode_array::<N, E, _>(array, stream))
+        .try_for_each(|array| encode_array::<N, _>(endian, array, stream))
 }
 
-fn decode_matrix<const N: usize, E: ByteOrder, R: Read>(
+fn decode_matrix<const N: usize, R: Read>(
+    endian: Endian,
     stream: &mut R,
-) -> Result<VectorMatrix<N, f64>, std::io::Error> {
-    let nb_points: u32 = stream.read_u32::<E>()?;
-    let mut coordinates = Vec::<VectorArray<N, f64>>::with_capacity(nb_points as usize);
-
-    for _ in 0..nb_points {
-        coordinates.push(decode_array::<N, E, _>(stream)?);
+    budget: &mut DecodeBudget<'_>,
+) -> Result<VectorMatrix<N, f64>, Error> {
+    budget.bytes(4)?;
+    let nb_parts = budget.rings(endian.read_u32(stream)?)?;
+    let mut coordinates = Vec::<VectorArray<N, f64>>::with_capacity(budget.capacity(nb_parts));
+
+    budget.enter_part();
+    for i in 0..nb_parts {
+        budget.at_part(i);
+        coordinates.push(decode_array::<N, _>(endian, stream, budget)?);
     }
+    budget.leave_part();
 
     Ok(VectorMatrix::new(coordinates))
 }
 
-fn encode_tensor<const N: usize, E: ByteOrder, W: Write>(
+fn encode_tensor<const N: usize, W: Write>(
+    endian: Endian,
     tensor: &VectorTensor<N, f64>,
     stream: &mut W,
 ) -> Result<(), std::io::Error> {
-    stream.write_u32::<E>(tensor.len() as u32)?;
+    endian.write_u32(tensor.len() as u32, stream)?;
     tensor
         .iter()
-        .try_for_each(|matrix| encode_matrix::<N, E, _>(matrix, stream))
+        .try_for_each(|matrix| encode_matrix::<N, _>(endian, matrix, stream))
 }
 
-fn decode_tensor<const N: usize, E: ByteOrder, R: Read>(
+fn decode_tensor<const N: usize, R: Read>(
+    endian: Endian,
     stream: &mut R,
-) -> Result<VectorTensor<N, f64>, std::io::Error> {
-    let nb_points: u32 = stream.read_u32::<E>()?;
-    let mut coordinates = Vec::<VectorMatrix<N, f64>>::with_capacity(nb_points as usize);
-
-    for _ in 0..nb_points {
-        coordinates.push(decode_matrix::<N, E, _>(stream)?);
+    budget: &mut DecodeBudget<'_>,
+) -> Result<VectorTensor<N, f64>, Error> {
+    budget.bytes(4)?;
+    let nb_parts = budget.rings(endian.read_u32(stream)?)?;
+    let mut coordinates = Vec::<VectorMatrix<N, f64>>::with_capacity(budget.capacity(nb_parts));
+
+    budget.enter_part();
+    for i in 0..nb_parts {
+        budget.at_part(i);
+        coordinates.push(decode_matrix::<N, _>(endian, stream, budget)?);
     }
+    budget.leave_part();
 
     Ok(VectorTensor::new(coordinates))
 }
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Endianess {
     BigEndian,
     LittleEndian,
@@ -406,6 +814,15 @@ impl From<PhantomData<LittleEndian>> for Endianess {
     }
 }
 
+impl From<Endianess> for Endian {
+    fn from(value: Endianess) -> Self {
+        match value {
+            Endianess::BigEndian => Endian::Big,
+            Endianess::LittleEndian => Endian::Little,
+        }
+    }
+}
+
 impl From<Endianess> for u8 {
     fn from(value: Endianess) -> Self {
         match value {
@@ -418,12 +835,241 @@ impl From<Endianess> for u8 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::io::SridPolicy;
 
     #[test]
     pub fn test_isomorphism() {
-        let expected = SpatiaLiteGeometry::new(Point::new([10.0, 20.0]));
+        // Le format SpatiaLite porte toujours un SRID.
+        let mut expected = SpatiaLiteGeometry::new(Point::new([10.0, 20.0]));
+        expected.set_srid(Some(DEFAULT_SRID));
+
         let bytes = expected.encode_to_vec().expect("cannot encode geometry");
         let value = SpatiaLiteGeometry::decode_from_slice(&bytes).expect("cannot decode geometry");
         assert_eq!(value, expected)
     }
+
+    #[test]
+    pub fn test_raw_geometry() {
+        let mut polygon = Polygon::new([[0.0, 0.0], [4.0, 0.0], [4.0, 2.0], [0.0, 0.0]]);
+        polygon.srid = Some(2154);
+
+        let bytes = SpatiaLiteGeometry::new(polygon.clone())
+            .encode_to_vec()
+            .expect("cannot encode geometry");
+
+        let raw =
+            SpatiaLiteRawGeometry::from_bytes(bytes.as_slice()).expect("cannot decode the header");
+
+        assert_eq!(raw.kind(), GeometryKind::Polygon);
+        assert_eq!(raw.srid(), 2154);
+        assert_eq!(raw.mbr(), &MBR2D::new([0.0, 0.0], [4.0, 2.0]));
+        assert_eq!(raw.as_bytes(), bytes.as_slice());
+        assert_eq!(raw.materialize().ok(), Some(Geometry::from(polygon)));
+    }
+
+    #[test]
+    fn test_decode_options() {
+        let mut geometry = SpatiaLiteGeometry::new(Point::new([10.0, 20.0]));
+        geometry.set_srid(Some(DEFAULT_SRID));
+        let bytes = geometry.encode_to_vec().expect("cannot encode geometry");
+
+        let strict = DecodeOptions::strict();
+        assert!(decode_geometry_with(&mut bytes.as_slice(), &strict).is_ok());
+
+        // L'emprise embarquée commence après le début, le boutisme et le SRID.
+        let mut forged_mbr = bytes.clone();
+        forged_mbr[6..14].copy_from_slice(&(-1.0f64).to_le_bytes());
+        assert!(
+            decode_geometry_with(&mut forged_mbr.as_slice(), &DecodeOptions::default()).is_ok()
+        );
+        assert!(matches!(
+            decode_geometry_with(&mut forged_mbr.as_slice(), &strict),
+            Err(Error::InvalidCoordinates { .. })
+        ));
+
+        let expect = DecodeOptions {
+            srid: SridPolicy::Expect(2154),
+            ..DecodeOptions::default()
+        };
+        assert!(matches!(
+            decode_geometry_with(&mut bytes.as_slice(), &expect),
+            Err(Error::SridMismatch {
+                expecting: Some(2154),
+                got: Some(DEFAULT_SRID)
+            })
+        ));
+    }
+
+    #[test]
+    fn test_corrupt_input_is_an_error() {
+        let mut geometry = SpatiaLiteGeometry::new(Point::new([10.0, 20.0]));
+        geometry.set_srid(Some(DEFAULT_SRID));
+        let bytes = geometry.encode_to_vec().expect("cannot encode geometry");
+
+        let mut bad_start = bytes.clone();
+        bad_start[0] = 0x01;
+        assert!(matches!(
+            SpatiaLiteGeometry::decode_from_slice(&bad_start).map_err(Error::into_inner),
+            Err(Error::InvalidMarker {
+                marker: "start",
+                ..
+            })
+        ));
+
+        let mut bad_endianness = bytes.clone();
+        bad_endianness[1] = 0x02;
+        assert!(matches!(
+            SpatiaLiteGeometry::decode_from_slice(&bad_endianness).map_err(Error::into_inner),
+            Err(Error::InvalidByteOrder(0x02))
+        ));
+
+        let mut bad_end = bytes.clone();
+        *bad_end.last_mut().unwrap() = 0x00;
+        assert!(matches!(
+            SpatiaLiteGeometry::decode_from_slice(&bad_end).map_err(Error::into_inner),
+            Err(Error::InvalidMarker { marker: "end", .. })
+        ));
+
+        assert!(matches!(
+            SpatiaLiteGeometry::decode_from_slice(&bytes[..bytes.len() - 4])
+                .map_err(Error::into_inner),
+            Err(Error::UnexpectedEof)
+        ));
+    }
+
+    #[test]
+    fn test_encoded_size() {
+        let geometries: [Geometry; 5] = [
+            Point::new([10.0, 20.0]).into(),
+            PointZ::new([1.0, 2.0, 3.0]).into(),
+            LineString::new([[0.0, 0.0], [1.0, 1.0], [2.0, 0.0]]).into(),
+            Polygon::new((
+                [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 0.0]],
+                [[1.0, 1.0], [2.0, 1.0], [2.0, 2.0], [1.0, 1.0]],
+            ))
+            .into(),
+            MultiPolygon::new(VectorTensor::from_iter([
+                vec![vec![[0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 0.0]]],
+                vec![vec![[10.0, -2.0], [12.0, -2.0], [12.0, 1.0], [10.0, -2.0]]],
+            ]))
+            .into(),
+        ];
+
+        for geometry in geometries {
+            let mut geometry = SpatiaLiteGeometry::new(geometry);
+            geometry.set_srid(Some(4326));
+
+            let bytes = geometry.encode_to_vec().expect("cannot encode geometry");
+            assert_eq!(geometry.encoded_size(), Some(bytes.len()));
+        }
+    }
+
+    #[test]
+    fn test_encoder() {
+        let mut geometry: Geometry = LineString::new([[0.0, 0.0], [1.0, 1.0], [2.0, 0.0]]).into();
+        geometry.set_srid(Some(DEFAULT_SRID));
+
+        let mut batch = Vec::default();
+        for endianess in [Endianess::LittleEndian, Endianess::BigEndian] {
+            let encoder = SpatiaLiteEncoder::new(endianess);
+            encoder
+                .encode_into(&geometry, &mut batch)
+                .expect("cannot encode geometry");
+        }
+
+        let (little, consumed) = SpatiaLiteGeometry::decode_partial(&batch).unwrap();
+        assert_eq!(batch[1], LITTLE_ENDIAN);
+        assert_eq!(batch[consumed + 1], BIG_ENDIAN);
+
+        let big = SpatiaLiteGeometry::decode_from_slice(&batch[consumed..]).unwrap();
+        assert_eq!(*little, geometry);
+        assert_eq!(*big, geometry);
+    }
+
+    #[test]
+    fn test_encoder_orientation() {
+        let polygon = Polygon::new((
+            [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0]],
+            [[4.0, 4.0], [6.0, 4.0], [6.0, 6.0], [4.0, 6.0]],
+        ));
+
+        let mut encoder = SpatiaLiteEncoder::default().with_orientation(RingOrientation::Clockwise);
+        let encoded = encoder.encode(&polygon).unwrap();
+        let decoded = Geometry::from(SpatiaLiteGeometry::decode_from_slice(encoded).unwrap());
+
+        assert!(decoded.is_oriented(RingOrientation::Clockwise));
+        assert!(!Geometry::from(polygon).is_oriented(RingOrientation::Clockwise));
+    }
+
+    #[test]
+    fn test_encode_all() {
+        let geometries: Vec<Geometry> = [
+            Geometry::from(Point::new([10.0, 20.0])),
+            Geometry::from(Polygon::new([
+                [0.0, 0.0],
+                [1.0, 0.0],
+                [1.0, 1.0],
+                [0.0, 0.0],
+            ])),
+        ]
+        .into_iter()
+        .map(|mut geometry| {
+            geometry.set_srid(Some(DEFAULT_SRID));
+            geometry
+        })
+        .collect();
+
+        let mut bytes = Vec::default();
+        encode_all(&geometries, &mut bytes).expect("cannot encode geometries");
+
+        assert_eq!(
+            decode_all(&bytes, 2).expect("cannot decode geometries"),
+            geometries
+        );
+        assert!(decode_all(&bytes, 1).is_err());
+    }
+
+    #[test]
+    fn test_peek_header() {
+        let mut geometry = Geometry::from(Polygon::new([
+            [0.0, 0.0],
+            [1.0, 0.0],
+            [1.0, 1.0],
+            [0.0, 0.0],
+        ]));
+        geometry.set_srid(Some(2154));
+
+        let mut bytes = Vec::default();
+        encode_geometry(&geometry, &mut bytes).unwrap();
+
+        assert_eq!(peek_kind(&bytes).unwrap(), GeometryKind::Polygon);
+        assert_eq!(peek_srid(&bytes).unwrap(), 2154);
+
+        // Les coordonnées ne sont pas lues.
+        assert_eq!(peek_kind(&bytes[..43]).unwrap(), GeometryKind::Polygon);
+        assert!(peek_kind(&bytes[..42]).is_err());
+        assert!(peek_srid(&[0x01]).is_err());
+    }
+
+    /// Une géométrie nue est liée en EWKB, illisible pour SpatiaLite : le proxy est requis.
+    #[cfg(all(feature = "sea-query", feature = "ewkb"))]
+    #[test]
+    fn test_sea_query_value_requires_proxy() {
+        let geometry = Geometry::from(Point::new([10.0, 20.0]));
+
+        let ::sea_query::Value::Bytes(Some(bytes)) = ::sea_query::Value::from(geometry.clone())
+        else {
+            panic!("expecting a bytes value");
+        };
+        assert!(decode_geometry(&mut bytes.as_slice()).is_err());
+
+        let ::sea_query::Value::Bytes(Some(bytes)) =
+            ::sea_query::Value::from(SpatiaLiteGeometry::new(geometry.clone()))
+        else {
+            panic!("expecting a bytes value");
+        };
+        let mut expected = geometry;
+        expected.set_srid(Some(DEFAULT_SRID));
+        assert_eq!(decode_geometry(&mut bytes.as_slice()).unwrap(), expected);
+    }
 }
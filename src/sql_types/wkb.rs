@@ -0,0 +1,141 @@
+//! Décodage du résultat de `ST_AsBinary(geom)`.
+use std::ops::{Deref, DerefMut};
+
+use ::sqlx::{error::BoxDynError, Database, Decode, Type};
+
+use crate::{error::Error, ewkb, types::Geometry};
+
+#[derive(Debug, Clone, PartialEq)]
+/// Géométrie sélectionnée au format WKB, typiquement via `ST_AsBinary(geom)`.
+///
+/// Le WKB n'embarque pas de SRID, la géométrie décodée n'en a donc pas.
+pub struct WkbBytes(pub(crate) Geometry);
+
+impl WkbBytes {
+    pub fn into_geometry(self) -> Geometry {
+        self.0
+    }
+}
+
+impl Deref for WkbBytes {
+    type Target = Geometry;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for WkbBytes {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<WkbBytes> for Geometry {
+    fn from(value: WkbBytes) -> Self {
+        value.0
+    }
+}
+
+/// Décode un WKB ; le WKB est un sous-ensemble de l'EWKB, sans SRID.
+fn decode_wkb(mut encoded: &[u8]) -> Result<Geometry, Error> {
+    ewkb::decode_geometry(&mut encoded)
+}
+
+impl<DB> Type<DB> for WkbBytes
+where
+    DB: Database,
+    Vec<u8>: Type<DB>,
+{
+    fn type_info() -> <DB as Database>::TypeInfo {
+        <Vec<u8> as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &<DB as Database>::TypeInfo) -> bool {
+        <Vec<u8> as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'r, DB> Decode<'r, DB> for WkbBytes
+where
+    DB: Database,
+    &'r [u8]: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        let encoded = <&'r [u8] as Decode<'r, DB>>::decode(value)?;
+        Ok(Self(decode_wkb(encoded)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{GeometryImpl as _, GeometryKind, MultiPoint};
+
+    /// En-tête ISO WKB petit-boutiste de code `code`.
+    fn iso_header(code: u32) -> Vec<u8> {
+        let mut bytes = vec![1];
+        bytes.extend_from_slice(&code.to_le_bytes());
+        bytes
+    }
+
+    fn push_f64s(bytes: &mut Vec<u8>, values: &[f64]) {
+        values
+            .iter()
+            .for_each(|v| bytes.extend_from_slice(&v.to_le_bytes()));
+    }
+
+    #[test]
+    fn test_decode_iso_point_z() {
+        let mut bytes = iso_header(1001);
+        push_f64s(&mut bytes, &[1.0, 2.0, 3.0]);
+
+        let geometry = decode_wkb(&bytes).expect("cannot decode ISO WKB");
+        assert_eq!(geometry.kind(), GeometryKind::PointZ);
+        assert_eq!(geometry.srid(), None);
+    }
+
+    #[test]
+    fn test_decode_iso_polygon_z() {
+        let mut bytes = iso_header(1003);
+        bytes.extend_from_slice(&1u32.to_le_bytes());
+        bytes.extend_from_slice(&4u32.to_le_bytes());
+        push_f64s(
+            &mut bytes,
+            &[0.0, 0.0, 1.0, 1.0, 0.0, 2.0, 1.0, 1.0, 3.0, 0.0, 0.0, 1.0],
+        );
+
+        let geometry = decode_wkb(&bytes).expect("cannot decode ISO WKB");
+        assert_eq!(geometry.kind(), GeometryKind::PolygonZ);
+        assert_eq!(geometry.srid(), None);
+    }
+
+    /// Multi-géométries telles que renvoyées par `ST_AsBinary` : chaque membre porte
+    /// son propre en-tête.
+    #[test]
+    fn test_decode_iso_multi_geometries() {
+        // ST_AsBinary('MULTIPOINT((1 2),(3 4))'::geometry, 'NDR')
+        let bytes = ewkb::decode_hex(
+            "0104000000020000000101000000000000000000F03F00000000000000400101000000000000\
+             00000008400000000000001040",
+        )
+        .unwrap();
+        let geometry = decode_wkb(&bytes).expect("cannot decode ISO WKB");
+        assert_eq!(
+            geometry,
+            Geometry::from(MultiPoint::new([[1.0, 2.0], [3.0, 4.0]]))
+        );
+
+        // ST_AsBinary('MULTILINESTRING Z ((0 0 1,1 1 2),(2 2 3,3 3 4))'::geometry, 'NDR')
+        let bytes = ewkb::decode_hex(
+            "01ED0300000200000001EA030000020000000000000000000000000000000000000000000000\
+             0000F03F000000000000F03F000000000000F03F000000000000004001EA0300000200000000\
+             0000000000004000000000000000400000000000000840000000000000084000000000000008\
+             400000000000001040",
+        )
+        .unwrap();
+        let geometry = decode_wkb(&bytes).expect("cannot decode ISO WKB");
+        assert_eq!(geometry.kind(), GeometryKind::MultiLineStringZ);
+        assert_eq!(geometry.srid(), None);
+    }
+}
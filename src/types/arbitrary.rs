@@ -0,0 +1,124 @@
+//! Génération de géométries arbitraires (fonctionnalité `arbitrary`), pour les
+//! tests de propriété et le fuzzing.
+//!
+//! Les tailles sont bornées pour garder des cas de test rapides, les coordonnées
+//! sont toujours finies et les anneaux des polygones sont fermés.
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+use super::{
+    line_string::LineString, multi_line_string::MultiLineString, multi_point::MultiPoint,
+    multi_polygon::MultiPolygon, point::Point, polygon::Polygon, Geometry, GeometryImpl, Vector,
+    VectorArray, VectorMatrix, VectorTensor,
+};
+
+/// Nombre maximal de points d'une ligne ou d'un anneau.
+const MAX_VERTICES: usize = 16;
+
+/// Nombre maximal de parties (anneaux, lignes, polygones) d'un niveau d'imbrication.
+const MAX_PARTS: usize = 4;
+
+fn collect<'a, T, F>(u: &mut Unstructured<'a>, max: usize, mut f: F) -> Result<Vec<T>>
+where
+    F: FnMut(&mut Unstructured<'a>) -> Result<T>,
+{
+    let len = u.int_in_range(0..=max)?;
+    (0..len).map(|_| f(u)).collect()
+}
+
+impl<'a, const N: usize> Arbitrary<'a> for Vector<N, f64> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut scalars = [0.0; N];
+
+        for scalar in scalars.iter_mut() {
+            let value = f64::arbitrary(u)?;
+            *scalar = if value.is_finite() { value } else { 0.0 };
+        }
+
+        Ok(Vector::new(scalars))
+    }
+}
+
+impl<'a, const N: usize> Arbitrary<'a> for VectorArray<N, f64> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        collect(u, MAX_VERTICES, Vector::arbitrary).map(VectorArray::new)
+    }
+}
+
+impl<'a, const N: usize> Arbitrary<'a> for VectorMatrix<N, f64> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        collect(u, MAX_PARTS, VectorArray::arbitrary).map(VectorMatrix::new)
+    }
+}
+
+impl<'a, const N: usize> Arbitrary<'a> for VectorTensor<N, f64> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        collect(u, MAX_PARTS, VectorMatrix::arbitrary).map(VectorTensor::new)
+    }
+}
+
+/// Implémente [Arbitrary] pour une géométrie via son constructeur,
+/// qui se charge par exemple de fermer les anneaux.
+macro_rules! impl_arbitrary_geometry {
+    ($($geometry:ident),*) => {
+        $(
+            impl<'a, const N: usize> Arbitrary<'a> for $geometry<N, f64> {
+                fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                    let mut geometry = Self::new(<Self as GeometryImpl>::Coordinates::arbitrary(u)?);
+                    geometry.srid = Option::<u32>::arbitrary(u)?;
+                    Ok(geometry)
+                }
+            }
+        )*
+    };
+}
+
+impl_arbitrary_geometry!(
+    Point,
+    MultiPoint,
+    LineString,
+    MultiLineString,
+    Polygon,
+    MultiPolygon
+);
+
+impl<'a> Arbitrary<'a> for Geometry {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(match u.int_in_range(0..=11)? {
+            0 => super::Point::arbitrary(u)?.into(),
+            1 => super::MultiPoint::arbitrary(u)?.into(),
+            2 => super::LineString::arbitrary(u)?.into(),
+            3 => super::MultiLineString::arbitrary(u)?.into(),
+            4 => super::Polygon::arbitrary(u)?.into(),
+            5 => super::MultiPolygon::arbitrary(u)?.into(),
+            6 => super::PointZ::arbitrary(u)?.into(),
+            7 => super::MultiPointZ::arbitrary(u)?.into(),
+            8 => super::LineStringZ::arbitrary(u)?.into(),
+            9 => super::MultiLineStringZ::arbitrary(u)?.into(),
+            10 => super::PolygonZ::arbitrary(u)?.into(),
+            _ => super::MultiPolygonZ::arbitrary(u)?.into(),
+        })
+    }
+}
+
+#[cfg(all(test, feature = "ewkb"))]
+mod tests {
+    use super::*;
+    use crate::{ewkb, Decodable as _, Encodable as _};
+
+    #[test]
+    fn test_ewkb_round_trip() {
+        // Une graine déterministe suffit à couvrir toutes les classes de géométrie.
+        let seed = (0..4096u32)
+            .map(|i| (i.wrapping_mul(2654435761) >> 13) as u8)
+            .collect::<Vec<_>>();
+        let mut u = Unstructured::new(&seed);
+
+        for _ in 0..32 {
+            let expected = ewkb::EWKBGeometry::new(Geometry::arbitrary(&mut u).unwrap());
+            let bytes = expected.encode_to_vec().expect("cannot encode geometry");
+            let value =
+                ewkb::EWKBGeometry::decode_from_slice(&bytes).expect("cannot decode geometry");
+            assert_eq!(value, expected);
+        }
+    }
+}
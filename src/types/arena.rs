@@ -0,0 +1,114 @@
+//! Géométries allouées dans une arène (fonctionnalité `arena`), basée sur `bumpalo`.
+//!
+//! Les sommets et les anneaux d'une [ArenaGeometry] sont des tranches empruntées à une
+//! [Bump] : décoder des millions de petites géométries ne sollicite plus l'allocateur,
+//! et la mémoire est libérée d'un seul coup par [Bump::reset].
+pub use bumpalo::Bump;
+
+use super::{
+    Geometry, GeometryKind, LineString, LineStringZ, MultiLineString, MultiLineStringZ, MultiPoint,
+    MultiPointZ, MultiPolygon, MultiPolygonZ, Point, PointZ, Polygon, PolygonZ, Vector,
+    VectorArray, VectorMatrix, VectorTensor,
+};
+use crate::types::GeometryImpl as _;
+
+/// Coordonnées d'une géométrie, empruntées à l'arène.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ArenaCoordinates<'bump> {
+    Vector2D(&'bump Vector<2, f64>),
+    VectorArray2D(&'bump [Vector<2, f64>]),
+    VectorMatrix2D(&'bump [&'bump [Vector<2, f64>]]),
+    VectorTensor2D(&'bump [&'bump [&'bump [Vector<2, f64>]]]),
+
+    Vector3D(&'bump Vector<3, f64>),
+    VectorArray3D(&'bump [Vector<3, f64>]),
+    VectorMatrix3D(&'bump [&'bump [Vector<3, f64>]]),
+    VectorTensor3D(&'bump [&'bump [&'bump [Vector<3, f64>]]]),
+}
+
+/// Géométrie dont les coordonnées sont allouées dans une [Bump].
+///
+/// Obtenue par [crate::ewkb::decode_geometry_in], elle vit aussi longtemps que l'arène.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ArenaGeometry<'bump> {
+    kind: GeometryKind,
+    srid: Option<u32>,
+    coordinates: ArenaCoordinates<'bump>,
+}
+
+impl<'bump> ArenaGeometry<'bump> {
+    /// Le type doit correspondre à la forme des coordonnées.
+    pub(crate) fn new(
+        kind: GeometryKind,
+        srid: Option<u32>,
+        coordinates: ArenaCoordinates<'bump>,
+    ) -> Self {
+        Self {
+            kind,
+            srid,
+            coordinates,
+        }
+    }
+
+    pub fn kind(&self) -> GeometryKind {
+        self.kind
+    }
+
+    pub fn srid(&self) -> Option<u32> {
+        self.srid
+    }
+
+    pub fn coordinates(&self) -> ArenaCoordinates<'bump> {
+        self.coordinates
+    }
+
+    /// Copie la géométrie hors de l'arène.
+    pub fn to_geometry(&self) -> Geometry {
+        use ArenaCoordinates::*;
+
+        let mut geometry: Geometry = match self.coordinates {
+            Vector2D(v) => Point::new(*v).into(),
+            VectorArray2D(a) if self.kind == GeometryKind::MultiPoint => {
+                MultiPoint::new(array(a)).into()
+            }
+            VectorArray2D(a) => LineString::new(array(a)).into(),
+            VectorMatrix2D(m) if self.kind == GeometryKind::MultiLineString => {
+                MultiLineString::new(matrix(m)).into()
+            }
+            VectorMatrix2D(m) => Polygon::new(matrix(m)).into(),
+            VectorTensor2D(t) => MultiPolygon::new(tensor(t)).into(),
+
+            Vector3D(v) => PointZ::new(*v).into(),
+            VectorArray3D(a) if self.kind == GeometryKind::MultiPointZ => {
+                MultiPointZ::new(array(a)).into()
+            }
+            VectorArray3D(a) => LineStringZ::new(array(a)).into(),
+            VectorMatrix3D(m) if self.kind == GeometryKind::MultiLineStringZ => {
+                MultiLineStringZ::new(matrix(m)).into()
+            }
+            VectorMatrix3D(m) => PolygonZ::new(matrix(m)).into(),
+            VectorTensor3D(t) => MultiPolygonZ::new(tensor(t)).into(),
+        };
+
+        geometry.set_srid(self.srid);
+        geometry
+    }
+}
+
+impl From<ArenaGeometry<'_>> for Geometry {
+    fn from(value: ArenaGeometry<'_>) -> Self {
+        value.to_geometry()
+    }
+}
+
+fn array<const N: usize>(array: &[Vector<N, f64>]) -> VectorArray<N, f64> {
+    array.iter().cloned().collect()
+}
+
+fn matrix<const N: usize>(matrix: &[&[Vector<N, f64>]]) -> VectorMatrix<N, f64> {
+    matrix.iter().copied().map(array).collect()
+}
+
+fn tensor<const N: usize>(tensor: &[&[&[Vector<N, f64>]]]) -> VectorTensor<N, f64> {
+    tensor.iter().copied().map(matrix).collect()
+}
@@ -0,0 +1,175 @@
+//! Cercle englobant minimal (algorithme de Welzl), équivalent local de
+//! `ST_MinimumBoundingCircle`.
+use super::{
+    planar::{parts, Coord, Part},
+    Geometry, GeometryImpl as _, Point,
+};
+use crate::prelude::*;
+
+/// Tolérance relative du test d'appartenance au cercle.
+const EPSILON: f64 = 1e-12;
+
+#[derive(Debug, Clone, Copy)]
+struct Circle {
+    center: Coord,
+    radius: f64,
+}
+
+impl Circle {
+    fn contains(&self, p: Coord) -> bool {
+        distance(self.center, p) <= self.radius * (1.0 + EPSILON) + EPSILON
+    }
+}
+
+impl Geometry {
+    /// Plus petit cercle contenant tous les sommets de la géométrie, sous la forme
+    /// `(centre, rayon)`, calculé dans le plan XY.
+    ///
+    /// Renvoie `None` si la géométrie est vide.
+    pub fn bounding_circle(&self) -> Option<(Point, f64)> {
+        let mut points = parts(self)
+            .into_iter()
+            .flat_map(|part| match part {
+                Part::Point(p) => vec![p],
+                Part::Line(line) => line,
+                Part::Polygon(rings) => rings.into_iter().flatten().collect(),
+            })
+            .collect::<Vec<_>>();
+
+        shuffle(&mut points);
+        let circle = welzl(&points)?;
+
+        let mut center = Point::new([circle.center.0, circle.center.1]);
+        center.srid = self.srid();
+        Some((center, circle.radius))
+    }
+}
+
+/// Version itérative de l'algorithme de Welzl.
+fn welzl(points: &[Coord]) -> Option<Circle> {
+    let mut circle = Circle {
+        center: *points.first()?,
+        radius: 0.0,
+    };
+
+    for i in 1..points.len() {
+        if circle.contains(points[i]) {
+            continue;
+        }
+
+        circle = Circle {
+            center: points[i],
+            radius: 0.0,
+        };
+
+        for j in 0..i {
+            if circle.contains(points[j]) {
+                continue;
+            }
+
+            circle = diameter_circle(points[i], points[j]);
+
+            for k in 0..j {
+                if !circle.contains(points[k]) {
+                    circle = circumcircle(points[i], points[j], points[k]);
+                }
+            }
+        }
+    }
+
+    Some(circle)
+}
+
+fn distance(a: Coord, b: Coord) -> f64 {
+    (a.0 - b.0).hypot(a.1 - b.1)
+}
+
+fn diameter_circle(a: Coord, b: Coord) -> Circle {
+    Circle {
+        center: ((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0),
+        radius: distance(a, b) / 2.0,
+    }
+}
+
+fn circumcircle(a: Coord, b: Coord, c: Coord) -> Circle {
+    let (bx, by) = (b.0 - a.0, b.1 - a.1);
+    let (cx, cy) = (c.0 - a.0, c.1 - a.1);
+    let d = 2.0 * (bx * cy - by * cx);
+
+    if d == 0.0 {
+        // Points alignés : le cercle de diamètre la paire la plus éloignée.
+        return [(a, b), (a, c), (b, c)]
+            .into_iter()
+            .map(|(p, q)| diameter_circle(p, q))
+            .max_by(|c1, c2| c1.radius.total_cmp(&c2.radius))
+            .expect("three pairs");
+    }
+
+    let (b2, c2) = (bx * bx + by * by, cx * cx + cy * cy);
+    let center = (a.0 + (cy * b2 - by * c2) / d, a.1 + (bx * c2 - cx * b2) / d);
+
+    Circle {
+        center,
+        radius: distance(center, a),
+    }
+}
+
+/// Mélange déterministe (xorshift) garantissant une complexité linéaire en moyenne.
+fn shuffle(points: &mut [Coord]) {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+
+    for i in (1..points.len()).rev() {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        points.swap(i, (state % (i as u64 + 1)) as usize);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{LineString, MultiPoint, Polygon};
+
+    use super::*;
+
+    #[test]
+    fn test_bounding_circle() {
+        let square = Geometry::from(Polygon::new([
+            [0.0, 0.0],
+            [2.0, 0.0],
+            [2.0, 2.0],
+            [0.0, 2.0],
+        ]));
+        let (center, radius) = square.bounding_circle().expect("not empty");
+
+        assert_eq!(center, Point::new([1.0, 1.0]));
+        assert!((radius - 2.0_f64.sqrt()).abs() < 1e-12);
+
+        let line = Geometry::from(LineString::new([[0.0, 0.0], [1.0, 0.0], [4.0, 0.0]]));
+        assert_eq!(line.bounding_circle(), Some((Point::new([2.0, 0.0]), 2.0)));
+    }
+
+    #[test]
+    fn test_bounding_circle_contains_all_points() {
+        let points = (0..50)
+            .map(|i| {
+                let i = i as f64;
+                [(i * 7.3) % 11.0, (i * 3.1) % 5.0]
+            })
+            .collect::<Vec<_>>();
+        let geometry = Geometry::from(MultiPoint::new(
+            points
+                .iter()
+                .copied()
+                .collect::<crate::types::VectorArray2D>(),
+        ));
+
+        let (center, radius) = geometry.bounding_circle().expect("not empty");
+        let circle = Circle {
+            center: (center.coordinates.x(), center.coordinates.y()),
+            radius,
+        };
+
+        assert!(points.iter().all(|[x, y]| circle.contains((*x, *y))));
+    }
+}
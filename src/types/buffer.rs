@@ -0,0 +1,322 @@
+//! Zone tampon approchée, équivalent local de `ST_Buffer` avec des jointures arrondies.
+use core::f64::consts::{FRAC_PI_2, PI};
+
+use super::{
+    distance::segment_distance,
+    multi_polygon::MultiPolygon,
+    planar::{parts, segments, signed_area, Coord, Part},
+    polygon::Polygon,
+    Geometry, GeometryImpl as _, GeometryKind,
+};
+use crate::error::Error;
+use crate::prelude::*;
+
+impl Geometry {
+    /// Construit une zone tampon de `distance` autour de la géométrie, les arcs étant
+    /// approchés par `segments_per_quadrant` segments par quart de cercle.
+    ///
+    /// Une distance négative n'est acceptée que pour les surfaces, qu'elle érode.
+    /// Les zones des composants d'une géométrie multiple ne sont pas fusionnées, et
+    /// l'anneau produit peut s'auto-intersecter dans les angles rentrants aigus.
+    pub fn buffer(&self, distance: f64, segments_per_quadrant: usize) -> Result<Geometry, Error> {
+        let segments_per_quadrant = segments_per_quadrant.max(1);
+        let parts = parts(self);
+
+        let polygons = parts
+            .iter()
+            .map(|part| match part {
+                Part::Polygon(rings) => Ok(buffer_polygon(rings, distance, segments_per_quadrant)),
+                _ if distance < 0.0 => Err(Error::unsupported_geometry(
+                    self.kind(),
+                    "only a surface can have a negative buffer",
+                )),
+                // Comme ST_Buffer, une zone de largeur nulle autour d'un point ou d'une ligne est vide.
+                _ if distance == 0.0 => Ok(vec![]),
+                Part::Point(p) => Ok(vec![circle(*p, distance, segments_per_quadrant)]),
+                Part::Line(line) => Ok(vec![buffer_line(line, distance, segments_per_quadrant)]),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut geometry: Geometry = match self.kind() {
+            GeometryKind::Point
+            | GeometryKind::PointZ
+            | GeometryKind::LineString
+            | GeometryKind::LineStringZ
+            | GeometryKind::Polygon
+            | GeometryKind::PolygonZ => Polygon::new(
+                polygons
+                    .into_iter()
+                    .flatten()
+                    .map(to_arrays)
+                    .collect::<super::VectorMatrix2D>(),
+            )
+            .into(),
+            _ => MultiPolygon::new(
+                polygons
+                    .into_iter()
+                    .filter(|rings| !rings.is_empty())
+                    .map(|rings| rings.into_iter().map(to_arrays).collect::<Vec<_>>())
+                    .collect::<super::VectorTensor2D>(),
+            )
+            .into(),
+        };
+
+        geometry.set_srid(self.srid());
+        Ok(geometry)
+    }
+}
+
+fn to_arrays(ring: Vec<Coord>) -> Vec<[f64; 2]> {
+    ring.into_iter().map(|(x, y)| [x, y]).collect()
+}
+
+/// Cercle de rayon `radius` centré sur `center`, parcouru dans le sens direct.
+fn circle(center: Coord, radius: f64, segments_per_quadrant: usize) -> Vec<Coord> {
+    let nb_segments = 4 * segments_per_quadrant;
+
+    (0..=nb_segments)
+        .map(|i| {
+            let angle = 2.0 * PI * (i % nb_segments) as f64 / nb_segments as f64;
+            (
+                center.0 + radius * angle.cos(),
+                center.1 + radius * angle.sin(),
+            )
+        })
+        .collect()
+}
+
+/// Retire les sommets consécutifs dupliqués.
+fn dedup(ring: &[Coord]) -> Vec<Coord> {
+    let mut ring = ring.to_vec();
+    ring.dedup();
+    ring
+}
+
+/// Ligne : on décale le chemin aller-retour, les demi-tours formant les extrémités arrondies.
+fn buffer_line(line: &[Coord], distance: f64, segments_per_quadrant: usize) -> Vec<Coord> {
+    let line = dedup(line);
+
+    match line.as_slice() {
+        [] => vec![],
+        [p] => circle(*p, distance, segments_per_quadrant),
+        _ => {
+            let path = line
+                .iter()
+                .chain(line.iter().rev().skip(1))
+                .copied()
+                .collect::<Vec<_>>();
+
+            offset_ring(&path, distance, segments_per_quadrant)
+        }
+    }
+}
+
+/// Une érosion plus large que le rayon inscrit retourne l'anneau décalé : il est alors
+/// écarté, et le polygone entier disparaît si c'est son extérieur.
+///
+/// Le sens de parcours ne suffit pas à détecter ce cas, un anneau convexe étant retourné
+/// par une symétrie centrale : chaque sommet décalé doit aussi rester à `distance` de
+/// l'anneau d'origine. Un anneau dont seul un étranglement s'effondre est donc écarté.
+fn buffer_polygon(
+    rings: &[Vec<Coord>],
+    distance: f64,
+    segments_per_quadrant: usize,
+) -> Vec<Vec<Coord>> {
+    let mut buffered = Vec::with_capacity(rings.len());
+
+    for (i, ring) in rings.iter().enumerate() {
+        let mut ring = dedup(ring);
+
+        // L'extérieur tourne dans le sens direct, les trous dans le sens indirect,
+        // de sorte que la normale à droite pointe toujours hors de la surface.
+        let is_exterior = i == 0;
+        if (signed_area(&ring) > 0.0) != is_exterior {
+            ring.reverse();
+        }
+
+        let offset = offset_ring(&ring, distance, segments_per_quadrant);
+        let area = signed_area(&offset);
+        let is_valid = offset.len() >= 4
+            && area != 0.0
+            && (area > 0.0) == is_exterior
+            && keeps_distance(&ring, &offset, distance);
+
+        match (is_valid, is_exterior) {
+            (true, _) => buffered.push(offset),
+            (false, true) => return vec![],
+            (false, false) => {}
+        }
+    }
+
+    buffered
+}
+
+/// Vrai si tous les sommets de `offset` sont à `distance` de `ring`, à l'arrondi près.
+fn keeps_distance(ring: &[Coord], offset: &[Coord], distance: f64) -> bool {
+    let min_distance = distance.abs() * (1.0 - 1e-9);
+
+    offset.iter().all(|p| {
+        segments(ring)
+            .map(|(a, b)| segment_distance(a, b, *p))
+            .fold(f64::INFINITY, f64::min)
+            >= min_distance
+    })
+}
+
+/// En deçà, l'angle rentrant est trop aigu pour joindre les côtés décalés en un point.
+const MITER_LIMIT: f64 = 0.1;
+
+/// Décale un anneau fermé de `distance` vers sa droite, avec des jointures arrondies
+/// sur les angles saillants.
+fn offset_ring(ring: &[Coord], distance: f64, segments_per_quadrant: usize) -> Vec<Coord> {
+    // Anneau ouvert : le dernier point répète le premier.
+    let vertices = &ring[..ring.len().saturating_sub(1)];
+    let n = vertices.len();
+
+    if n < 2 {
+        return vec![];
+    }
+
+    let step = FRAC_PI_2 / segments_per_quadrant as f64;
+    let mut offset = Vec::<Coord>::new();
+
+    for i in 0..n {
+        let prev = vertices[(i + n - 1) % n];
+        let p = vertices[i];
+        let next = vertices[(i + 1) % n];
+
+        let (d1, d2) = ((p.0 - prev.0, p.1 - prev.1), (next.0 - p.0, next.1 - p.1));
+        let (a1, a2) = (right_normal_angle(d1), right_normal_angle(d2));
+
+        // Angle signé du virage en p, positif à gauche. Un demi-tour vaut toujours +π.
+        let turn = match (d1.0 * d2.1 - d1.1 * d2.0).atan2(d1.0 * d2.0 + d1.1 * d2.1) {
+            turn if turn == -PI => PI,
+            turn => turn,
+        };
+
+        if turn * distance > 0.0 || (turn == PI && distance > 0.0) {
+            let nb_steps = (turn.abs() / step).ceil().max(1.0) as usize;
+
+            offset.extend((0..=nb_steps).map(|k| {
+                let angle = a1 + turn * k as f64 / nb_steps as f64;
+                (p.0 + distance * angle.cos(), p.1 + distance * angle.sin())
+            }));
+        } else if 1.0 + (a2 - a1).cos() > MITER_LIMIT {
+            // Angle rentrant : intersection des deux côtés décalés.
+            let scale = distance / (1.0 + (a2 - a1).cos());
+            offset.push((
+                p.0 + scale * (a1.cos() + a2.cos()),
+                p.1 + scale * (a1.sin() + a2.sin()),
+            ));
+        } else {
+            offset.push((p.0 + distance * a1.cos(), p.1 + distance * a1.sin()));
+            offset.push((p.0 + distance * a2.cos(), p.1 + distance * a2.sin()));
+        }
+    }
+
+    offset.dedup();
+    if let Some(first) = offset.first().copied() {
+        offset.push(first);
+    }
+    offset
+}
+
+/// Angle de la normale à droite d'une direction.
+fn right_normal_angle((dx, dy): Coord) -> f64 {
+    (-dx).atan2(dy)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{GeometryImpl as _, LineString, Point, Polygon};
+
+    use super::*;
+
+    fn area(geometry: &Geometry) -> f64 {
+        parts(geometry)
+            .iter()
+            .map(|part| match part {
+                Part::Polygon(rings) => {
+                    signed_area(&rings[0]).abs()
+                        - rings[1..].iter().map(|r| signed_area(r).abs()).sum::<f64>()
+                }
+                _ => 0.0,
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_buffer_point() {
+        let buffer = Geometry::from(Point::new([1.0, 2.0]))
+            .buffer(10.0, 32)
+            .expect("cannot buffer point");
+
+        assert_eq!(buffer.kind(), GeometryKind::Polygon);
+        assert!((area(&buffer) - PI * 100.0).abs() < 1.0);
+        assert!(buffer.contains(&Point::new([1.0, 11.9]).into()));
+        assert!(!buffer.contains(&Point::new([1.0, 12.1]).into()));
+    }
+
+    #[test]
+    fn test_buffer_line() {
+        let buffer = Geometry::from(LineString::new([[0.0, 0.0], [10.0, 0.0]]))
+            .buffer(1.0, 16)
+            .expect("cannot buffer line");
+
+        // Rectangle 10x2 et deux demi-disques de rayon 1.
+        assert!((area(&buffer) - (20.0 + PI)).abs() < 0.05);
+        assert!(buffer.contains(&Point::new([5.0, 0.9]).into()));
+        assert!(!buffer.contains(&Point::new([5.0, 1.1]).into()));
+        assert!(buffer.contains(&Point::new([10.9, 0.0]).into()));
+    }
+
+    #[test]
+    fn test_buffer_polygon() {
+        let square = Geometry::from(Polygon::new([
+            [0.0, 0.0],
+            [10.0, 0.0],
+            [10.0, 10.0],
+            [0.0, 10.0],
+        ]));
+
+        let grown = square.buffer(1.0, 16).expect("cannot buffer polygon");
+        assert!((area(&grown) - (100.0 + 40.0 + PI)).abs() < 0.05);
+
+        let shrunk = square.buffer(-1.0, 16).expect("cannot buffer polygon");
+        assert!((area(&shrunk) - 64.0).abs() < 1e-9);
+
+        assert!(Geometry::from(Point::new([0.0, 0.0]))
+            .buffer(-1.0, 8)
+            .is_err());
+        assert_eq!(
+            Geometry::from(Point::new([0.0, 0.0]))
+                .buffer(0.0, 8)
+                .unwrap(),
+            Geometry::from(Polygon::default())
+        );
+    }
+
+    #[test]
+    fn test_buffer_polygon_over_erosion() {
+        let square = Geometry::from(Polygon::new([
+            [0.0, 0.0],
+            [2.0, 0.0],
+            [2.0, 2.0],
+            [0.0, 2.0],
+        ]));
+
+        let eroded = square.buffer(-5.0, 8).expect("cannot buffer polygon");
+        assert_eq!(eroded, Geometry::from(Polygon::default()));
+
+        // Le trou est comblé par la zone tampon : seul l'extérieur subsiste.
+        let with_hole = Geometry::from(Polygon::new((
+            [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0]],
+            [[4.0, 4.0], [6.0, 4.0], [6.0, 6.0], [4.0, 6.0]],
+        )));
+        let filled = with_hole.buffer(2.0, 8).expect("cannot buffer polygon");
+        let Geometry::Polygon(filled) = filled else {
+            panic!("expecting a polygon");
+        };
+        assert_eq!(filled.coordinates.len(), 1);
+    }
+}
@@ -0,0 +1,214 @@
+//! Coordonnées rangées par axe (struct-of-arrays).
+//!
+//! Les géométries stockent leurs points sous forme de [VectorArray], ce qui
+//! entrelace les axes (`x0 y0 x1 y1 ...`) ; c'est ce stockage qu'utilisent les
+//! codecs. Le [CoordBuffer] est un tampon de travail à côté de ce stockage : il
+//! range toutes les abscisses, puis toutes les ordonnées, etc., ce qui permet de
+//! parcourir un axe de manière contiguë (calcul d'emprise, vectorisation) sur de
+//! très grandes lignes, avant de revenir à un [VectorArray].
+use super::{Scalar, Vector, VectorArray, MBR};
+use crate::{error::Error, prelude::*};
+
+/// Coordonnées de points de dimension `dimension`, rangées axe par axe.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoordBuffer<U> {
+    /// Une suite de valeurs par axe, toutes de même longueur.
+    axes: Vec<Vec<U>>,
+}
+
+impl<U> CoordBuffer<U> {
+    /// Tampon vide de la dimension donnée.
+    pub fn new(dimension: usize) -> Self {
+        Self::with_capacity(dimension, 0)
+    }
+
+    /// Tampon vide pouvant recevoir `capacity` points sans réallocation.
+    pub fn with_capacity(dimension: usize, capacity: usize) -> Self {
+        Self {
+            axes: (0..dimension)
+                .map(|_| Vec::with_capacity(capacity))
+                .collect(),
+        }
+    }
+
+    pub fn dimension(&self) -> usize {
+        self.axes.len()
+    }
+
+    /// Nombre de points.
+    pub fn len(&self) -> usize {
+        self.axes.first().map_or(0, Vec::len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Valeurs contiguës d'un axe (0 pour x, 1 pour y, ...), `None` si l'axe n'existe pas.
+    pub fn axis(&self, axis: usize) -> Option<&[U]> {
+        self.axes.get(axis).map(Vec::as_slice)
+    }
+
+    /// Valeurs modifiables d'un axe, par exemple pour translater tous les points.
+    pub fn axis_mut(&mut self, axis: usize) -> Option<&mut [U]> {
+        self.axes.get_mut(axis).map(Vec::as_mut_slice)
+    }
+
+    /// Ajoute un point, qui doit être de la dimension du tampon.
+    pub fn push<const N: usize>(&mut self, point: impl Into<Vector<N, U>>) -> Result<(), Error> {
+        if N != self.dimension() {
+            return Err(Error::invalid_dimension(self.dimension(), N));
+        }
+
+        self.axes
+            .iter_mut()
+            .zip(point.into())
+            .for_each(|(axis, value)| axis.push(value));
+
+        Ok(())
+    }
+
+    /// Retire tous les points, en conservant la dimension et la capacité.
+    pub fn clear(&mut self) {
+        self.axes.iter_mut().for_each(Vec::clear)
+    }
+}
+
+impl<U> CoordBuffer<U>
+where
+    U: Copy,
+{
+    /// Coordonnées du point `index`.
+    pub fn get<const N: usize>(&self, index: usize) -> Option<Vector<N, U>> {
+        (N == self.dimension() && index < self.len())
+            .then(|| Vector::new(core::array::from_fn(|axis| self.axes[axis][index])))
+    }
+
+    /// Parcourt les points sous forme de vecteurs de dimension `N`.
+    ///
+    /// N'itère sur rien si `N` n'est pas la dimension du tampon.
+    pub fn iter<const N: usize>(&self) -> impl Iterator<Item = Vector<N, U>> + '_ {
+        let len = if N == self.dimension() { self.len() } else { 0 };
+        (0..len).filter_map(move |index| self.get(index))
+    }
+}
+
+impl<U> CoordBuffer<U>
+where
+    U: Scalar,
+{
+    /// Emprise des points, calculée axe par axe sur des données contiguës.
+    ///
+    /// Les coordonnées NaN sont ignorées, `None` si un axe n'a aucune valeur exploitable.
+    pub fn mbr<const N: usize>(&self) -> Option<MBR<N, U>> {
+        if N != self.dimension() {
+            return None;
+        }
+
+        let mut min = [U::zero(); N];
+        let mut max = [U::zero(); N];
+
+        for axis in 0..N {
+            let mut values = self
+                .axis(axis)?
+                .iter()
+                .copied()
+                .filter(|v| v.partial_cmp(v).is_some());
+            let first = values.next()?;
+
+            (min[axis], max[axis]) = values.fold((first, first), |(lo, hi), v| {
+                (if v < lo { v } else { lo }, if v > hi { v } else { hi })
+            });
+        }
+
+        Some(MBR::new(min, max))
+    }
+}
+
+impl<const N: usize, U> From<&VectorArray<N, U>> for CoordBuffer<U>
+where
+    U: Copy,
+{
+    fn from(value: &VectorArray<N, U>) -> Self {
+        Self {
+            axes: (0..N)
+                .map(|axis| value.iter().map(|v| v[axis]).collect())
+                .collect(),
+        }
+    }
+}
+
+impl<const N: usize, U> From<VectorArray<N, U>> for CoordBuffer<U>
+where
+    U: Copy,
+{
+    fn from(value: VectorArray<N, U>) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl<const N: usize, U> TryFrom<&CoordBuffer<U>> for VectorArray<N, U>
+where
+    U: Copy,
+{
+    type Error = Error;
+
+    fn try_from(value: &CoordBuffer<U>) -> Result<Self, Self::Error> {
+        if value.dimension() != N {
+            return Err(Error::invalid_dimension(N, value.dimension()));
+        }
+
+        Ok(value.iter::<N>().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coord_buffer_layout() {
+        let array = VectorArray::from([[0.0, 10.0], [1.0, 11.0], [2.0, 12.0]]);
+        let buffer = CoordBuffer::from(&array);
+
+        assert_eq!(buffer.len(), 3);
+        assert_eq!(buffer.axis(0), Some([0.0, 1.0, 2.0].as_slice()));
+        assert_eq!(buffer.axis(1), Some([10.0, 11.0, 12.0].as_slice()));
+        assert_eq!(buffer.get::<2>(1), Some(Vector::new([1.0, 11.0])));
+        assert_eq!(VectorArray::<2, f64>::try_from(&buffer).ok(), Some(array));
+        assert!(VectorArray::<3, f64>::try_from(&buffer).is_err());
+    }
+
+    #[test]
+    fn test_coord_buffer_mbr() {
+        let array = VectorArray::from([[0.0, f64::NAN, 1.0], [-3.0, 2.0, 5.0]]);
+        let buffer = CoordBuffer::from(&array);
+
+        assert_eq!(buffer.mbr::<3>(), array.mbr());
+        assert_eq!(buffer.mbr::<2>(), None);
+        assert_eq!(CoordBuffer::<f64>::new(2).mbr::<2>(), None);
+    }
+
+    #[test]
+    fn test_coord_buffer_push() {
+        let mut buffer = CoordBuffer::with_capacity(2, 3);
+        buffer.push([0.0, 10.0]).unwrap();
+        buffer.push([1.0, 11.0]).unwrap();
+        assert!(buffer.push([2.0, 12.0, 22.0]).is_err());
+        assert_eq!(buffer.len(), 2);
+
+        buffer
+            .axis_mut(0)
+            .unwrap()
+            .iter_mut()
+            .for_each(|x| *x += 5.0);
+        assert_eq!(
+            VectorArray::<2, f64>::try_from(&buffer).ok(),
+            Some(VectorArray::from([[5.0, 10.0], [6.0, 11.0]]))
+        );
+
+        buffer.clear();
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.dimension(), 2);
+    }
+}
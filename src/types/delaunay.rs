@@ -0,0 +1,158 @@
+//! Triangulation de Delaunay (algorithme de Bowyer–Watson), équivalent local de
+//! `ST_DelaunayTriangles`.
+use super::{
+    multi_point::MultiPoint, multi_polygon::MultiPolygon, planar::Coord, GeometryImpl as _,
+    VectorArray, VectorMatrix, VectorTensor,
+};
+use crate::prelude::*;
+
+impl<const N: usize> MultiPoint<N, f64> {
+    /// Triangule les points dans le plan XY.
+    ///
+    /// Chaque triangle est un polygone orienté dans le sens direct, dont les sommets
+    /// conservent toutes leurs coordonnées (l'altitude pour un TIN). Les points
+    /// dupliqués dans le plan ne sont utilisés qu'une fois.
+    pub fn delaunay(&self) -> MultiPolygon<N, f64> {
+        let mut vertices = self.coordinates.iter().collect::<Vec<_>>();
+        vertices.sort_by(|a, b| a.x().total_cmp(&b.x()).then(a.y().total_cmp(&b.y())));
+        vertices.dedup_by(|a, b| a.x() == b.x() && a.y() == b.y());
+
+        let points = vertices.iter().map(|v| (v.x(), v.y())).collect::<Vec<_>>();
+
+        let triangles = triangulate(&points)
+            .into_iter()
+            .map(|[a, b, c]| {
+                [a, b, c, a]
+                    .into_iter()
+                    .map(|i| *vertices[i])
+                    .collect::<VectorArray<N, f64>>()
+            })
+            .map(|ring| VectorMatrix::new(vec![ring]))
+            .collect::<VectorTensor<N, f64>>();
+
+        let mut multi_polygon = MultiPolygon::new(triangles);
+        multi_polygon.srid = self.srid;
+        multi_polygon
+    }
+}
+
+/// Renvoie les triangles sous forme d'indices dans `points`, orientés dans le sens direct.
+fn triangulate(points: &[Coord]) -> Vec<[usize; 3]> {
+    if points.len() < 3 {
+        return vec![];
+    }
+
+    // Super-triangle englobant largement tous les points.
+    let (min_x, max_x) = bounds(points.iter().map(|p| p.0));
+    let (min_y, max_y) = bounds(points.iter().map(|p| p.1));
+    let size = (max_x - min_x).max(max_y - min_y).max(1.0);
+    let (mid_x, mid_y) = ((min_x + max_x) / 2.0, (min_y + max_y) / 2.0);
+
+    let n = points.len();
+    let mut all = points.to_vec();
+    all.push((mid_x - 20.0 * size, mid_y - size));
+    all.push((mid_x, mid_y + 20.0 * size));
+    all.push((mid_x + 20.0 * size, mid_y - size));
+
+    let mut triangles = vec![ccw(&all, [n, n + 1, n + 2])];
+
+    for i in 0..n {
+        let p = all[i];
+
+        let (bad, good): (Vec<_>, Vec<_>) = triangles
+            .into_iter()
+            .partition(|t| in_circumcircle(&all, *t, p));
+
+        // Les arêtes du trou sont celles qui n'appartiennent qu'à un seul mauvais triangle.
+        let edges = bad
+            .iter()
+            .flat_map(|[a, b, c]| [(*a, *b), (*b, *c), (*c, *a)])
+            .collect::<Vec<_>>();
+
+        triangles = good;
+        triangles.extend(
+            edges
+                .iter()
+                .filter(|(a, b)| !edges.contains(&(*b, *a)))
+                .map(|(a, b)| ccw(&all, [*a, *b, i])),
+        );
+    }
+
+    triangles
+        .into_iter()
+        .filter(|t| t.iter().all(|v| *v < n))
+        .collect()
+}
+
+fn bounds(values: impl Iterator<Item = f64>) -> (f64, f64) {
+    values.fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| {
+        (min.min(v), max.max(v))
+    })
+}
+
+fn orientation(points: &[Coord], [a, b, c]: [usize; 3]) -> f64 {
+    let (a, b, c) = (points[a], points[b], points[c]);
+    (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0)
+}
+
+fn ccw(points: &[Coord], [a, b, c]: [usize; 3]) -> [usize; 3] {
+    if orientation(points, [a, b, c]) < 0.0 {
+        [a, c, b]
+    } else {
+        [a, b, c]
+    }
+}
+
+/// Vrai si `p` est strictement à l'intérieur du cercle circonscrit au triangle direct `t`.
+fn in_circumcircle(points: &[Coord], [a, b, c]: [usize; 3], p: Coord) -> bool {
+    let [a, b, c] = [points[a], points[b], points[c]].map(|v| (v.0 - p.0, v.1 - p.1));
+    let lift = |v: Coord| v.0 * v.0 + v.1 * v.1;
+
+    let det = a.0 * (b.1 * lift(c) - lift(b) * c.1) - a.1 * (b.0 * lift(c) - lift(b) * c.0)
+        + lift(a) * (b.0 * c.1 - b.1 * c.0);
+
+    det > 0.0
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{planar::signed_area, GeometryImpl as _, MultiPoint, MultiPointZ};
+
+    #[test]
+    fn test_delaunay_square() {
+        let points = MultiPoint::new([[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0], [0.5, 0.5]]);
+        let triangles = points.delaunay();
+
+        assert_eq!(triangles.coordinates.len(), 4);
+
+        let area = triangles
+            .coordinates
+            .iter()
+            .map(|t| signed_area(&crate::types::planar::coords(&t[0])))
+            .sum::<f64>();
+        assert_eq!(area, 1.0);
+    }
+
+    #[test]
+    fn test_delaunay_keeps_altitude() {
+        let points = MultiPointZ::new([
+            [0.0, 0.0, 1.0],
+            [1.0, 0.0, 2.0],
+            [0.0, 1.0, 3.0],
+            [0.0, 0.0, 4.0],
+        ]);
+        let triangles = points.delaunay();
+
+        assert_eq!(triangles.coordinates.len(), 1);
+        assert_eq!(triangles.coordinates[0][0].len(), 4);
+        assert!(triangles.coordinates[0][0].iter().any(|v| v.z() == 3.0));
+    }
+
+    #[test]
+    fn test_delaunay_too_few_points() {
+        assert!(MultiPoint::new([[0.0, 0.0], [1.0, 0.0]])
+            .delaunay()
+            .coordinates
+            .is_empty());
+    }
+}
@@ -0,0 +1,190 @@
+//! Densification des lignes, équivalent local de `ST_Segmentize`.
+use super::{line_string::LineString, polygon::Polygon, Vector, VectorArray};
+use crate::error::Error;
+use crate::prelude::*;
+
+/// Nombre maximal de sommets d'une géométrie densifiée.
+///
+/// Comme `ST_Segmentize`, la densification échoue plutôt que d'allouer sans
+/// limite lorsque la longueur maximale est minuscule devant celle des segments.
+pub const MAX_DENSIFIED_VERTICES: usize = 1 << 24;
+
+impl<const N: usize> LineString<N, f64> {
+    /// Insère des sommets intermédiaires de sorte qu'aucun segment ne dépasse
+    /// `max_segment_length`, mesurée dans le plan XY.
+    ///
+    /// Échoue si une coordonnée n'est pas finie, ou si le résultat dépasserait
+    /// [MAX_DENSIFIED_VERTICES] sommets.
+    pub fn densify(&self, max_segment_length: f64) -> Result<Self, Error> {
+        check_len(densified_len(&self.coordinates, max_segment_length)?)?;
+
+        Ok(Self {
+            coordinates: densify_array(&self.coordinates, max_segment_length),
+            srid: self.srid,
+        })
+    }
+}
+
+impl<const N: usize> Polygon<N, f64> {
+    /// Densifie chaque anneau du polygone, voir [LineString::densify].
+    ///
+    /// La limite de sommets porte sur l'ensemble des anneaux.
+    pub fn densify(&self, max_segment_length: f64) -> Result<Self, Error> {
+        check_len(
+            self.coordinates
+                .iter()
+                .map(|ring| densified_len(ring, max_segment_length))
+                .sum::<Result<f64, _>>()?,
+        )?;
+
+        Ok(Self {
+            coordinates: self
+                .coordinates
+                .iter()
+                .map(|ring| densify_array(ring, max_segment_length))
+                .collect(),
+            srid: self.srid,
+        })
+    }
+}
+
+/// Vrai si la longueur maximale demandée laisse la suite de points inchangée.
+fn is_noop(max_segment_length: f64) -> bool {
+    max_segment_length.is_nan() || max_segment_length <= 0.0
+}
+
+/// Nombre de morceaux du segment `[a, b]`.
+fn nb_pieces<const N: usize>(
+    a: &Vector<N, f64>,
+    b: &Vector<N, f64>,
+    max_segment_length: f64,
+) -> f64 {
+    let length = (b.x() - a.x()).hypot(b.y() - a.y());
+    (length / max_segment_length).ceil().max(1.0)
+}
+
+/// Nombre de sommets de la suite densifiée, compté en flottant car il peut
+/// dépasser `usize`.
+fn densified_len<const N: usize>(
+    array: &VectorArray<N, f64>,
+    max_segment_length: f64,
+) -> Result<f64, Error> {
+    if array.iter().flat_map(|v| v.iter()).any(|c| !c.is_finite()) {
+        return Err(Error::invalid_coordinates("non-finite coordinate"));
+    }
+
+    if array.is_empty() || is_noop(max_segment_length) {
+        return Ok(array.len() as f64);
+    }
+
+    Ok(array
+        .windows(2)
+        .map(|w| nb_pieces(&w[0], &w[1], max_segment_length))
+        .sum::<f64>()
+        + 1.0)
+}
+
+fn check_len(len: f64) -> Result<(), Error> {
+    if len > MAX_DENSIFIED_VERTICES as f64 {
+        return Err(Error::TooManyVertices {
+            max: MAX_DENSIFIED_VERTICES,
+        });
+    }
+
+    Ok(())
+}
+
+/// Densifie la suite de points, dont la taille a été vérifiée par [check_len].
+fn densify_array<const N: usize>(
+    array: &VectorArray<N, f64>,
+    max_segment_length: f64,
+) -> VectorArray<N, f64> {
+    if array.is_empty() || is_noop(max_segment_length) {
+        return array.clone();
+    }
+
+    let mut densified = Vec::with_capacity(array.len());
+
+    for w in array.windows(2) {
+        let (a, b) = (&w[0], &w[1]);
+        let nb_pieces = nb_pieces(a, b, max_segment_length) as usize;
+
+        densified.extend((0..nb_pieces).map(|i| {
+            Vector::new(core::array::from_fn(|k| {
+                a[k] + (b[k] - a[k]) * i as f64 / nb_pieces as f64
+            }))
+        }));
+    }
+
+    densified.extend(array.last().cloned());
+    VectorArray::new(densified)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MAX_DENSIFIED_VERTICES;
+    use crate::{
+        error::Error,
+        types::{GeometryImpl as _, LineString, LineStringZ, Polygon},
+    };
+
+    #[test]
+    fn test_densify_line_string() {
+        let line = LineString::new([[0.0, 0.0], [10.0, 0.0], [10.0, 1.0]]);
+
+        assert_eq!(
+            line.densify(4.0).unwrap(),
+            LineString::new([
+                [0.0, 0.0],
+                [10.0 / 3.0, 0.0],
+                [20.0 / 3.0, 0.0],
+                [10.0, 0.0],
+                [10.0, 1.0]
+            ])
+        );
+
+        let line = LineStringZ::new([[0.0, 0.0, 0.0], [2.0, 0.0, 10.0]]);
+        assert_eq!(
+            line.densify(1.0).unwrap(),
+            LineStringZ::new([[0.0, 0.0, 0.0], [1.0, 0.0, 5.0], [2.0, 0.0, 10.0]])
+        );
+    }
+
+    #[test]
+    fn test_densify_polygon() {
+        let polygon = Polygon::new([[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]])
+            .densify(1.0)
+            .unwrap();
+
+        assert_eq!(polygon.coordinates[0].len(), 9);
+        assert_eq!(
+            polygon.coordinates[0].first(),
+            polygon.coordinates[0].last()
+        );
+    }
+
+    #[test]
+    fn test_densify_limits() {
+        let line = LineString::new([[0.0, 0.0], [1e9, 0.0]]);
+        assert!(matches!(
+            line.densify(1e-9),
+            Err(Error::TooManyVertices { .. })
+        ));
+
+        let line = LineString::new([[0.0, 0.0], [f64::INFINITY, 0.0]]);
+        assert!(matches!(
+            line.densify(1.0),
+            Err(Error::InvalidCoordinates { .. })
+        ));
+
+        // La limite porte sur l'ensemble des anneaux du polygone.
+        let side = 3000.0;
+        let polygon = Polygon::new((
+            [[0.0, 0.0], [side, 0.0], [side, side], [0.0, side]],
+            [[1.0, 1.0], [2.0, 1.0], [2.0, 2.0], [1.0, 2.0]],
+        ));
+        let step = side * 4.0 / (MAX_DENSIFIED_VERTICES - 1) as f64;
+        assert!(polygon.densify(step).is_err());
+        assert!(polygon.densify(1.0).is_ok());
+    }
+}
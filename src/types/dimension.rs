@@ -0,0 +1,155 @@
+//! Passage des géométries 2D en 3D.
+//!
+//! L'altitude est fournie par une fonction appelée pour chaque sommet, par exemple
+//! l'échantillonnage d'un modèle numérique de terrain. Elle reçoit l'indice du
+//! sommet, compté dans l'ordre des coordonnées sur toute la géométrie, et le sommet 2D.
+use super::{
+    Geometry, GeometryImpl as _, LineString, LineStringZ, MultiLineString, MultiLineStringZ,
+    MultiPoint, MultiPointZ, MultiPolygon, MultiPolygonZ, Point, PointZ, Polygon, PolygonZ,
+    Vector2D, Vector3D, VectorArray2D, VectorArray3D, VectorMatrix2D, VectorMatrix3D,
+    VectorTensor2D, VectorTensor3D,
+};
+
+/// Source d'altitude partagée par les niveaux d'imbrication, qui tient le compte des sommets.
+struct Elevation<F> {
+    z: F,
+    index: usize,
+}
+
+impl<F: Fn(usize, &Vector2D) -> f64> Elevation<F> {
+    fn new(z: F) -> Self {
+        Self { z, index: 0 }
+    }
+
+    fn vector(&mut self, v: &Vector2D) -> Vector3D {
+        let z = (self.z)(self.index, v);
+        self.index += 1;
+        Vector3D::new([v.x(), v.y(), z])
+    }
+
+    fn array(&mut self, array: &VectorArray2D) -> VectorArray3D {
+        array.iter().map(|v| self.vector(v)).collect()
+    }
+
+    fn matrix(&mut self, matrix: &VectorMatrix2D) -> VectorMatrix3D {
+        VectorMatrix3D::new(matrix.iter().map(|a| self.array(a)).collect())
+    }
+
+    fn tensor(&mut self, tensor: &VectorTensor2D) -> VectorTensor3D {
+        VectorTensor3D::new(tensor.iter().map(|m| self.matrix(m)).collect())
+    }
+}
+
+impl Point {
+    /// Point 3D d'altitude `z(0, sommet)`.
+    pub fn to_3d(&self, z: impl Fn(usize, &Vector2D) -> f64) -> PointZ {
+        let mut point = PointZ::new(Elevation::new(z).vector(&self.coordinates));
+        point.srid = self.srid;
+        point
+    }
+}
+
+impl LineString {
+    /// Ligne 3D dont l'altitude de chaque sommet est donnée par `z`.
+    pub fn to_3d(&self, z: impl Fn(usize, &Vector2D) -> f64) -> LineStringZ {
+        let mut line = LineStringZ::new(Elevation::new(z).array(&self.coordinates));
+        line.srid = self.srid;
+        line
+    }
+}
+
+impl MultiPoint {
+    /// Points 3D dont l'altitude de chaque point est donnée par `z`.
+    pub fn to_3d(&self, z: impl Fn(usize, &Vector2D) -> f64) -> MultiPointZ {
+        let mut points = MultiPointZ::new(Elevation::new(z).array(&self.coordinates));
+        points.srid = self.srid;
+        points
+    }
+}
+
+impl Polygon {
+    /// Polygone 3D dont l'altitude de chaque sommet est donnée par `z`.
+    ///
+    /// Les sommets sont comptés anneau après anneau, point de fermeture compris.
+    pub fn to_3d(&self, z: impl Fn(usize, &Vector2D) -> f64) -> PolygonZ {
+        let mut polygon = PolygonZ::new(Elevation::new(z).matrix(&self.coordinates));
+        polygon.srid = self.srid;
+        polygon
+    }
+}
+
+impl MultiLineString {
+    /// Lignes 3D dont l'altitude de chaque sommet est donnée par `z`.
+    pub fn to_3d(&self, z: impl Fn(usize, &Vector2D) -> f64) -> MultiLineStringZ {
+        let mut lines = MultiLineStringZ::new(Elevation::new(z).matrix(&self.coordinates));
+        lines.srid = self.srid;
+        lines
+    }
+}
+
+impl MultiPolygon {
+    /// Polygones 3D dont l'altitude de chaque sommet est donnée par `z`.
+    pub fn to_3d(&self, z: impl Fn(usize, &Vector2D) -> f64) -> MultiPolygonZ {
+        let mut polygons = MultiPolygonZ::new(Elevation::new(z).tensor(&self.coordinates));
+        polygons.srid = self.srid;
+        polygons
+    }
+}
+
+impl Geometry {
+    /// Géométrie 3D dont l'altitude de chaque sommet est donnée par `z` ; une
+    /// géométrie déjà 3D est clonée telle quelle.
+    pub fn to_3d(&self, z: impl Fn(usize, &Vector2D) -> f64) -> Geometry {
+        match self {
+            Geometry::Point(a) => a.to_3d(z).into(),
+            Geometry::LineString(a) => a.to_3d(z).into(),
+            Geometry::Polygon(a) => a.to_3d(z).into(),
+            Geometry::MultiPoint(a) => a.to_3d(z).into(),
+            Geometry::MultiLineString(a) => a.to_3d(z).into(),
+            Geometry::MultiPolygon(a) => a.to_3d(z).into(),
+            geometry => geometry.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_3d() {
+        let mut line = LineString::new([[0.0, 0.0], [1.0, 2.0], [2.0, 4.0]]);
+        line.srid = Some(2154);
+
+        let lifted = line.to_3d(|i, v| i as f64 * 10.0 + v.y());
+        assert_eq!(lifted.srid, Some(2154));
+        assert_eq!(
+            lifted,
+            LineStringZ {
+                coordinates: VectorArray3D::from([
+                    [0.0, 0.0, 0.0],
+                    [1.0, 2.0, 12.0],
+                    [2.0, 4.0, 24.0]
+                ]),
+                srid: Some(2154)
+            }
+        );
+
+        let polygon = Polygon::new((
+            [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0]],
+            [[4.0, 4.0], [6.0, 4.0], [6.0, 6.0]],
+        ));
+        let Geometry::PolygonZ(lifted) = Geometry::from(polygon).to_3d(|i, _| i as f64) else {
+            panic!("expecting a 3D polygon");
+        };
+        assert_eq!(lifted.coordinates[1][0].z(), 4.0);
+        assert_eq!(lifted.coordinates[1][3].z(), 7.0);
+
+        let point = Geometry::from(PointZ::new([1.0, 2.0, 3.0]));
+        assert_eq!(point.to_3d(|_, _| 0.0), point);
+        assert_eq!(
+            Point::new([1.0, 2.0]).to_3d(|_, _| 100.0),
+            PointZ::new([1.0, 2.0, 100.0])
+        );
+    }
+}
@@ -0,0 +1,105 @@
+//! Distance planaire entre un point et une géométrie.
+//!
+//! Les calculs sont faits dans le plan XY, la coordonnée Z est ignorée.
+// Sans `std`, les fonctions mathématiques de `f64` viennent de `libm` ; l'import
+// est inutile lorsqu'une autre dépendance lie `std`.
+use super::{
+    planar::{locate_in_polygon, parts, segments, Coord, Location, Part},
+    Geometry, Point,
+};
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use num_traits::Float as _;
+
+impl Geometry {
+    /// Distance planaire minimale entre la géométrie et un point, dans l'unité
+    /// du système de référence (équivalent local de `ST_Distance`).
+    ///
+    /// Un point situé à l'intérieur d'un polygone est à une distance nulle.
+    /// Une géométrie vide est à une distance infinie.
+    pub fn distance(&self, point: &Point) -> f64 {
+        let p = (point.coordinates.x(), point.coordinates.y());
+
+        parts(self)
+            .iter()
+            .map(|part| part_distance(part, p))
+            .fold(f64::INFINITY, f64::min)
+    }
+}
+
+fn part_distance(part: &Part, p: Coord) -> f64 {
+    match part {
+        Part::Point(a) => coord_distance(*a, p),
+        Part::Line(line) => line_distance(line, p),
+        Part::Polygon(rings) => match locate_in_polygon(rings, p) {
+            Location::Exterior => rings
+                .iter()
+                .map(|ring| line_distance(ring, p))
+                .fold(f64::INFINITY, f64::min),
+            _ => 0.0,
+        },
+    }
+}
+
+fn coord_distance(a: Coord, p: Coord) -> f64 {
+    (a.0 - p.0).hypot(a.1 - p.1)
+}
+
+/// Distance entre le point `p` et le segment `[a, b]`.
+pub(crate) fn segment_distance(a: Coord, b: Coord, p: Coord) -> f64 {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let length2 = dx * dx + dy * dy;
+
+    if length2 == 0.0 {
+        return coord_distance(a, p);
+    }
+
+    // Projection de p sur la droite (ab), bornée au segment.
+    let t = (((p.0 - a.0) * dx + (p.1 - a.1) * dy) / length2).clamp(0.0, 1.0);
+    coord_distance((a.0 + t * dx, a.1 + t * dy), p)
+}
+
+fn line_distance(line: &[Coord], p: Coord) -> f64 {
+    match line {
+        [] => f64::INFINITY,
+        [a] => coord_distance(*a, p),
+        _ => segments(line)
+            .map(|(a, b)| segment_distance(a, b, p))
+            .fold(f64::INFINITY, f64::min),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{GeometryImpl as _, LineString, MultiPoint, Polygon};
+
+    use super::*;
+
+    #[test]
+    fn test_point_to_line_distance() {
+        let line = Geometry::from(LineString::new([[0.0, 0.0], [10.0, 0.0], [10.0, 10.0]]));
+
+        assert_eq!(line.distance(&Point::new([5.0, 3.0])), 3.0);
+        assert_eq!(line.distance(&Point::new([13.0, 14.0])), 5.0);
+        assert_eq!(line.distance(&Point::new([-3.0, -4.0])), 5.0);
+    }
+
+    #[test]
+    fn test_point_to_polygon_distance() {
+        let polygon = Geometry::from(Polygon::new((
+            [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0]],
+            [[4.0, 4.0], [6.0, 4.0], [6.0, 6.0], [4.0, 6.0]],
+        )));
+
+        assert_eq!(polygon.distance(&Point::new([2.0, 2.0])), 0.0);
+        assert_eq!(polygon.distance(&Point::new([5.0, 5.0])), 1.0);
+        assert_eq!(polygon.distance(&Point::new([5.0, 12.0])), 2.0);
+    }
+
+    #[test]
+    fn test_point_to_points_distance() {
+        let points = Geometry::from(MultiPoint::new([[0.0, 0.0], [3.0, 4.0]]));
+
+        assert_eq!(points.distance(&Point::new([3.0, 5.0])), 1.0);
+    }
+}
@@ -0,0 +1,100 @@
+//! Conversions entre nos géométries et celles de GDAL (fonctionnalité `gdal`),
+//! pour lire les formats pris en charge par GDAL et les persister via nos types sqlx.
+//!
+//! Le pont passe par le WKB : le SRID est transporté par la référence spatiale
+//! GDAL, sous la forme d'un code EPSG.
+use ::gdal::{spatial_ref::SpatialRef, vector::Geometry as GdalGeometry};
+
+use super::Geometry;
+use crate::{error::Error, ewkb::EWKBGeometry, io::Decodable as _};
+
+fn gdal_error(error: ::gdal::errors::GdalError) -> Error {
+    Error::Gdal(error.to_string())
+}
+
+impl TryFrom<&Geometry> for GdalGeometry {
+    type Error = Error;
+
+    fn try_from(value: &Geometry) -> Result<Self, Self::Error> {
+        // GDAL ne comprend pas le drapeau SRID de l'EWKB.
+        let mut wkb = Vec::new();
+        crate::ewkb::encode_wkb(value.into(), &mut wkb)?;
+
+        let mut gdal_geometry = GdalGeometry::from_wkb(&wkb).map_err(gdal_error)?;
+
+        if let Some(srid) = value.srid() {
+            gdal_geometry.set_spatial_ref(SpatialRef::from_epsg(srid).map_err(gdal_error)?);
+        }
+
+        Ok(gdal_geometry)
+    }
+}
+
+impl TryFrom<&GdalGeometry> for Geometry {
+    type Error = Error;
+
+    /// Seules les références spatiales identifiées par un code EPSG sont conservées.
+    fn try_from(value: &GdalGeometry) -> Result<Self, Self::Error> {
+        let wkb = value.wkb().map_err(gdal_error)?;
+        let mut geometry = Geometry::from(EWKBGeometry::decode_from_slice(&wkb)?);
+
+        let srid = value
+            .spatial_ref()
+            .and_then(|spatial_ref| spatial_ref.auth_code().ok())
+            .and_then(|code| u32::try_from(code).ok());
+        geometry.set_srid(srid);
+
+        Ok(geometry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{
+        GeometryImpl as _, LineStringZ, MultiPolygon, MultiPolygonZ, Point, VectorTensor,
+    };
+
+    #[test]
+    fn test_gdal_round_trip() {
+        let mut point = Point::new([2.3522, 48.8566]);
+        point.srid = Some(4326);
+
+        let mut parcels = MultiPolygon::new(
+            [
+                vec![vec![[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 0.0]]],
+                vec![vec![[2.0, 2.0], [3.0, 2.0], [3.0, 3.0], [2.0, 2.0]]],
+            ]
+            .into_iter()
+            .collect::<VectorTensor<2, f64>>(),
+        );
+        parcels.srid = Some(2154);
+
+        let buildings = MultiPolygonZ::new(
+            [vec![vec![
+                [0.0, 0.0, 10.0],
+                [1.0, 0.0, 10.0],
+                [1.0, 1.0, 12.0],
+                [0.0, 0.0, 10.0],
+            ]]]
+            .into_iter()
+            .collect::<VectorTensor<3, f64>>(),
+        );
+
+        let geometries = [
+            Geometry::from(point),
+            LineStringZ::new([[0.0, 0.0, 1.0], [1.0, 1.0, 2.0]]).into(),
+            parcels.into(),
+            buildings.into(),
+        ];
+
+        for geometry in geometries {
+            let gdal_geometry =
+                GdalGeometry::try_from(&geometry).expect("cannot convert to a GDAL geometry");
+            let converted =
+                Geometry::try_from(&gdal_geometry).expect("cannot convert from a GDAL geometry");
+
+            assert_eq!(converted, geometry);
+        }
+    }
+}
@@ -0,0 +1,220 @@
+//! Conversions entre nos géométries 2D et celles de `geo-types` (fonctionnalité `geo`),
+//! pour appliquer les algorithmes de la bibliothèque `geo` aux géométries décodées.
+//!
+//! `geo-types` ne porte pas de SRID : il est perdu à la conversion.
+use ::geo_types as geo;
+
+use super::{
+    Geometry, GeometryImpl as _, GeometryKind, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Vector, VectorArray2D, VectorMatrix2D,
+};
+use crate::error::Error;
+
+fn coord(vector: &Vector<2, f64>) -> geo::Coord {
+    geo::Coord {
+        x: vector.x(),
+        y: vector.y(),
+    }
+}
+
+fn vector(coord: geo::Coord) -> Vector<2, f64> {
+    Vector::new([coord.x, coord.y])
+}
+
+fn line_string(array: &VectorArray2D) -> geo::LineString {
+    array.iter().map(coord).collect()
+}
+
+fn array(line: geo::LineString) -> VectorArray2D {
+    line.into_iter().map(vector).collect()
+}
+
+/// Le premier anneau est l'extérieur, les suivants sont les trous.
+fn polygon(rings: &VectorMatrix2D) -> geo::Polygon {
+    let mut rings = rings.iter().map(line_string);
+    let exterior = rings
+        .next()
+        .unwrap_or_else(|| geo::LineString::new(Vec::new()));
+    geo::Polygon::new(exterior, rings.collect())
+}
+
+fn matrix(polygon: geo::Polygon) -> VectorMatrix2D {
+    let (exterior, interiors) = polygon.into_inner();
+
+    if exterior.0.is_empty() && interiors.is_empty() {
+        return VectorMatrix2D::new(Vec::new());
+    }
+
+    std::iter::once(exterior)
+        .chain(interiors)
+        .map(array)
+        .collect()
+}
+
+impl From<&Point> for geo::Point {
+    fn from(value: &Point) -> Self {
+        geo::Point(coord(&value.coordinates))
+    }
+}
+
+impl From<geo::Point> for Point {
+    fn from(value: geo::Point) -> Self {
+        Point::new(vector(value.0))
+    }
+}
+
+impl From<&MultiPoint> for geo::MultiPoint {
+    fn from(value: &MultiPoint) -> Self {
+        value
+            .coordinates
+            .iter()
+            .map(|v| geo::Point(coord(v)))
+            .collect()
+    }
+}
+
+impl From<geo::MultiPoint> for MultiPoint {
+    fn from(value: geo::MultiPoint) -> Self {
+        MultiPoint::new(
+            value
+                .into_iter()
+                .map(|point| vector(point.0))
+                .collect::<VectorArray2D>(),
+        )
+    }
+}
+
+impl From<&LineString> for geo::LineString {
+    fn from(value: &LineString) -> Self {
+        line_string(&value.coordinates)
+    }
+}
+
+impl From<geo::LineString> for LineString {
+    fn from(value: geo::LineString) -> Self {
+        LineString::new(array(value))
+    }
+}
+
+impl From<&MultiLineString> for geo::MultiLineString {
+    fn from(value: &MultiLineString) -> Self {
+        value.coordinates.iter().map(line_string).collect()
+    }
+}
+
+impl From<geo::MultiLineString> for MultiLineString {
+    fn from(value: geo::MultiLineString) -> Self {
+        MultiLineString::new(value.into_iter().map(array).collect::<VectorMatrix2D>())
+    }
+}
+
+impl From<&Polygon> for geo::Polygon {
+    fn from(value: &Polygon) -> Self {
+        polygon(&value.coordinates)
+    }
+}
+
+impl From<geo::Polygon> for Polygon {
+    fn from(value: geo::Polygon) -> Self {
+        Polygon::new(matrix(value))
+    }
+}
+
+impl From<&MultiPolygon> for geo::MultiPolygon {
+    fn from(value: &MultiPolygon) -> Self {
+        value.coordinates.iter().map(polygon).collect()
+    }
+}
+
+impl From<geo::MultiPolygon> for MultiPolygon {
+    fn from(value: geo::MultiPolygon) -> Self {
+        MultiPolygon::new(
+            value
+                .into_iter()
+                .map(matrix)
+                .collect::<super::VectorTensor2D>(),
+        )
+    }
+}
+
+/// Seules les géométries 2D ont un équivalent dans `geo-types`.
+impl TryFrom<&Geometry> for geo::Geometry {
+    type Error = Error;
+
+    fn try_from(value: &Geometry) -> Result<Self, Self::Error> {
+        match value {
+            Geometry::Point(a) => Ok(geo::Point::from(a).into()),
+            Geometry::LineString(a) => Ok(geo::LineString::from(a).into()),
+            Geometry::Polygon(a) => Ok(geo::Polygon::from(a).into()),
+            Geometry::MultiPoint(a) => Ok(geo::MultiPoint::from(a).into()),
+            Geometry::MultiLineString(a) => Ok(geo::MultiLineString::from(a).into()),
+            Geometry::MultiPolygon(a) => Ok(geo::MultiPolygon::from(a).into()),
+            _ => Err(Error::invalid_dimension(2, 3)),
+        }
+    }
+}
+
+/// Les segments, rectangles et triangles sont convertis en lignes et polygones.
+impl TryFrom<geo::Geometry> for Geometry {
+    type Error = Error;
+
+    fn try_from(value: geo::Geometry) -> Result<Self, Self::Error> {
+        Ok(match value {
+            geo::Geometry::Point(a) => Point::from(a).into(),
+            geo::Geometry::Line(a) => {
+                LineString::new(VectorArray2D::from_iter([vector(a.start), vector(a.end)])).into()
+            }
+            geo::Geometry::LineString(a) => LineString::from(a).into(),
+            geo::Geometry::Polygon(a) => Polygon::from(a).into(),
+            geo::Geometry::MultiPoint(a) => MultiPoint::from(a).into(),
+            geo::Geometry::MultiLineString(a) => MultiLineString::from(a).into(),
+            geo::Geometry::MultiPolygon(a) => MultiPolygon::from(a).into(),
+            geo::Geometry::Rect(a) => Polygon::from(a.to_polygon()).into(),
+            geo::Geometry::Triangle(a) => Polygon::from(a.to_polygon()).into(),
+            geo::Geometry::GeometryCollection(_) => {
+                return Err(Error::unsupported_geometry(
+                    GeometryKind::GeometryCollection,
+                    "geometry collections are not supported",
+                ))
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_polygon_roundtrip() {
+        let expected = Polygon::new((
+            [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0]],
+            [[1.0, 1.0], [2.0, 1.0], [2.0, 2.0]],
+        ));
+        let converted = geo::Polygon::from(&expected);
+
+        assert_eq!(converted.interiors().len(), 1);
+        assert_eq!(Polygon::from(converted), expected);
+    }
+
+    #[test]
+    fn test_geometry_conversion() {
+        let rect = geo::Rect::new(
+            geo::coord! { x: 0.0, y: 0.0 },
+            geo::coord! { x: 1.0, y: 1.0 },
+        );
+        let geometry = Geometry::try_from(geo::Geometry::Rect(rect)).expect("cannot convert rect");
+
+        assert_eq!(geometry.kind(), GeometryKind::Polygon);
+        assert!(geo::Geometry::try_from(&geometry).is_ok());
+
+        let point_z = Geometry::from(super::super::PointZ::new([1.0, 2.0, 3.0]));
+        assert!(matches!(
+            geo::Geometry::try_from(&point_z),
+            Err(Error::InvalidDimension {
+                expecting: 2,
+                got: 3
+            })
+        ));
+    }
+}
@@ -0,0 +1,321 @@
+//! Implémentation en lecture seule des traits de `geo-traits` (fonctionnalité `geo-traits`),
+//! pour passer nos géométries sans copie aux bibliothèques génériques (geozero, geoarrow...).
+//!
+//! Les traits sont implémentés pour chaque classe de géométrie, et pour les vecteurs
+//! qui les composent. [Geometry](super::Geometry) ne peut pas les implémenter : ses
+//! variantes 2D et 3D n'ont pas le même type de point.
+use ::geo_traits::{
+    CoordTrait, Dimensions, GeometryTrait, GeometryType, LineStringTrait, MultiLineStringTrait,
+    MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, UnimplementedGeometryCollection,
+    UnimplementedLine, UnimplementedRect, UnimplementedTriangle,
+};
+
+use super::{
+    line_string::LineString, multi_line_string::MultiLineString, multi_point::MultiPoint,
+    multi_polygon::MultiPolygon, point::Point, polygon::Polygon, Vector, VectorArray, VectorMatrix,
+};
+
+fn dimensions<const N: usize>() -> Dimensions {
+    match N {
+        2 => Dimensions::Xy,
+        3 => Dimensions::Xyz,
+        n => Dimensions::Unknown(n),
+    }
+}
+
+/// Implémente [GeometryTrait] ; `$as_type` construit la vue typée à partir de `$this`.
+macro_rules! impl_geometry_trait {
+    ([$($generics:tt)*] $ty:ty, |$this:ident| $as_type:expr) => {
+        impl<$($generics)*> GeometryTrait for $ty {
+            type T = f64;
+            type PointType<'b>
+                = Vector<N, f64>
+            where
+                Self: 'b;
+            type LineStringType<'b>
+                = VectorArray<N, f64>
+            where
+                Self: 'b;
+            type PolygonType<'b>
+                = VectorMatrix<N, f64>
+            where
+                Self: 'b;
+            type MultiPointType<'b>
+                = MultiPoint<N, f64>
+            where
+                Self: 'b;
+            type MultiLineStringType<'b>
+                = MultiLineString<N, f64>
+            where
+                Self: 'b;
+            type MultiPolygonType<'b>
+                = MultiPolygon<N, f64>
+            where
+                Self: 'b;
+            type GeometryCollectionType<'b>
+                = UnimplementedGeometryCollection<f64>
+            where
+                Self: 'b;
+            type RectType<'b>
+                = UnimplementedRect<f64>
+            where
+                Self: 'b;
+            type TriangleType<'b>
+                = UnimplementedTriangle<f64>
+            where
+                Self: 'b;
+            type LineType<'b>
+                = UnimplementedLine<f64>
+            where
+                Self: 'b;
+
+            fn dim(&self) -> Dimensions {
+                dimensions::<N>()
+            }
+
+            fn as_type(
+                &self,
+            ) -> GeometryType<
+                '_,
+                Vector<N, f64>,
+                VectorArray<N, f64>,
+                VectorMatrix<N, f64>,
+                MultiPoint<N, f64>,
+                MultiLineString<N, f64>,
+                MultiPolygon<N, f64>,
+                UnimplementedGeometryCollection<f64>,
+                UnimplementedRect<f64>,
+                UnimplementedTriangle<f64>,
+                UnimplementedLine<f64>,
+            > {
+                let $this = self;
+                $as_type
+            }
+        }
+    };
+}
+
+impl_geometry_trait!([const N: usize] Vector<N, f64>, |this| GeometryType::Point(this));
+impl_geometry_trait!(['a, const N: usize] &'a Vector<N, f64>, |this| GeometryType::Point(this));
+impl_geometry_trait!([const N: usize] VectorArray<N, f64>, |this| GeometryType::LineString(this));
+impl_geometry_trait!(
+    ['a, const N: usize] &'a VectorArray<N, f64>,
+    |this| GeometryType::LineString(this)
+);
+impl_geometry_trait!([const N: usize] VectorMatrix<N, f64>, |this| GeometryType::Polygon(this));
+impl_geometry_trait!(
+    ['a, const N: usize] &'a VectorMatrix<N, f64>,
+    |this| GeometryType::Polygon(this)
+);
+impl_geometry_trait!([const N: usize] Point<N, f64>, |this| GeometryType::Point(&this.coordinates));
+impl_geometry_trait!(
+    [const N: usize] LineString<N, f64>,
+    |this| GeometryType::LineString(&this.coordinates)
+);
+impl_geometry_trait!(
+    [const N: usize] Polygon<N, f64>,
+    |this| GeometryType::Polygon(&this.coordinates)
+);
+impl_geometry_trait!([const N: usize] MultiPoint<N, f64>, |this| GeometryType::MultiPoint(this));
+impl_geometry_trait!(
+    [const N: usize] MultiLineString<N, f64>,
+    |this| GeometryType::MultiLineString(this)
+);
+impl_geometry_trait!(
+    [const N: usize] MultiPolygon<N, f64>,
+    |this| GeometryType::MultiPolygon(this)
+);
+
+macro_rules! impl_coord_trait {
+    ([$($generics:tt)*] $ty:ty) => {
+        impl<$($generics)*> CoordTrait for $ty {
+            type T = f64;
+
+            fn dim(&self) -> Dimensions {
+                dimensions::<N>()
+            }
+
+            fn x(&self) -> f64 {
+                self[0]
+            }
+
+            fn y(&self) -> f64 {
+                self[1]
+            }
+
+            fn nth_or_panic(&self, n: usize) -> f64 {
+                self[n]
+            }
+        }
+
+        impl<$($generics)*> PointTrait for $ty {
+            type CoordType<'b>
+                = &'b Vector<N, f64>
+            where
+                Self: 'b;
+
+            fn coord(&self) -> Option<Self::CoordType<'_>> {
+                Some(self)
+            }
+        }
+    };
+}
+
+impl_coord_trait!([const N: usize] Vector<N, f64>);
+impl_coord_trait!(['a, const N: usize] &'a Vector<N, f64>);
+
+impl<const N: usize> PointTrait for Point<N, f64> {
+    type CoordType<'b>
+        = &'b Vector<N, f64>
+    where
+        Self: 'b;
+
+    fn coord(&self) -> Option<Self::CoordType<'_>> {
+        Some(&self.coordinates)
+    }
+}
+
+macro_rules! impl_line_string_trait {
+    ([$($generics:tt)*] $ty:ty, |$this:ident| $array:expr) => {
+        impl<$($generics)*> LineStringTrait for $ty {
+            type CoordType<'b>
+                = &'b Vector<N, f64>
+            where
+                Self: 'b;
+
+            fn num_coords(&self) -> usize {
+                let $this = self;
+                $array.len()
+            }
+
+            unsafe fn coord_unchecked(&self, i: usize) -> Self::CoordType<'_> {
+                let $this = self;
+                $array.get_unchecked(i)
+            }
+        }
+    };
+}
+
+impl_line_string_trait!([const N: usize] VectorArray<N, f64>, |this| this);
+impl_line_string_trait!(['a, const N: usize] &'a VectorArray<N, f64>, |this| this);
+impl_line_string_trait!([const N: usize] LineString<N, f64>, |this| this.coordinates);
+
+/// Le premier anneau est l'extérieur, les suivants sont les trous.
+macro_rules! impl_polygon_trait {
+    ([$($generics:tt)*] $ty:ty, |$this:ident| $matrix:expr) => {
+        impl<$($generics)*> PolygonTrait for $ty {
+            type RingType<'b>
+                = &'b VectorArray<N, f64>
+            where
+                Self: 'b;
+
+            fn exterior(&self) -> Option<Self::RingType<'_>> {
+                let $this = self;
+                $matrix.first()
+            }
+
+            fn num_interiors(&self) -> usize {
+                let $this = self;
+                $matrix.len().saturating_sub(1)
+            }
+
+            unsafe fn interior_unchecked(&self, i: usize) -> Self::RingType<'_> {
+                let $this = self;
+                $matrix.get_unchecked(i + 1)
+            }
+        }
+    };
+}
+
+impl_polygon_trait!([const N: usize] VectorMatrix<N, f64>, |this| this);
+impl_polygon_trait!(['a, const N: usize] &'a VectorMatrix<N, f64>, |this| this);
+impl_polygon_trait!([const N: usize] Polygon<N, f64>, |this| this.coordinates);
+
+impl<const N: usize> MultiPointTrait for MultiPoint<N, f64> {
+    type InnerPointType<'b>
+        = &'b Vector<N, f64>
+    where
+        Self: 'b;
+
+    fn num_points(&self) -> usize {
+        self.coordinates.len()
+    }
+
+    unsafe fn point_unchecked(&self, i: usize) -> Self::InnerPointType<'_> {
+        self.coordinates.get_unchecked(i)
+    }
+}
+
+impl<const N: usize> MultiLineStringTrait for MultiLineString<N, f64> {
+    type InnerLineStringType<'b>
+        = &'b VectorArray<N, f64>
+    where
+        Self: 'b;
+
+    fn num_line_strings(&self) -> usize {
+        self.coordinates.len()
+    }
+
+    unsafe fn line_string_unchecked(&self, i: usize) -> Self::InnerLineStringType<'_> {
+        self.coordinates.get_unchecked(i)
+    }
+}
+
+impl<const N: usize> MultiPolygonTrait for MultiPolygon<N, f64> {
+    type InnerPolygonType<'b>
+        = &'b VectorMatrix<N, f64>
+    where
+        Self: 'b;
+
+    fn num_polygons(&self) -> usize {
+        self.coordinates.len()
+    }
+
+    unsafe fn polygon_unchecked(&self, i: usize) -> Self::InnerPolygonType<'_> {
+        self.coordinates.get_unchecked(i)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{self, GeometryImpl as _};
+
+    /// Aire d'un polygone, calculée uniquement au travers des traits.
+    fn area<P: PolygonTrait<T = f64>>(polygon: &P) -> f64 {
+        let ring_area = |ring: P::RingType<'_>| {
+            let coords = ring.coords().map(|c| c.x_y()).collect::<Vec<_>>();
+            coords
+                .windows(2)
+                .map(|w| w[0].0 * w[1].1 - w[1].0 * w[0].1)
+                .sum::<f64>()
+                .abs()
+                / 2.0
+        };
+
+        polygon.exterior().map(ring_area).unwrap_or_default()
+            - polygon.interiors().map(ring_area).sum::<f64>()
+    }
+
+    #[test]
+    fn test_polygon_through_traits() {
+        let polygon = types::Polygon::new((
+            [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0]],
+            [[1.0, 1.0], [2.0, 1.0], [2.0, 2.0], [1.0, 2.0]],
+        ));
+
+        assert_eq!(polygon.dim(), Dimensions::Xy);
+        assert_eq!(polygon.num_interiors(), 1);
+        assert_eq!(area(&polygon), 99.0);
+        assert!(matches!(polygon.as_type(), GeometryType::Polygon(_)));
+    }
+
+    #[test]
+    fn test_multi_point_through_traits() {
+        let multi_point = types::MultiPointZ::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        assert_eq!(multi_point.dim(), Dimensions::Xyz);
+        let point = multi_point.point(1).expect("missing point");
+        assert_eq!(point.coord().map(|c| c.nth_or_panic(2)), Some(6.0));
+    }
+}
@@ -0,0 +1,229 @@
+//! Mesures sur l'ellipsoïde pour des coordonnées géographiques (longitude, latitude)
+//! en degrés, typiquement en EPSG:4326.
+use num_traits::Euclid;
+
+// Sans `std`, les fonctions mathématiques de `f64` viennent de `libm` ; l'import
+// est inutile lorsqu'une autre dépendance lie `std`.
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use num_traits::Float as _;
+
+use super::{line_string::LineString, point::Point, Vector};
+
+/// Rayon moyen de la Terre (IUGG), en mètres.
+pub const EARTH_MEAN_RADIUS: f64 = 6_371_008.8;
+
+/// Demi-grand axe de l'ellipsoïde WGS 84, en mètres.
+const WGS84_A: f64 = 6_378_137.0;
+/// Aplatissement de l'ellipsoïde WGS 84.
+const WGS84_F: f64 = 1.0 / 298.257_223_563;
+
+impl<const N: usize> Point<N, f64> {
+    /// Distance du grand cercle jusqu'à `other` sur une sphère de rayon
+    /// [EARTH_MEAN_RADIUS], en mètres.
+    pub fn haversine_distance(&self, other: &Self) -> f64 {
+        haversine(lon_lat(&self.coordinates), lon_lat(&other.coordinates))
+    }
+
+    /// Distance géodésique jusqu'à `other` sur l'ellipsoïde WGS 84 (formule inverse
+    /// de Vincenty), en mètres.
+    ///
+    /// Pour des points quasi antipodaux, où la méthode ne converge pas, on se rabat
+    /// sur [Point::haversine_distance].
+    pub fn geodesic_distance(&self, other: &Self) -> f64 {
+        vincenty(lon_lat(&self.coordinates), lon_lat(&other.coordinates))
+            .unwrap_or_else(|| self.haversine_distance(other))
+    }
+
+    /// Cap initial (azimut) du grand cercle menant à `other`, en degrés dans
+    /// `[0, 360)` depuis le nord, dans le sens horaire.
+    pub fn bearing_to(&self, other: &Self) -> f64 {
+        let (lon1, lat1) = lon_lat(&self.coordinates);
+        let (lon2, lat2) = lon_lat(&other.coordinates);
+        let (lat1, lat2, delta_lon) = (
+            lat1.to_radians(),
+            lat2.to_radians(),
+            (lon2 - lon1).to_radians(),
+        );
+
+        let y = delta_lon.sin() * lat2.cos();
+        let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * delta_lon.cos();
+
+        Euclid::rem_euclid(&y.atan2(x).to_degrees(), &360.0)
+    }
+
+    /// Point atteint en parcourant `distance_m` mètres sur le grand cercle de cap
+    /// initial `bearing_deg` degrés.
+    ///
+    /// Les autres coordonnées (l'altitude) et le SRID sont conservés.
+    pub fn destination(&self, bearing_deg: f64, distance_m: f64) -> Self {
+        let (lon, lat) = lon_lat(&self.coordinates);
+        let (lon, lat, bearing) = (lon.to_radians(), lat.to_radians(), bearing_deg.to_radians());
+        let delta = distance_m / EARTH_MEAN_RADIUS;
+
+        let lat2 = (lat.sin() * delta.cos() + lat.cos() * delta.sin() * bearing.cos()).asin();
+        let lon2 = lon
+            + (bearing.sin() * delta.sin() * lat.cos()).atan2(delta.cos() - lat.sin() * lat2.sin());
+
+        let mut destination = self.clone();
+        destination.coordinates[0] =
+            Euclid::rem_euclid(&(lon2.to_degrees() + 540.0), &360.0) - 180.0;
+        destination.coordinates[1] = lat2.to_degrees();
+        destination
+    }
+}
+
+impl<const N: usize> LineString<N, f64> {
+    /// Longueur de la ligne en mètres, somme des distances du grand cercle.
+    pub fn haversine_length(&self) -> f64 {
+        self.coordinates
+            .windows(2)
+            .map(|w| haversine(lon_lat(&w[0]), lon_lat(&w[1])))
+            .sum()
+    }
+}
+
+fn lon_lat<const N: usize>(v: &Vector<N, f64>) -> (f64, f64) {
+    (v.x(), v.y())
+}
+
+fn haversine((lon1, lat1): (f64, f64), (lon2, lat2): (f64, f64)) -> f64 {
+    let (lon1, lat1, lon2, lat2) = (
+        lon1.to_radians(),
+        lat1.to_radians(),
+        lon2.to_radians(),
+        lat2.to_radians(),
+    );
+
+    let h = ((lat2 - lat1) / 2.0).sin().powi(2)
+        + lat1.cos() * lat2.cos() * ((lon2 - lon1) / 2.0).sin().powi(2);
+
+    2.0 * EARTH_MEAN_RADIUS * h.sqrt().min(1.0).asin()
+}
+
+/// Formule inverse de Vincenty, `None` si elle ne converge pas.
+fn vincenty((lon1, lat1): (f64, f64), (lon2, lat2): (f64, f64)) -> Option<f64> {
+    let b = WGS84_A * (1.0 - WGS84_F);
+    let l = (lon2 - lon1).to_radians();
+
+    let u1 = ((1.0 - WGS84_F) * lat1.to_radians().tan()).atan();
+    let u2 = ((1.0 - WGS84_F) * lat2.to_radians().tan()).atan();
+    let (sin_u1, cos_u1) = u1.sin_cos();
+    let (sin_u2, cos_u2) = u2.sin_cos();
+
+    let mut lambda = l;
+
+    for _ in 0..200 {
+        let (sin_lambda, cos_lambda) = lambda.sin_cos();
+        let sin_sigma = ((cos_u2 * sin_lambda).powi(2)
+            + (cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda).powi(2))
+        .sqrt();
+
+        if sin_sigma == 0.0 {
+            // Points confondus.
+            return Some(0.0);
+        }
+
+        let cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+        let sigma = sin_sigma.atan2(cos_sigma);
+        let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+        let cos2_alpha = 1.0 - sin_alpha * sin_alpha;
+
+        // Sur l'équateur, cos²α est nul.
+        let cos_2sigma_m = if cos2_alpha == 0.0 {
+            0.0
+        } else {
+            cos_sigma - 2.0 * sin_u1 * sin_u2 / cos2_alpha
+        };
+
+        let c = WGS84_F / 16.0 * cos2_alpha * (4.0 + WGS84_F * (4.0 - 3.0 * cos2_alpha));
+        let previous = lambda;
+        lambda = l
+            + (1.0 - c)
+                * WGS84_F
+                * sin_alpha
+                * (sigma
+                    + c * sin_sigma
+                        * (cos_2sigma_m + c * cos_sigma * (-1.0 + 2.0 * cos_2sigma_m.powi(2))));
+
+        if (lambda - previous).abs() < 1e-12 {
+            let u2 = cos2_alpha * (WGS84_A * WGS84_A - b * b) / (b * b);
+            let big_a = 1.0 + u2 / 16384.0 * (4096.0 + u2 * (-768.0 + u2 * (320.0 - 175.0 * u2)));
+            let big_b = u2 / 1024.0 * (256.0 + u2 * (-128.0 + u2 * (74.0 - 47.0 * u2)));
+            let delta_sigma = big_b
+                * sin_sigma
+                * (cos_2sigma_m
+                    + big_b / 4.0
+                        * (cos_sigma * (-1.0 + 2.0 * cos_2sigma_m.powi(2))
+                            - big_b / 6.0
+                                * cos_2sigma_m
+                                * (-3.0 + 4.0 * sin_sigma.powi(2))
+                                * (-3.0 + 4.0 * cos_2sigma_m.powi(2))));
+
+            return Some(b * big_a * (sigma - delta_sigma));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{GeometryImpl as _, LineString, Point};
+
+    #[test]
+    fn test_haversine_distance() {
+        // Paris - Londres, environ 343,5 km.
+        let paris = Point::new([2.3522, 48.8566]);
+        let london = Point::new([-0.1278, 51.5074]);
+
+        let distance = paris.haversine_distance(&london);
+        assert!((distance - 343_500.0).abs() < 1_000.0, "{distance}");
+        assert_eq!(paris.haversine_distance(&paris), 0.0);
+    }
+
+    #[test]
+    fn test_geodesic_distance() {
+        // Exemple de référence de Vincenty : Flinders Peak - Buninyong.
+        let flinders_peak = Point::new([
+            144.0 + 25.0 / 60.0 + 29.52440 / 3600.0,
+            -(37.0 + 57.0 / 60.0 + 3.72030 / 3600.0),
+        ]);
+        let buninyong = Point::new([
+            143.0 + 55.0 / 60.0 + 35.38390 / 3600.0,
+            -(37.0 + 39.0 / 60.0 + 10.15610 / 3600.0),
+        ]);
+
+        let distance = flinders_peak.geodesic_distance(&buninyong);
+        assert!((distance - 54_972.271).abs() < 1e-2, "{distance}");
+
+        // Points antipodaux : repli sur la distance du grand cercle.
+        let antipode = Point::new([180.0, 0.0]).geodesic_distance(&Point::new([0.0, 0.0]));
+        assert!(antipode.is_finite());
+    }
+
+    #[test]
+    fn test_bearing_and_destination() {
+        let origin = Point::new([0.0, 0.0]);
+
+        assert_eq!(origin.bearing_to(&Point::new([0.0, 1.0])), 0.0);
+        assert_eq!(origin.bearing_to(&Point::new([1.0, 0.0])), 90.0);
+        assert_eq!(origin.bearing_to(&Point::new([-1.0, 0.0])), 270.0);
+
+        let paris = Point::new([2.3522, 48.8566]);
+        let london = Point::new([-0.1278, 51.5074]);
+        let bearing = paris.bearing_to(&london);
+        let distance = paris.haversine_distance(&london);
+
+        let arrival = paris.destination(bearing, distance);
+        assert!(arrival.haversine_distance(&london) < 1e-3);
+    }
+
+    #[test]
+    fn test_haversine_length() {
+        let line = LineString::new([[0.0, 0.0], [1.0, 0.0], [1.0, 1.0]]);
+        let degree = Point::new([0.0, 0.0]).haversine_distance(&Point::new([1.0, 0.0]));
+
+        assert!((line.haversine_length() - 2.0 * degree).abs() < 1e-6);
+    }
+}
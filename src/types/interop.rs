@@ -0,0 +1,88 @@
+//! Conversions entre nos vecteurs et ceux des bibliothèques d'algèbre linéaire
+//! (`nalgebra`, `glam`), activées par les fonctionnalités du même nom.
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra {
+    use super::super::Vector;
+
+    impl From<Vector<2, f64>> for ::nalgebra::Point2<f64> {
+        fn from(value: Vector<2, f64>) -> Self {
+            Self::new(value.x(), value.y())
+        }
+    }
+
+    impl From<::nalgebra::Point2<f64>> for Vector<2, f64> {
+        fn from(value: ::nalgebra::Point2<f64>) -> Self {
+            Vector::new([value.x, value.y])
+        }
+    }
+
+    impl From<Vector<3, f64>> for ::nalgebra::Point3<f64> {
+        fn from(value: Vector<3, f64>) -> Self {
+            Self::new(value.x(), value.y(), value.z())
+        }
+    }
+
+    impl From<::nalgebra::Point3<f64>> for Vector<3, f64> {
+        fn from(value: ::nalgebra::Point3<f64>) -> Self {
+            Vector::new([value.x, value.y, value.z])
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_nalgebra_roundtrip() {
+            let vector = Vector::new([1.0, 2.0, 3.0]);
+            let point = ::nalgebra::Point3::from(vector);
+
+            assert_eq!(point, ::nalgebra::Point3::new(1.0, 2.0, 3.0));
+            assert_eq!(Vector::from(point), vector);
+        }
+    }
+}
+
+#[cfg(feature = "glam")]
+mod glam {
+    use super::super::Vector;
+
+    impl From<Vector<2, f64>> for ::glam::DVec2 {
+        fn from(value: Vector<2, f64>) -> Self {
+            Self::from_array(*value)
+        }
+    }
+
+    impl From<::glam::DVec2> for Vector<2, f64> {
+        fn from(value: ::glam::DVec2) -> Self {
+            Vector::new(value.to_array())
+        }
+    }
+
+    impl From<Vector<3, f64>> for ::glam::DVec3 {
+        fn from(value: Vector<3, f64>) -> Self {
+            Self::from_array(*value)
+        }
+    }
+
+    impl From<::glam::DVec3> for Vector<3, f64> {
+        fn from(value: ::glam::DVec3) -> Self {
+            Vector::new(value.to_array())
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_glam_roundtrip() {
+            let vector = Vector::new([1.0, 2.0]);
+            let dvec = ::glam::DVec2::from(vector);
+
+            assert_eq!(dvec, ::glam::DVec2::new(1.0, 2.0));
+            assert_eq!(Vector::from(dvec), vector);
+        }
+    }
+}
@@ -0,0 +1,230 @@
+//! Codes numériques des types de géométrie, partagés par les formats binaires.
+//!
+//! Les tables sont indexées dans l'ordre de [GEOMETRY_KINDS] : un encodage est une simple
+//! indexation, un décodage une recherche dans la table.
+use super::GeometryKind;
+
+/// Tous les types de géométrie, dans l'ordre de déclaration de [GeometryKind].
+pub const GEOMETRY_KINDS: [GeometryKind; 14] = [
+    GeometryKind::Point,
+    GeometryKind::LineString,
+    GeometryKind::Polygon,
+    GeometryKind::MultiPoint,
+    GeometryKind::MultiLineString,
+    GeometryKind::MultiPolygon,
+    GeometryKind::GeometryCollection,
+    GeometryKind::PointZ,
+    GeometryKind::LineStringZ,
+    GeometryKind::PolygonZ,
+    GeometryKind::MultiPointZ,
+    GeometryKind::MultiLineStringZ,
+    GeometryKind::MultiPolygonZ,
+    GeometryKind::GeometryCollectionZ,
+];
+
+/// Codes ISO WKB (SpatiaLite, GeoPackage) : le code 2D, plus 1000 pour Z.
+pub const WKB_CODES: [u32; 14] = [
+    1, 2, 3, 4, 5, 6, 7, 1001, 1002, 1003, 1004, 1005, 1006, 1007,
+];
+
+/// Codes EWKB (PostGIS) : le code 2D, avec le drapeau [EWKB_Z_FLAG] pour Z.
+pub const EWKB_CODES: [u32; 14] = [
+    1,
+    2,
+    3,
+    4,
+    5,
+    6,
+    7,
+    EWKB_Z_FLAG | 1,
+    EWKB_Z_FLAG | 2,
+    EWKB_Z_FLAG | 3,
+    EWKB_Z_FLAG | 4,
+    EWKB_Z_FLAG | 5,
+    EWKB_Z_FLAG | 6,
+    EWKB_Z_FLAG | 7,
+];
+
+/// Mots-clés WKT des types 2D, indexés par [GeometryKind::base_code] moins un.
+pub const WKT_TAGS: [&str; 7] = [
+    "POINT",
+    "LINESTRING",
+    "POLYGON",
+    "MULTIPOINT",
+    "MULTILINESTRING",
+    "MULTIPOLYGON",
+    "GEOMETRYCOLLECTION",
+];
+
+/// Drapeau EWKB des géométries 3D.
+pub const EWKB_Z_FLAG: u32 = 0x80000000;
+
+impl GeometryKind {
+    const fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Vrai pour les types 3D.
+    pub const fn is_3d(self) -> bool {
+        self.index() >= 7
+    }
+
+    /// Toujours faux : les mesures (M, ZM) ne sont pas prises en charge.
+    pub const fn has_m(self) -> bool {
+        false
+    }
+
+    /// Type 2D correspondant, par exemple [GeometryKind::Point] pour [GeometryKind::PointZ].
+    pub const fn base_kind(self) -> Self {
+        GEOMETRY_KINDS[self.index() % 7]
+    }
+
+    /// Vrai pour les types composés de plusieurs géométries, collections comprises.
+    pub const fn is_multi(self) -> bool {
+        self.base_code() >= 4
+    }
+
+    /// Type multiple regroupant des géométries de ce type, de même dimension.
+    ///
+    /// `None` si le type est déjà multiple.
+    pub const fn multi_of(self) -> Option<Self> {
+        if self.is_multi() {
+            return None;
+        }
+
+        Self::from_base_code(self.base_code() + 3, self.is_3d())
+    }
+
+    /// Type des géométries composant ce type multiple, de même dimension.
+    ///
+    /// `None` pour un type simple ou une collection, dont les composants sont hétérogènes.
+    pub const fn single_of(self) -> Option<Self> {
+        match self.base_code() {
+            4..=6 => Self::from_base_code(self.base_code() - 3, self.is_3d()),
+            _ => None,
+        }
+    }
+
+    /// Code du type 2D correspondant, de 1 (Point) à 7 (GeometryCollection).
+    pub const fn base_code(self) -> u32 {
+        (self.index() % 7) as u32 + 1
+    }
+
+    /// Inverse de [GeometryKind::base_code].
+    pub const fn from_base_code(code: u32, is_3d: bool) -> Option<Self> {
+        match code {
+            1..=7 => Some(GEOMETRY_KINDS[(code - 1) as usize + if is_3d { 7 } else { 0 }]),
+            _ => None,
+        }
+    }
+
+    /// Mot-clé WKT du type, sans le suffixe ` Z` des types 3D.
+    pub const fn wkt_tag(self) -> &'static str {
+        WKT_TAGS[self.index() % 7]
+    }
+
+    /// Inverse de [GeometryKind::wkt_tag], sans tenir compte de la casse.
+    pub fn from_wkt_tag(tag: &str, is_3d: bool) -> Option<Self> {
+        let code = WKT_TAGS
+            .iter()
+            .position(|candidate| candidate.eq_ignore_ascii_case(tag))?;
+        Self::from_base_code(code as u32 + 1, is_3d)
+    }
+
+    /// Code ISO WKB du type.
+    pub const fn wkb_code(self) -> u32 {
+        WKB_CODES[self.index()]
+    }
+
+    /// Inverse de [GeometryKind::wkb_code].
+    pub const fn from_wkb_code(code: u32) -> Option<Self> {
+        lookup(&WKB_CODES, code)
+    }
+
+    /// Code EWKB du type, sans le drapeau SRID.
+    pub const fn ewkb_code(self) -> u32 {
+        EWKB_CODES[self.index()]
+    }
+
+    /// Inverse de [GeometryKind::ewkb_code].
+    ///
+    /// Les codes ISO WKB sont aussi acceptés, ceux que renvoie notamment ST_AsBinary.
+    pub const fn from_ewkb_code(code: u32) -> Option<Self> {
+        match lookup(&EWKB_CODES, code) {
+            Some(kind) => Some(kind),
+            None => Self::from_wkb_code(code),
+        }
+    }
+}
+
+const fn lookup(codes: &[u32; 14], code: u32) -> Option<GeometryKind> {
+    let mut i = 0;
+    while i < codes.len() {
+        if codes[i] == code {
+            return Some(GEOMETRY_KINDS[i]);
+        }
+        i += 1;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kind_codes() {
+        for (i, kind) in GEOMETRY_KINDS.into_iter().enumerate() {
+            assert_eq!(kind as usize, i);
+
+            assert_eq!(GeometryKind::from_wkb_code(kind.wkb_code()), Some(kind));
+            assert_eq!(GeometryKind::from_ewkb_code(kind.ewkb_code()), Some(kind));
+            assert_eq!(GeometryKind::from_ewkb_code(kind.wkb_code()), Some(kind));
+            assert_eq!(
+                GeometryKind::from_base_code(kind.base_code(), kind.is_3d()),
+                Some(kind)
+            );
+            assert_eq!(
+                GeometryKind::from_wkt_tag(kind.wkt_tag(), kind.is_3d()),
+                Some(kind)
+            );
+        }
+
+        for kind in GEOMETRY_KINDS {
+            assert!(!kind.has_m());
+            assert!(!kind.base_kind().is_3d());
+            assert_eq!(kind.base_kind().base_code(), kind.base_code());
+
+            if let Some(multi) = kind.multi_of() {
+                assert!(multi.is_multi());
+                assert_eq!(multi.is_3d(), kind.is_3d());
+                assert_eq!(multi.single_of(), Some(kind));
+            }
+        }
+
+        assert_eq!(GeometryKind::PolygonZ.base_kind(), GeometryKind::Polygon);
+        assert_eq!(
+            GeometryKind::LineStringZ.multi_of(),
+            Some(GeometryKind::MultiLineStringZ)
+        );
+        assert_eq!(GeometryKind::MultiPoint.multi_of(), None);
+        assert_eq!(
+            GeometryKind::MultiPoint.single_of(),
+            Some(GeometryKind::Point)
+        );
+        assert_eq!(GeometryKind::GeometryCollection.single_of(), None);
+        assert!(GeometryKind::GeometryCollectionZ.is_multi());
+        assert!(!GeometryKind::Polygon.is_multi());
+
+        assert_eq!(GeometryKind::PolygonZ.wkb_code(), 1003);
+        assert_eq!(GeometryKind::PolygonZ.ewkb_code(), 0x80000003);
+        assert_eq!(GeometryKind::from_wkb_code(0x80000003), None);
+        assert_eq!(GeometryKind::from_base_code(0, false), None);
+        assert_eq!(GeometryKind::from_ewkb_code(42), None);
+        assert_eq!(
+            GeometryKind::from_wkt_tag("MultiLineString", true),
+            Some(GeometryKind::MultiLineStringZ)
+        );
+        assert_eq!(GeometryKind::from_wkt_tag("CIRCULARSTRING", false), None);
+    }
+}
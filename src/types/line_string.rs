@@ -1,15 +1,25 @@
-use super::{GeometryImpl, VectorArray, MBR};
+use super::{GeometryImpl, Scalar, VectorArray, MBR};
 
 pub type LineStringCoordinates<const N: usize, U> = VectorArray<N, U>;
 
 /// Une suite de points reliés.
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
 pub struct LineString<const N: usize, U> {
     pub coordinates: LineStringCoordinates<N, U>,
     pub srid: Option<u32>,
 }
 
-impl<const N: usize, U>  GeometryImpl for LineString<N, U> {
+/// Géométrie vide, sans SRID.
+impl<const N: usize, U> Default for LineString<N, U> {
+    fn default() -> Self {
+        Self {
+            coordinates: Default::default(),
+            srid: None,
+        }
+    }
+}
+
+impl<const N: usize, U> GeometryImpl for LineString<N, U> {
     type Coordinates = LineStringCoordinates<N, U>;
 
     fn new<C: Into<Self::Coordinates>>(coordinates: C) -> Self {
@@ -22,15 +32,53 @@ impl<const N: usize, U>  GeometryImpl for LineString<N, U> {
 
 impl<const N: usize, U> LineString<N, U>
 where
-    U: Copy + PartialOrd,
+    U: Scalar,
 {
-    pub fn mbr(&self) -> MBR<U> {
-        MBR {
-            min_x: self.coordinates.min_x(),
-            max_x: self.coordinates.max_x(),
-            min_y: self.coordinates.min_y(),
-            max_y: self.coordinates.max_y(),
-        }
+    /// Rectangle englobant, `None` si la géométrie n'a aucune coordonnée exploitable.
+    pub fn mbr(&self) -> Option<MBR<N, U>> {
+        self.coordinates.mbr()
+    }
+}
+
+impl<U> FromIterator<(U, U)> for LineString<2, U> {
+    fn from_iter<T: IntoIterator<Item = (U, U)>>(iter: T) -> Self {
+        Self::new(
+            iter.into_iter()
+                .map(|(x, y)| [x, y])
+                .collect::<VectorArray<2, U>>(),
+        )
     }
 }
 
+impl<U> FromIterator<(U, U, U)> for LineString<3, U> {
+    fn from_iter<T: IntoIterator<Item = (U, U, U)>>(iter: T) -> Self {
+        Self::new(
+            iter.into_iter()
+                .map(|(x, y, z)| [x, y, z])
+                .collect::<VectorArray<3, U>>(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{GeometryImpl as _, LineString, LineStringZ, MultiPoint, MultiPointZ};
+
+    #[test]
+    fn test_collect_tuples() {
+        let line: LineString = (0..3).map(|i| (i as f64, 0.0)).collect();
+        assert_eq!(line, LineString::new([[0.0, 0.0], [1.0, 0.0], [2.0, 0.0]]));
+
+        let points: MultiPointZ = [(1.0, 2.0, 3.0)].into_iter().collect();
+        assert_eq!(points, MultiPointZ::new([[1.0, 2.0, 3.0]]));
+
+        assert_eq!(
+            [(0.0, 0.0, 1.0)].into_iter().collect::<LineStringZ>(),
+            LineStringZ::new([[0.0, 0.0, 1.0]])
+        );
+        assert!(core::iter::empty()
+            .collect::<MultiPoint>()
+            .coordinates
+            .is_empty());
+    }
+}
@@ -0,0 +1,125 @@
+//! Référencement linéaire, équivalents locaux de `ST_LineInterpolatePoint` et
+//! `ST_LineSubstring`.
+//!
+//! Les longueurs sont mesurées dans le plan XY, les autres coordonnées sont interpolées.
+use super::{line_string::LineString, point::Point, GeometryImpl as _, Vector, VectorArray};
+use crate::prelude::*;
+
+impl<const N: usize> LineString<N, f64> {
+    /// Longueur planaire de la ligne.
+    pub fn length(&self) -> f64 {
+        self.coordinates
+            .windows(2)
+            .map(|w| segment_length(&w[0], &w[1]))
+            .sum()
+    }
+
+    /// Point situé à la fraction `fraction` (bornée à `[0, 1]`) de la longueur de la ligne.
+    ///
+    /// Renvoie `None` si la ligne est vide.
+    pub fn point_at_fraction(&self, fraction: f64) -> Option<Point<N, f64>> {
+        self.point_at_distance(fraction.clamp(0.0, 1.0) * self.length())
+    }
+
+    /// Point situé à `distance` (bornée à la longueur) du début de la ligne.
+    ///
+    /// Renvoie `None` si la ligne est vide.
+    pub fn point_at_distance(&self, distance: f64) -> Option<Point<N, f64>> {
+        let mut point = Point::new(self.vector_at_distance(distance)?);
+        point.srid = self.srid;
+        Some(point)
+    }
+
+    /// Portion de la ligne comprise entre les fractions `start` et `end` de sa longueur.
+    ///
+    /// Renvoie `None` si la ligne est vide ou si `start > end`.
+    pub fn substring(&self, start: f64, end: f64) -> Option<Self> {
+        let (start, end) = (start.clamp(0.0, 1.0), end.clamp(0.0, 1.0));
+
+        if start > end {
+            return None;
+        }
+
+        let length = self.length();
+        let (from, to) = (start * length, end * length);
+
+        let mut coordinates = vec![self.vector_at_distance(from)?];
+        let mut travelled = 0.0;
+
+        for w in self.coordinates.windows(2) {
+            travelled += segment_length(&w[0], &w[1]);
+
+            if travelled > from && travelled < to {
+                coordinates.push(w[1]);
+            }
+        }
+
+        coordinates.push(self.vector_at_distance(to)?);
+
+        Some(Self {
+            coordinates: VectorArray::new(coordinates),
+            srid: self.srid,
+        })
+    }
+
+    fn vector_at_distance(&self, distance: f64) -> Option<Vector<N, f64>> {
+        let mut remaining = distance.max(0.0);
+
+        for w in self.coordinates.windows(2) {
+            let (a, b) = (&w[0], &w[1]);
+            let length = segment_length(a, b);
+
+            if remaining <= length && length > 0.0 {
+                let t = remaining / length;
+                return Some(Vector::new(core::array::from_fn(|k| {
+                    a[k] + t * (b[k] - a[k])
+                })));
+            }
+
+            remaining -= length;
+        }
+
+        self.coordinates.last().cloned()
+    }
+}
+
+fn segment_length<const N: usize>(a: &Vector<N, f64>, b: &Vector<N, f64>) -> f64 {
+    (b.x() - a.x()).hypot(b.y() - a.y())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{GeometryImpl as _, LineString, LineStringZ, Point, PointZ};
+
+    #[test]
+    fn test_point_at_fraction() {
+        let line = LineString::new([[0.0, 0.0], [10.0, 0.0], [10.0, 10.0]]);
+
+        assert_eq!(line.length(), 20.0);
+        assert_eq!(line.point_at_fraction(0.25), Some(Point::new([5.0, 0.0])));
+        assert_eq!(line.point_at_fraction(0.75), Some(Point::new([10.0, 5.0])));
+        assert_eq!(line.point_at_fraction(2.0), Some(Point::new([10.0, 10.0])));
+        assert_eq!(line.point_at_distance(-1.0), Some(Point::new([0.0, 0.0])));
+
+        let line = LineStringZ::new([[0.0, 0.0, 0.0], [4.0, 0.0, 8.0]]);
+        assert_eq!(
+            line.point_at_distance(1.0),
+            Some(PointZ::new([1.0, 0.0, 2.0]))
+        );
+    }
+
+    #[test]
+    fn test_substring() {
+        let line = LineString::new([[0.0, 0.0], [10.0, 0.0], [10.0, 10.0]]);
+
+        assert_eq!(
+            line.substring(0.25, 0.75),
+            Some(LineString::new([[5.0, 0.0], [10.0, 0.0], [10.0, 5.0]]))
+        );
+        assert_eq!(
+            line.substring(0.0, 0.25),
+            Some(LineString::new([[0.0, 0.0], [5.0, 0.0]]))
+        );
+        assert_eq!(line.substring(0.75, 0.25), None);
+    }
+}
@@ -1,8 +1,236 @@
-#[derive(Debug, Clone, PartialEq, Eq)]
-/// Rectangle à limite minimum (minimum bounding rectangle)
-pub struct MBR<U> {
-    pub min_x: U,
-    pub min_y: U,
-    pub max_x: U,
-    pub max_y: U,
+use serde::{de::Error as _, ser::SerializeTuple as _, Deserialize, Serialize};
+
+use super::{Geometry, GeometryImpl as _, Polygon, Scalar, MBR2D};
+use crate::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Rectangle à limite minimum (minimum bounding rectangle) dans un espace n-d.
+///
+/// `min[i]` et `max[i]` bornent la i-ème coordonnée.
+pub struct MBR<const N: usize, U> {
+    pub min: [U; N],
+    pub max: [U; N],
+}
+
+impl<const N: usize, U> MBR<N, U> {
+    pub fn new(min: [U; N], max: [U; N]) -> Self {
+        Self { min, max }
+    }
+}
+
+impl<const N: usize, U> MBR<N, U>
+where
+    U: Scalar,
+{
+    pub fn min_x(&self) -> U {
+        self.min[0]
+    }
+
+    pub fn max_x(&self) -> U {
+        self.max[0]
+    }
+
+    pub fn min_y(&self) -> U {
+        self.min[1]
+    }
+
+    pub fn max_y(&self) -> U {
+        self.max[1]
+    }
+
+    /// Rectangle englobant les deux rectangles.
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            min: core::array::from_fn(|i| {
+                if other.min[i] < self.min[i] {
+                    other.min[i]
+                } else {
+                    self.min[i]
+                }
+            }),
+            max: core::array::from_fn(|i| {
+                if other.max[i] > self.max[i] {
+                    other.max[i]
+                } else {
+                    self.max[i]
+                }
+            }),
+        }
+    }
+
+    /// Projection du rectangle sur le plan XY.
+    pub fn to_2d(&self) -> MBR<2, U> {
+        MBR::new([self.min_x(), self.min_y()], [self.max_x(), self.max_y()])
+    }
+}
+
+impl<U> MBR<3, U>
+where
+    U: Copy,
+{
+    pub fn min_z(&self) -> U {
+        self.min[2]
+    }
+
+    pub fn max_z(&self) -> U {
+        self.max[2]
+    }
+}
+
+impl<const N: usize> MBR<N, f64> {
+    /// Polygone correspondant à l'emprise planaire, parcouru dans le sens direct.
+    pub fn to_polygon(&self, srid: Option<u32>) -> Polygon {
+        let mut polygon = Polygon::new([
+            [self.min_x(), self.min_y()],
+            [self.max_x(), self.min_y()],
+            [self.max_x(), self.max_y()],
+            [self.min_x(), self.max_y()],
+            [self.min_x(), self.min_y()],
+        ]);
+        polygon.srid = srid;
+        polygon
+    }
+}
+
+impl MBR2D {
+    /// Emprise planaire d'un ensemble de géométries, `None` si aucune n'a d'emprise.
+    pub fn from_geometries<'a, I>(geometries: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = &'a Geometry>,
+    {
+        geometries
+            .into_iter()
+            .filter_map(Geometry::mbr)
+            .reduce(|a, b| a.union(&b))
+    }
+}
+
+/// Agrandit l'emprise pour couvrir chaque géométrie ; les géométries vides sont ignorées.
+impl<'a> Extend<&'a Geometry> for MBR2D {
+    fn extend<T: IntoIterator<Item = &'a Geometry>>(&mut self, iter: T) {
+        if let Some(mbr) = Self::from_geometries(iter) {
+            *self = self.union(&mbr);
+        }
+    }
+}
+
+/// Sérialisé comme le membre `bbox` de GeoJSON : `[minx, miny, (minz,) maxx, maxy, (maxz)]`.
+impl<const N: usize, U> Serialize for MBR<N, U>
+where
+    U: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut tuple = serializer.serialize_tuple(2 * N)?;
+
+        for value in self.min.iter().chain(self.max.iter()) {
+            tuple.serialize_element(value)?;
+        }
+
+        tuple.end()
+    }
+}
+
+impl<'de, const N: usize, U> Deserialize<'de> for MBR<N, U>
+where
+    U: Deserialize<'de> + Copy,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let values = Vec::<U>::deserialize(deserializer)?;
+
+        if values.len() != 2 * N {
+            return Err(D::Error::invalid_length(
+                values.len(),
+                &format!("a bbox of {} values", 2 * N).as_str(),
+            ));
+        }
+
+        Ok(Self::new(
+            core::array::from_fn(|i| values[i]),
+            core::array::from_fn(|i| values[N + i]),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{
+        Geometry, GeometryImpl as _, LineString, LineStringZ, Point, PointZ, VectorArray, MBR2D,
+        MBR3D,
+    };
+
+    #[test]
+    fn test_mbr_3d() {
+        let line = LineStringZ::new([[0.0, 1.0, -2.0], [3.0, -1.0, 5.0]]);
+        let mbr = line.mbr().unwrap();
+
+        assert_eq!((mbr.min_z(), mbr.max_z()), (-2.0, 5.0));
+        assert_eq!(mbr.to_2d().min, [0.0, -1.0]);
+        assert_eq!(mbr.to_2d().max, [3.0, 1.0]);
+    }
+
+    #[test]
+    fn test_mbr_to_polygon() {
+        let mbr = LineStringZ::new([[0.0, 1.0, 0.0], [2.0, 3.0, 0.0]])
+            .mbr()
+            .unwrap();
+        let polygon = mbr.to_polygon(Some(4326));
+
+        assert_eq!(polygon.srid, Some(4326));
+        assert_eq!(polygon.coordinates[0].len(), 5);
+        assert_eq!(polygon.mbr(), Some(mbr.to_2d()));
+    }
+
+    #[test]
+    fn test_mbr_empty_or_nan() {
+        let line = LineString::new([[f64::NAN, 1.0], [2.0, f64::NAN], [-1.0, 3.0]]);
+        let mbr = line.mbr().unwrap();
+
+        assert_eq!((mbr.min_x(), mbr.max_x()), (-1.0, 2.0));
+        assert_eq!((mbr.min_y(), mbr.max_y()), (1.0, 3.0));
+        assert_eq!(LineString::new(VectorArray::new(vec![])).mbr(), None);
+        assert_eq!(Point::new([f64::NAN, 0.0]).mbr(), None);
+    }
+
+    #[test]
+    fn test_mbr_union() {
+        let a = PointZ::new([0.0, 0.0, 1.0]).mbr().unwrap();
+        let b = PointZ::new([2.0, -1.0, 4.0]).mbr().unwrap();
+        let union = a.union(&b);
+
+        assert_eq!(union.min, [0.0, -1.0, 1.0]);
+        assert_eq!(union.max, [2.0, 0.0, 4.0]);
+    }
+
+    #[test]
+    fn test_mbr_from_geometries() {
+        let geometries: Vec<Geometry> = vec![
+            Point::new([1.0, 1.0]).into(),
+            LineString::new(VectorArray::new(vec![])).into(),
+            LineStringZ::new([[-2.0, 0.0, 9.0], [0.0, 3.0, 9.0]]).into(),
+        ];
+
+        let mut mbr = MBR2D::from_geometries(&geometries).unwrap();
+        assert_eq!(mbr, MBR2D::new([-2.0, 0.0], [1.0, 3.0]));
+
+        mbr.extend(&[Geometry::from(Point::new([5.0, -1.0]))]);
+        assert_eq!(mbr, MBR2D::new([-2.0, -1.0], [5.0, 3.0]));
+
+        assert_eq!(MBR2D::from_geometries(&geometries[1..2]), None);
+    }
+
+    #[test]
+    fn test_mbr_serde() {
+        let mbr = MBR2D::new([-1.5, 2.0], [10.0, 20.25]);
+        let encoded = serde_json::to_string(&mbr).expect("cannot serialize bbox");
+
+        assert_eq!(encoded, "[-1.5,2.0,10.0,20.25]");
+        assert_eq!(serde_json::from_str::<MBR2D>(&encoded).ok(), Some(mbr));
+        assert!(serde_json::from_str::<MBR3D>(&encoded).is_err());
+    }
 }
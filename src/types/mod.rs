@@ -1,14 +1,68 @@
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "arena")]
+mod arena;
+mod bounding_circle;
+mod buffer;
+mod coord_buffer;
+mod delaunay;
+mod densify;
+mod dimension;
+mod distance;
+#[cfg(feature = "gdal")]
+mod gdal;
+#[cfg(feature = "geo")]
+mod geo;
+#[cfg(feature = "geo-traits")]
+mod geo_traits;
+mod geodesy;
+mod interop;
+mod kind_codes;
 mod line_string;
+mod linear_referencing;
 mod mbr;
 mod multi_line_string;
 mod multi_point;
 mod multi_polygon;
+mod orientation;
+mod overlay;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod planar;
 mod point;
 mod polygon;
+mod predicates;
+#[cfg(feature = "proj4rs")]
+mod reproject;
+mod scalar;
+pub mod segment;
+mod shared;
+mod simplify;
+mod space_filling;
+mod transform;
+mod validity;
 mod vectors;
-
+mod views;
+pub mod wkt;
+
+#[cfg(feature = "arena")]
+pub use arena::{ArenaCoordinates, ArenaGeometry, Bump};
+pub use coord_buffer::CoordBuffer;
+pub use densify::MAX_DENSIFIED_VERTICES;
+pub use geodesy::EARTH_MEAN_RADIUS;
+pub use kind_codes::{EWKB_CODES, EWKB_Z_FLAG, GEOMETRY_KINDS, WKB_CODES, WKT_TAGS};
 pub use mbr::MBR;
-pub use vectors::{Vector, VectorArray, VectorMatrix, VectorTensor};
+pub use orientation::RingOrientation;
+pub use overlay::BooleanOps;
+#[cfg(feature = "proj4rs")]
+pub use reproject::Reprojector;
+pub use scalar::Scalar;
+pub use shared::SharedGeometry;
+pub use space_filling::sort_geometries_hilbert;
+pub use transform::AffineTransform;
+pub use validity::ValidationError;
+pub use vectors::{Vector, VectorArray, VectorMatrix, VectorTensor, INLINE_VECTORS};
+pub use views::{PointView, RingView};
 
 pub type Vector2D = Vector<2, f64>;
 pub type VectorArray2D = VectorArray<2, f64>;
@@ -20,6 +74,9 @@ pub type VectorArray3D = VectorArray<3, f64>;
 pub type VectorMatrix3D = VectorMatrix<3, f64>;
 pub type VectorTensor3D = VectorTensor<3, f64>;
 
+pub type MBR2D = MBR<2, f64>;
+pub type MBR3D = MBR<3, f64>;
+
 // A point in a 2D space.
 pub type Point = point::Point<2, f64>;
 pub type MultiPoint = multi_point::MultiPoint<2, f64>;
@@ -61,6 +118,11 @@ pub enum Geometry {
 }
 
 impl Geometry {
+    /// Emprunte la géométrie, sans la cloner.
+    pub fn as_geometry_ref(&self) -> GeometryRef<'_> {
+        self.into()
+    }
+
     /// Emprunte les coordonnées d'une géométrie.
     pub fn borrow_coordinates(&self) -> CoordinatesRef<'_> {
         match self {
@@ -96,7 +158,8 @@ impl Geometry {
         }
     }
 
-    pub fn mbr(&self) -> MBR<f64> {
+    /// Emprise planaire, `None` si la géométrie est vide ou n'a que des coordonnées NaN.
+    pub fn mbr(&self) -> Option<MBR2D> {
         match self {
             Geometry::Point(a) => a.mbr(),
             Geometry::LineString(a) => a.mbr(),
@@ -104,12 +167,46 @@ impl Geometry {
             Geometry::MultiPoint(a) => a.mbr(),
             Geometry::MultiLineString(a) => a.mbr(),
             Geometry::MultiPolygon(a) => a.mbr(),
+            Geometry::PointZ(a) => a.mbr().map(|mbr| mbr.to_2d()),
+            Geometry::LineStringZ(a) => a.mbr().map(|mbr| mbr.to_2d()),
+            Geometry::PolygonZ(a) => a.mbr().map(|mbr| mbr.to_2d()),
+            Geometry::MultiPointZ(a) => a.mbr().map(|mbr| mbr.to_2d()),
+            Geometry::MultiLineStringZ(a) => a.mbr().map(|mbr| mbr.to_2d()),
+            Geometry::MultiPolygonZ(a) => a.mbr().map(|mbr| mbr.to_2d()),
+        }
+    }
+
+    /// Emprise en trois dimensions, `None` pour une géométrie 2D.
+    pub fn mbr_3d(&self) -> Option<MBR3D> {
+        match self {
             Geometry::PointZ(a) => a.mbr(),
             Geometry::LineStringZ(a) => a.mbr(),
             Geometry::PolygonZ(a) => a.mbr(),
             Geometry::MultiPointZ(a) => a.mbr(),
             Geometry::MultiLineStringZ(a) => a.mbr(),
             Geometry::MultiPolygonZ(a) => a.mbr(),
+            _ => None,
+        }
+    }
+
+    /// Estimation des octets alloués sur le tas par les coordonnées, pour dimensionner
+    /// un cache de géométries décodées.
+    ///
+    /// La capacité réservée est comptée ; les petits tableaux stockés en ligne
+    /// (voir [INLINE_VECTORS]) ne coûtent rien.
+    pub fn heap_size(&self) -> usize {
+        match self {
+            Geometry::Point(_) | Geometry::PointZ(_) => 0,
+            Geometry::LineString(a) => a.coordinates.heap_size(),
+            Geometry::Polygon(a) => a.coordinates.heap_size(),
+            Geometry::MultiPoint(a) => a.coordinates.heap_size(),
+            Geometry::MultiLineString(a) => a.coordinates.heap_size(),
+            Geometry::MultiPolygon(a) => a.coordinates.heap_size(),
+            Geometry::LineStringZ(a) => a.coordinates.heap_size(),
+            Geometry::PolygonZ(a) => a.coordinates.heap_size(),
+            Geometry::MultiPointZ(a) => a.coordinates.heap_size(),
+            Geometry::MultiLineStringZ(a) => a.coordinates.heap_size(),
+            Geometry::MultiPolygonZ(a) => a.coordinates.heap_size(),
         }
     }
 
@@ -130,6 +227,17 @@ impl Geometry {
         }
     }
 
+    /// Renvoie la géométrie avec le SRID donné, empruntée si elle l'a déjà.
+    pub fn with_srid(&self, srid: Option<u32>) -> CowGeometry<'_> {
+        if self.srid() == srid {
+            return alloc::borrow::Cow::Borrowed(self);
+        }
+
+        let mut geometry = self.clone();
+        geometry.set_srid(srid);
+        alloc::borrow::Cow::Owned(geometry)
+    }
+
     pub fn srid(&self) -> Option<u32> {
         match self {
             Geometry::Point(p) => p.srid,
@@ -148,7 +256,355 @@ impl Geometry {
     }
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// Implémente les accesseurs typés de chaque variante de [Geometry].
+macro_rules! impl_geometry_variants {
+    ($($variant:ident => $as_ref:ident, $as_mut:ident, $into:ident;)*) => {
+        impl Geometry {
+            $(
+                #[doc = concat!("Emprunte la géométrie si c'est un `", stringify!($variant), "`.")]
+                pub fn $as_ref(&self) -> Option<&$variant> {
+                    match self {
+                        Self::$variant(geometry) => Some(geometry),
+                        _ => None,
+                    }
+                }
+
+                #[doc = concat!("Emprunte mutablement la géométrie si c'est un `", stringify!($variant), "`.")]
+                pub fn $as_mut(&mut self) -> Option<&mut $variant> {
+                    match self {
+                        Self::$variant(geometry) => Some(geometry),
+                        _ => None,
+                    }
+                }
+
+                #[doc = concat!("Extrait le `", stringify!($variant), "`, ou rend la géométrie s'il s'agit d'un autre type.")]
+                // La géométrie rendue n'est pas une erreur à propager, mais la valeur d'origine.
+                #[allow(clippy::result_large_err)]
+                pub fn $into(self) -> Result<$variant, Self> {
+                    match self {
+                        Self::$variant(geometry) => Ok(geometry),
+                        geometry => Err(geometry),
+                    }
+                }
+            )*
+        }
+
+        $(
+            impl<'a> TryFrom<&'a Geometry> for &'a $variant {
+                type Error = super::error::Error;
+
+                fn try_from(value: &'a Geometry) -> Result<Self, Self::Error> {
+                    value.$as_ref().ok_or_else(|| {
+                        super::error::Error::invalid_geometry_kind(GeometryKind::$variant, value.kind())
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_geometry_variants! {
+    Point => as_point, as_point_mut, into_point;
+    LineString => as_line_string, as_line_string_mut, into_line_string;
+    Polygon => as_polygon, as_polygon_mut, into_polygon;
+    MultiPoint => as_multi_point, as_multi_point_mut, into_multi_point;
+    MultiLineString => as_multi_line_string, as_multi_line_string_mut, into_multi_line_string;
+    MultiPolygon => as_multi_polygon, as_multi_polygon_mut, into_multi_polygon;
+    PointZ => as_point_z, as_point_z_mut, into_point_z;
+    LineStringZ => as_line_string_z, as_line_string_z_mut, into_line_string_z;
+    PolygonZ => as_polygon_z, as_polygon_z_mut, into_polygon_z;
+    MultiPointZ => as_multi_point_z, as_multi_point_z_mut, into_multi_point_z;
+    MultiLineStringZ => as_multi_line_string_z, as_multi_line_string_z_mut, into_multi_line_string_z;
+    MultiPolygonZ => as_multi_polygon_z, as_multi_polygon_z_mut, into_multi_polygon_z;
+}
+
+/// Géométrie empruntée ou possédée, renvoyée par les transformations
+/// qui peuvent laisser la géométrie inchangée.
+///
+/// Permet aux traitements qui ne modifient qu'une partie des géométries
+/// de ne pas cloner les autres.
+pub type CowGeometry<'a> = alloc::borrow::Cow<'a, Geometry>;
+
+/// Une géométrie est sérialisée en EWKT (`SRID=4326;POINT(1 2)`), quelles que soient
+/// les fonctionnalités actives, voir [wkt::serialize].
+///
+/// Une autre représentation se choisit champ par champ :
+/// `#[serde(with = "sql_gis::ewkb")]` ou `#[serde(with = "sql_gis::geojson")]`.
+impl serde::Serialize for Geometry {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        wkt::serialize(self, serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Geometry {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        wkt::deserialize(deserializer)
+    }
+}
+
+/// Référence empruntée vers une géométrie.
+///
+/// Permet d'encoder une géométrie typée sans la convertir (et donc la cloner)
+/// en [Geometry].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GeometryRef<'a> {
+    Point(&'a Point),
+    LineString(&'a LineString),
+    Polygon(&'a Polygon),
+    MultiPoint(&'a MultiPoint),
+    MultiLineString(&'a MultiLineString),
+    MultiPolygon(&'a MultiPolygon),
+
+    PointZ(&'a PointZ),
+    LineStringZ(&'a LineStringZ),
+    PolygonZ(&'a PolygonZ),
+    MultiPointZ(&'a MultiPointZ),
+    MultiLineStringZ(&'a MultiLineStringZ),
+    MultiPolygonZ(&'a MultiPolygonZ),
+}
+
+impl GeometryRef<'_> {
+    /// Hache la géométrie de façon cohérente avec son égalité : `0.0` et `-0.0`,
+    /// égaux, ont la même empreinte.
+    #[cfg(any(feature = "ewkb", feature = "spatialite-format", feature = "geojson"))]
+    pub(crate) fn hash_into<H: core::hash::Hasher>(&self, state: &mut H) {
+        use core::hash::Hash;
+
+        fn vector<const N: usize, H: core::hash::Hasher>(v: &Vector<N, f64>, state: &mut H) {
+            v.iter()
+                .for_each(|c| if *c == 0.0 { 0u64 } else { c.to_bits() }.hash(state))
+        }
+
+        fn array<const N: usize, H: core::hash::Hasher>(a: &[Vector<N, f64>], state: &mut H) {
+            a.len().hash(state);
+            a.iter().for_each(|v| vector(v, state))
+        }
+
+        fn matrix<const N: usize, H: core::hash::Hasher>(m: &[VectorArray<N, f64>], state: &mut H) {
+            m.len().hash(state);
+            m.iter().for_each(|a| array(a, state))
+        }
+
+        self.kind().hash(state);
+        self.srid().hash(state);
+        match self.borrow_coordinates() {
+            CoordinatesRef::Vector2D(v) => vector(v, state),
+            CoordinatesRef::VectorArray2D(a) => array(a, state),
+            CoordinatesRef::VectorMatrix2D(m) => matrix(m, state),
+            CoordinatesRef::VectorTensor2D(t) => {
+                t.len().hash(state);
+                t.iter().for_each(|m| matrix(m, state))
+            }
+            CoordinatesRef::Vector3D(v) => vector(v, state),
+            CoordinatesRef::VectorArray3D(a) => array(a, state),
+            CoordinatesRef::VectorMatrix3D(m) => matrix(m, state),
+            CoordinatesRef::VectorTensor3D(t) => {
+                t.len().hash(state);
+                t.iter().for_each(|m| matrix(m, state))
+            }
+        }
+    }
+
+    /// Emprunte les coordonnées d'une géométrie.
+    pub fn borrow_coordinates(&self) -> CoordinatesRef<'_> {
+        match self {
+            GeometryRef::Point(a) => CoordinatesRef::Vector2D(&a.coordinates),
+            GeometryRef::LineString(a) => CoordinatesRef::VectorArray2D(&a.coordinates),
+            GeometryRef::Polygon(a) => CoordinatesRef::VectorMatrix2D(&a.coordinates),
+            GeometryRef::MultiPoint(a) => CoordinatesRef::VectorArray2D(&a.coordinates),
+            GeometryRef::MultiLineString(a) => CoordinatesRef::VectorMatrix2D(&a.coordinates),
+            GeometryRef::MultiPolygon(a) => CoordinatesRef::VectorTensor2D(&a.coordinates),
+            GeometryRef::PointZ(a) => CoordinatesRef::Vector3D(&a.coordinates),
+            GeometryRef::LineStringZ(a) => CoordinatesRef::VectorArray3D(&a.coordinates),
+            GeometryRef::PolygonZ(a) => CoordinatesRef::VectorMatrix3D(&a.coordinates),
+            GeometryRef::MultiPointZ(a) => CoordinatesRef::VectorArray3D(&a.coordinates),
+            GeometryRef::MultiLineStringZ(a) => CoordinatesRef::VectorMatrix3D(&a.coordinates),
+            GeometryRef::MultiPolygonZ(a) => CoordinatesRef::VectorTensor3D(&a.coordinates),
+        }
+    }
+
+    pub fn kind(&self) -> GeometryKind {
+        match self {
+            GeometryRef::Point(_) => GeometryKind::Point,
+            GeometryRef::LineString(_) => GeometryKind::LineString,
+            GeometryRef::Polygon(_) => GeometryKind::Polygon,
+            GeometryRef::MultiPoint(_) => GeometryKind::MultiPoint,
+            GeometryRef::MultiLineString(_) => GeometryKind::MultiLineString,
+            GeometryRef::MultiPolygon(_) => GeometryKind::MultiPolygon,
+            GeometryRef::PointZ(_) => GeometryKind::PointZ,
+            GeometryRef::LineStringZ(_) => GeometryKind::LineStringZ,
+            GeometryRef::PolygonZ(_) => GeometryKind::PolygonZ,
+            GeometryRef::MultiPointZ(_) => GeometryKind::MultiPointZ,
+            GeometryRef::MultiLineStringZ(_) => GeometryKind::MultiLineStringZ,
+            GeometryRef::MultiPolygonZ(_) => GeometryKind::MultiPolygonZ,
+        }
+    }
+
+    /// Emprise planaire, `None` si la géométrie est vide ou n'a que des coordonnées NaN.
+    pub fn mbr(&self) -> Option<MBR2D> {
+        match self {
+            GeometryRef::Point(a) => a.mbr(),
+            GeometryRef::LineString(a) => a.mbr(),
+            GeometryRef::Polygon(a) => a.mbr(),
+            GeometryRef::MultiPoint(a) => a.mbr(),
+            GeometryRef::MultiLineString(a) => a.mbr(),
+            GeometryRef::MultiPolygon(a) => a.mbr(),
+            GeometryRef::PointZ(a) => a.mbr().map(|mbr| mbr.to_2d()),
+            GeometryRef::LineStringZ(a) => a.mbr().map(|mbr| mbr.to_2d()),
+            GeometryRef::PolygonZ(a) => a.mbr().map(|mbr| mbr.to_2d()),
+            GeometryRef::MultiPointZ(a) => a.mbr().map(|mbr| mbr.to_2d()),
+            GeometryRef::MultiLineStringZ(a) => a.mbr().map(|mbr| mbr.to_2d()),
+            GeometryRef::MultiPolygonZ(a) => a.mbr().map(|mbr| mbr.to_2d()),
+        }
+    }
+
+    /// Emprise en trois dimensions, `None` pour une géométrie 2D.
+    pub fn mbr_3d(&self) -> Option<MBR3D> {
+        match self {
+            GeometryRef::PointZ(a) => a.mbr(),
+            GeometryRef::LineStringZ(a) => a.mbr(),
+            GeometryRef::PolygonZ(a) => a.mbr(),
+            GeometryRef::MultiPointZ(a) => a.mbr(),
+            GeometryRef::MultiLineStringZ(a) => a.mbr(),
+            GeometryRef::MultiPolygonZ(a) => a.mbr(),
+            _ => None,
+        }
+    }
+
+    pub fn srid(&self) -> Option<u32> {
+        match self {
+            GeometryRef::Point(a) => a.srid,
+            GeometryRef::LineString(a) => a.srid,
+            GeometryRef::Polygon(a) => a.srid,
+            GeometryRef::MultiPoint(a) => a.srid,
+            GeometryRef::MultiLineString(a) => a.srid,
+            GeometryRef::MultiPolygon(a) => a.srid,
+            GeometryRef::PointZ(a) => a.srid,
+            GeometryRef::LineStringZ(a) => a.srid,
+            GeometryRef::PolygonZ(a) => a.srid,
+            GeometryRef::MultiPointZ(a) => a.srid,
+            GeometryRef::MultiLineStringZ(a) => a.srid,
+            GeometryRef::MultiPolygonZ(a) => a.srid,
+        }
+    }
+
+    /// Clone la géométrie empruntée.
+    pub fn to_geometry(&self) -> Geometry {
+        match *self {
+            GeometryRef::Point(a) => a.clone().into(),
+            GeometryRef::LineString(a) => a.clone().into(),
+            GeometryRef::Polygon(a) => a.clone().into(),
+            GeometryRef::MultiPoint(a) => a.clone().into(),
+            GeometryRef::MultiLineString(a) => a.clone().into(),
+            GeometryRef::MultiPolygon(a) => a.clone().into(),
+            GeometryRef::PointZ(a) => a.clone().into(),
+            GeometryRef::LineStringZ(a) => a.clone().into(),
+            GeometryRef::PolygonZ(a) => a.clone().into(),
+            GeometryRef::MultiPointZ(a) => a.clone().into(),
+            GeometryRef::MultiLineStringZ(a) => a.clone().into(),
+            GeometryRef::MultiPolygonZ(a) => a.clone().into(),
+        }
+    }
+}
+
+impl<'a> From<&'a Geometry> for GeometryRef<'a> {
+    fn from(value: &'a Geometry) -> Self {
+        match value {
+            Geometry::Point(a) => GeometryRef::Point(a),
+            Geometry::LineString(a) => GeometryRef::LineString(a),
+            Geometry::Polygon(a) => GeometryRef::Polygon(a),
+            Geometry::MultiPoint(a) => GeometryRef::MultiPoint(a),
+            Geometry::MultiLineString(a) => GeometryRef::MultiLineString(a),
+            Geometry::MultiPolygon(a) => GeometryRef::MultiPolygon(a),
+            Geometry::PointZ(a) => GeometryRef::PointZ(a),
+            Geometry::LineStringZ(a) => GeometryRef::LineStringZ(a),
+            Geometry::PolygonZ(a) => GeometryRef::PolygonZ(a),
+            Geometry::MultiPointZ(a) => GeometryRef::MultiPointZ(a),
+            Geometry::MultiLineStringZ(a) => GeometryRef::MultiLineStringZ(a),
+            Geometry::MultiPolygonZ(a) => GeometryRef::MultiPolygonZ(a),
+        }
+    }
+}
+
+impl<'a> From<&'a Point> for GeometryRef<'a> {
+    fn from(value: &'a Point) -> Self {
+        Self::Point(value)
+    }
+}
+
+impl<'a> From<&'a LineString> for GeometryRef<'a> {
+    fn from(value: &'a LineString) -> Self {
+        Self::LineString(value)
+    }
+}
+
+impl<'a> From<&'a Polygon> for GeometryRef<'a> {
+    fn from(value: &'a Polygon) -> Self {
+        Self::Polygon(value)
+    }
+}
+
+impl<'a> From<&'a MultiPoint> for GeometryRef<'a> {
+    fn from(value: &'a MultiPoint) -> Self {
+        Self::MultiPoint(value)
+    }
+}
+
+impl<'a> From<&'a MultiLineString> for GeometryRef<'a> {
+    fn from(value: &'a MultiLineString) -> Self {
+        Self::MultiLineString(value)
+    }
+}
+
+impl<'a> From<&'a MultiPolygon> for GeometryRef<'a> {
+    fn from(value: &'a MultiPolygon) -> Self {
+        Self::MultiPolygon(value)
+    }
+}
+
+impl<'a> From<&'a PointZ> for GeometryRef<'a> {
+    fn from(value: &'a PointZ) -> Self {
+        Self::PointZ(value)
+    }
+}
+
+impl<'a> From<&'a LineStringZ> for GeometryRef<'a> {
+    fn from(value: &'a LineStringZ) -> Self {
+        Self::LineStringZ(value)
+    }
+}
+
+impl<'a> From<&'a PolygonZ> for GeometryRef<'a> {
+    fn from(value: &'a PolygonZ) -> Self {
+        Self::PolygonZ(value)
+    }
+}
+
+impl<'a> From<&'a MultiPointZ> for GeometryRef<'a> {
+    fn from(value: &'a MultiPointZ) -> Self {
+        Self::MultiPointZ(value)
+    }
+}
+
+impl<'a> From<&'a MultiLineStringZ> for GeometryRef<'a> {
+    fn from(value: &'a MultiLineStringZ) -> Self {
+        Self::MultiLineStringZ(value)
+    }
+}
+
+impl<'a> From<&'a MultiPolygonZ> for GeometryRef<'a> {
+    fn from(value: &'a MultiPolygonZ) -> Self {
+        Self::MultiPolygonZ(value)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord)]
 /// Représente la classe de la géométrie.
 pub enum GeometryKind {
     /// 2D point
@@ -210,8 +666,8 @@ impl AsRef<str> for GeometryKind {
         }
     }
 }
-impl std::fmt::Display for GeometryKind {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for GeometryKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let kind_str = self.as_ref();
         write!(f, "{kind_str}")
     }
@@ -483,191 +939,297 @@ pub enum CoordinatesMutRef<'a> {
     VectorTensor3D(&'a mut VectorTensor<3, f64>),
 }
 
-impl<'a> TryFrom<CoordinatesMutRef<'a>> for &'a mut Vector<2, f64> {
-    type Error = crate::error::Error;
+/// Coordonnées empruntées d'une géométrie.
+pub enum CoordinatesRef<'a> {
+    Vector2D(&'a Vector<2, f64>),
+    VectorArray2D(&'a VectorArray<2, f64>),
+    VectorMatrix2D(&'a VectorMatrix<2, f64>),
+    VectorTensor2D(&'a VectorTensor<2, f64>),
 
-    fn try_from(value: CoordinatesMutRef<'a>) -> Result<Self, Self::Error> {
-        match value {
-            CoordinatesMutRef::Vector2D(a) => Ok(a),
-            _ => panic!("not a 2D vector"),
-        }
-    }
+    Vector3D(&'a Vector<3, f64>),
+    VectorArray3D(&'a VectorArray<3, f64>),
+    VectorMatrix3D(&'a VectorMatrix<3, f64>),
+    VectorTensor3D(&'a VectorTensor<3, f64>),
 }
 
-impl<'a> TryFrom<CoordinatesMutRef<'a>> for &'a mut VectorArray<2, f64> {
-    type Error = crate::error::Error;
-
-    fn try_from(value: CoordinatesMutRef<'a>) -> Result<Self, Self::Error> {
-        match value {
-            CoordinatesMutRef::VectorArray2D(a) => Ok(a),
-            _ => panic!("not an array of 2D vectors"),
-        }
-    }
+/// Forme des coordonnées d'une géométrie.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum CoordinatesKind {
+    Vector2D,
+    VectorArray2D,
+    VectorMatrix2D,
+    VectorTensor2D,
+
+    Vector3D,
+    VectorArray3D,
+    VectorMatrix3D,
+    VectorTensor3D,
 }
 
-impl<'a> TryFrom<CoordinatesMutRef<'a>> for &'a mut VectorMatrix<2, f64> {
-    type Error = crate::error::Error;
-
-    fn try_from(value: CoordinatesMutRef<'a>) -> Result<Self, Self::Error> {
-        match value {
-            CoordinatesMutRef::VectorMatrix2D(a) => Ok(a),
-            _ => panic!("not a matrix of 2D vectors"),
-        }
+impl core::fmt::Display for CoordinatesKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let kind_str = match self {
+            Self::Vector2D => "2D vector",
+            Self::VectorArray2D => "array of 2D vectors",
+            Self::VectorMatrix2D => "matrix of 2D vectors",
+            Self::VectorTensor2D => "tensor of 2D vectors",
+            Self::Vector3D => "3D vector",
+            Self::VectorArray3D => "array of 3D vectors",
+            Self::VectorMatrix3D => "matrix of 3D vectors",
+            Self::VectorTensor3D => "tensor of 3D vectors",
+        };
+        write!(f, "{kind_str}")
     }
 }
 
-impl<'a> TryFrom<CoordinatesMutRef<'a>> for &'a mut VectorTensor<2, f64> {
-    type Error = crate::error::Error;
-
-    fn try_from(value: CoordinatesMutRef<'a>) -> Result<Self, Self::Error> {
-        match value {
-            CoordinatesMutRef::VectorTensor2D(a) => Ok(a),
-            _ => panic!("not a matrix of 2D vectors"),
+/// Implémente les accesseurs et conversions de chaque forme de coordonnées.
+macro_rules! impl_coordinates_variants {
+    ($($variant:ident($ty:ty) => $as_ref:ident, $as_mut:ident;)*) => {
+        impl Coordinates {
+            pub fn kind(&self) -> CoordinatesKind {
+                match self {
+                    $(Self::$variant(_) => CoordinatesKind::$variant,)*
+                }
+            }
         }
-    }
-}
-
-impl<'a> TryFrom<CoordinatesMutRef<'a>> for &'a mut Vector<3, f64> {
-    type Error = crate::error::Error;
 
-    fn try_from(value: CoordinatesMutRef<'a>) -> Result<Self, Self::Error> {
-        match value {
-            CoordinatesMutRef::Vector3D(a) => Ok(a),
-            _ => panic!("not a 3D vector"),
+        impl<'a> CoordinatesRef<'a> {
+            pub fn kind(&self) -> CoordinatesKind {
+                match self {
+                    $(Self::$variant(_) => CoordinatesKind::$variant,)*
+                }
+            }
+
+            $(
+                #[doc = concat!("Coordonnées de forme `", stringify!($variant), "`, le cas échéant.")]
+                pub fn $as_ref(&self) -> Option<&'a $ty> {
+                    match self {
+                        Self::$variant(coordinates) => Some(*coordinates),
+                        _ => None,
+                    }
+                }
+            )*
         }
-    }
-}
 
-impl<'a> TryFrom<CoordinatesMutRef<'a>> for &'a mut VectorArray<3, f64> {
-    type Error = crate::error::Error;
-
-    fn try_from(value: CoordinatesMutRef<'a>) -> Result<Self, Self::Error> {
-        match value {
-            CoordinatesMutRef::VectorArray3D(a) => Ok(a),
-            _ => panic!("not an array of 3D vectors"),
+        impl CoordinatesMutRef<'_> {
+            pub fn kind(&self) -> CoordinatesKind {
+                match self {
+                    $(Self::$variant(_) => CoordinatesKind::$variant,)*
+                }
+            }
+
+            $(
+                #[doc = concat!("Coordonnées de forme `", stringify!($variant), "`, le cas échéant.")]
+                pub fn $as_mut(&mut self) -> Option<&mut $ty> {
+                    match self {
+                        Self::$variant(coordinates) => Some(coordinates),
+                        _ => None,
+                    }
+                }
+            )*
         }
-    }
-}
-
-impl<'a> TryFrom<CoordinatesMutRef<'a>> for &'a mut VectorMatrix<3, f64> {
-    type Error = crate::error::Error;
 
-    fn try_from(value: CoordinatesMutRef<'a>) -> Result<Self, Self::Error> {
-        match value {
-            CoordinatesMutRef::VectorMatrix3D(a) => Ok(a),
-            _ => panic!("not a matrix of 3D vectors"),
-        }
-    }
+        $(
+            impl<'a> TryFrom<CoordinatesRef<'a>> for &'a $ty {
+                type Error = crate::error::Error;
+
+                fn try_from(value: CoordinatesRef<'a>) -> Result<Self, Self::Error> {
+                    value.$as_ref().ok_or_else(|| {
+                        crate::error::Error::invalid_coordinates_kind(
+                            CoordinatesKind::$variant,
+                            value.kind(),
+                        )
+                    })
+                }
+            }
+
+            impl<'a> TryFrom<CoordinatesMutRef<'a>> for &'a mut $ty {
+                type Error = crate::error::Error;
+
+                fn try_from(value: CoordinatesMutRef<'a>) -> Result<Self, Self::Error> {
+                    match value {
+                        CoordinatesMutRef::$variant(coordinates) => Ok(coordinates),
+                        value => Err(crate::error::Error::invalid_coordinates_kind(
+                            CoordinatesKind::$variant,
+                            value.kind(),
+                        )),
+                    }
+                }
+            }
+        )*
+    };
 }
 
-impl<'a> TryFrom<CoordinatesMutRef<'a>> for &'a mut VectorTensor<3, f64> {
-    type Error = crate::error::Error;
-
-    fn try_from(value: CoordinatesMutRef<'a>) -> Result<Self, Self::Error> {
-        match value {
-            CoordinatesMutRef::VectorTensor3D(a) => Ok(a),
-            _ => panic!("not a matrix of 3D vectors"),
-        }
-    }
+impl_coordinates_variants! {
+    Vector2D(Vector<2, f64>) => as_vector_2d, as_vector_2d_mut;
+    VectorArray2D(VectorArray<2, f64>) => as_vector_array_2d, as_vector_array_2d_mut;
+    VectorMatrix2D(VectorMatrix<2, f64>) => as_vector_matrix_2d, as_vector_matrix_2d_mut;
+    VectorTensor2D(VectorTensor<2, f64>) => as_vector_tensor_2d, as_vector_tensor_2d_mut;
+    Vector3D(Vector<3, f64>) => as_vector_3d, as_vector_3d_mut;
+    VectorArray3D(VectorArray<3, f64>) => as_vector_array_3d, as_vector_array_3d_mut;
+    VectorMatrix3D(VectorMatrix<3, f64>) => as_vector_matrix_3d, as_vector_matrix_3d_mut;
+    VectorTensor3D(VectorTensor<3, f64>) => as_vector_tensor_3d, as_vector_tensor_3d_mut;
 }
 
-/// Coordonnées empruntées d'une géométrie.
-pub enum CoordinatesRef<'a> {
-    Vector2D(&'a Vector<2, f64>),
-    VectorArray2D(&'a VectorArray<2, f64>),
-    VectorMatrix2D(&'a VectorMatrix<2, f64>),
-    VectorTensor2D(&'a VectorTensor<2, f64>),
-
-    Vector3D(&'a Vector<3, f64>),
-    VectorArray3D(&'a VectorArray<3, f64>),
-    VectorMatrix3D(&'a VectorMatrix<3, f64>),
-    VectorTensor3D(&'a VectorTensor<3, f64>),
-}
-
-impl<'a> TryFrom<CoordinatesRef<'a>> for &'a Vector<2, f64> {
-    type Error = crate::error::Error;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+
+    #[test]
+    fn test_coordinates_conversion_mismatch_is_an_error() {
+        let geometry = Geometry::from(Point::new([1.0, 2.0]));
+
+        let vector = <&Vector<2, f64>>::try_from(geometry.borrow_coordinates())
+            .expect("cannot borrow the point coordinates");
+        assert_eq!(vector.x(), 1.0);
+
+        assert!(matches!(
+            <&VectorArray<2, f64>>::try_from(geometry.borrow_coordinates()),
+            Err(Error::InvalidCoordinatesKind {
+                expecting: CoordinatesKind::VectorArray2D,
+                got: CoordinatesKind::Vector2D
+            })
+        ));
+
+        assert!(geometry.borrow_coordinates().as_vector_2d().is_some());
+        assert!(geometry.borrow_coordinates().as_vector_3d().is_none());
+    }
 
-    fn try_from(value: CoordinatesRef<'a>) -> Result<Self, Self::Error> {
-        match value {
-            CoordinatesRef::Vector2D(a) => Ok(a),
-            _ => panic!("not a 2D vector"),
-        }
+    #[test]
+    fn test_geometry_accessors() {
+        let mut geometry = Geometry::from(Point::new([1.0, 2.0]));
+
+        assert_eq!(geometry.as_point().map(|point| point.x()), Some(1.0));
+        assert!(geometry.as_point_z().is_none());
+
+        geometry.as_point_mut().expect("not a point").srid = Some(4326);
+        assert_eq!(geometry.srid(), Some(4326));
+
+        let point: &Point = (&geometry).try_into().expect("cannot borrow the point");
+        assert_eq!(point.y(), 2.0);
+        assert!(matches!(
+            <&Polygon>::try_from(&geometry),
+            Err(Error::InvalidGeometryKind {
+                expecting: GeometryKind::Polygon,
+                got: GeometryKind::Point
+            })
+        ));
     }
-}
 
-impl<'a> TryFrom<CoordinatesRef<'a>> for &'a VectorArray<2, f64> {
-    type Error = crate::error::Error;
+    #[test]
+    fn test_geometry_into() {
+        let geometry = Geometry::from(Point::new([1.0, 2.0]));
 
-    fn try_from(value: CoordinatesRef<'a>) -> Result<Self, Self::Error> {
-        match value {
-            CoordinatesRef::VectorArray2D(a) => Ok(a),
-            _ => panic!("not an array of 2D vectors"),
-        }
+        let geometry = geometry.into_polygon().expect_err("not a polygon");
+        assert_eq!(geometry.kind(), GeometryKind::Point);
+        assert_eq!(geometry.into_point(), Ok(Point::new([1.0, 2.0])));
     }
-}
 
-impl<'a> TryFrom<CoordinatesRef<'a>> for &'a VectorMatrix<2, f64> {
-    type Error = crate::error::Error;
+    #[test]
+    fn test_coordinates_mut_accessor() {
+        let mut vector = Vector::new([1.0, 2.0]);
+        let mut coordinates = CoordinatesMutRef::Vector2D(&mut vector);
 
-    fn try_from(value: CoordinatesRef<'a>) -> Result<Self, Self::Error> {
-        match value {
-            CoordinatesRef::VectorMatrix2D(a) => Ok(a),
-            _ => panic!("not a matrix of 2D vectors"),
-        }
+        *coordinates.as_vector_2d_mut().expect("not a 2D vector") = Vector::new([3.0, 4.0]);
+        assert!(coordinates.as_vector_array_2d_mut().is_none());
+        assert_eq!(vector.x(), 3.0);
     }
-}
 
-impl<'a> TryFrom<CoordinatesRef<'a>> for &'a VectorTensor<2, f64> {
-    type Error = crate::error::Error;
+    #[cfg(feature = "ewkb")]
+    #[test]
+    fn test_geometry_ref() {
+        let point = Point::new([1.0, 2.0]);
+        let geometry = Geometry::from(point.clone());
 
-    fn try_from(value: CoordinatesRef<'a>) -> Result<Self, Self::Error> {
-        match value {
-            CoordinatesRef::VectorTensor2D(a) => Ok(a),
-            _ => panic!("not a matrix of 2D vectors"),
-        }
-    }
-}
+        assert_eq!(GeometryRef::from(&point), geometry.as_geometry_ref());
+        assert_eq!(geometry.as_geometry_ref().to_geometry(), geometry);
 
-impl<'a> TryFrom<CoordinatesRef<'a>> for &'a Vector<3, f64> {
-    type Error = crate::error::Error;
+        let mut from_typed = Vec::default();
+        let mut from_owned = Vec::default();
+        crate::ewkb::encode_geometry(&point, &mut from_typed).expect("cannot encode point");
+        crate::ewkb::encode_geometry(&geometry, &mut from_owned).expect("cannot encode geometry");
+        assert_eq!(from_typed, from_owned);
+    }
 
-    fn try_from(value: CoordinatesRef<'a>) -> Result<Self, Self::Error> {
-        match value {
-            CoordinatesRef::Vector3D(a) => Ok(a),
-            _ => panic!("not a 3D vector"),
+    #[cfg(all(feature = "geojson", feature = "ewkb"))]
+    #[test]
+    fn test_serde_representation() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Feature {
+            #[serde(with = "crate::geojson")]
+            geometry: Geometry,
+            #[serde(with = "crate::ewkb")]
+            footprint: Geometry,
+            label: Geometry,
         }
-    }
-}
 
-impl<'a> TryFrom<CoordinatesRef<'a>> for &'a VectorArray<3, f64> {
-    type Error = crate::error::Error;
+        let mut footprint = Point::new([1.0, 2.0]);
+        footprint.srid = Some(4326);
 
-    fn try_from(value: CoordinatesRef<'a>) -> Result<Self, Self::Error> {
-        match value {
-            CoordinatesRef::VectorArray3D(a) => Ok(a),
-            _ => panic!("not an array of 3D vectors"),
-        }
-    }
-}
+        let feature = Feature {
+            geometry: Point::new([1.0, 2.0]).into(),
+            footprint: footprint.into(),
+            label: Point::new([1.0, 2.0]).into(),
+        };
 
-impl<'a> TryFrom<CoordinatesRef<'a>> for &'a VectorMatrix<3, f64> {
-    type Error = crate::error::Error;
+        let encoded = serde_json::to_value(&feature).expect("cannot serialize feature");
+        assert_eq!(encoded["geometry"]["type"], "Point");
+        assert!(encoded["footprint"].is_string());
+        assert_eq!(encoded["label"], "POINT(1 2)");
 
-    fn try_from(value: CoordinatesRef<'a>) -> Result<Self, Self::Error> {
-        match value {
-            CoordinatesRef::VectorMatrix3D(a) => Ok(a),
-            _ => panic!("not a matrix of 3D vectors"),
-        }
+        let decoded = serde_json::from_value::<Feature>(encoded).expect("cannot deserialize");
+        assert_eq!(decoded, feature);
     }
-}
 
-impl<'a> TryFrom<CoordinatesRef<'a>> for &'a VectorTensor<3, f64> {
-    type Error = crate::error::Error;
+    #[test]
+    fn test_heap_size() {
+        assert_eq!(Geometry::from(Point::new([1.0, 2.0])).heap_size(), 0);
+
+        // Un anneau court est stocké en ligne.
+        let small = Geometry::from(LineString::new([[0.0, 0.0], [1.0, 1.0]]));
+        assert_eq!(small.heap_size(), 0);
+
+        let points = (0..100)
+            .map(|i| [i as f64, 0.0])
+            .collect::<VectorArray<2, f64>>();
+        let large = Geometry::from(LineString::new(points));
+        assert!(large.heap_size() >= 100 * core::mem::size_of::<Vector<2, f64>>());
+
+        let polygon = Geometry::from(Polygon::new([[0.0, 0.0], [1.0, 0.0], [1.0, 1.0]]));
+        assert_eq!(
+            polygon.heap_size(),
+            core::mem::size_of::<VectorArray<2, f64>>()
+        );
+    }
 
-    fn try_from(value: CoordinatesRef<'a>) -> Result<Self, Self::Error> {
-        match value {
-            CoordinatesRef::VectorTensor3D(a) => Ok(a),
-            _ => panic!("not a matrix of 3D vectors"),
-        }
+    #[test]
+    fn test_standard_traits() {
+        assert_eq!(
+            LineString::default(),
+            LineString::new(VectorArray2D::new(vec![]))
+        );
+        assert_eq!(Geometry::from(MultiPolygonZ::default()).srid(), None);
+        assert!(GeometryKind::Point < GeometryKind::PointZ);
+
+        let kinds = std::collections::BTreeSet::from([
+            GeometryKind::PolygonZ,
+            GeometryKind::Point,
+            GeometryKind::Point,
+        ]);
+        assert_eq!(
+            kinds.into_iter().collect::<Vec<_>>(),
+            [GeometryKind::Point, GeometryKind::PolygonZ]
+        );
+
+        let points = std::collections::HashSet::from([
+            point::Point::<2, i32>::new([1, 2]),
+            point::Point::<2, i32>::new([1, 2]),
+        ]);
+        assert_eq!(points.len(), 1);
+
+        let mbr = MBR2D::new([0.0, 0.0], [1.0, 1.0]);
+        let copy = mbr;
+        assert_eq!(copy, mbr);
     }
 }
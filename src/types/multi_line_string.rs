@@ -1,15 +1,25 @@
-use super::{GeometryImpl, VectorMatrix, MBR};
+use super::{GeometryImpl, Scalar, VectorMatrix, MBR};
 
 pub type MultiLineStringCoordinates<const N: usize, U> = VectorMatrix<N, U>;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 /// Un ensemble de lignes brisées.
 pub struct MultiLineString<const N: usize, U> {
     pub coordinates: MultiLineStringCoordinates<N, U>,
     pub srid: Option<u32>,
 }
 
-impl<const N: usize, U>  GeometryImpl for MultiLineString<N, U> {
+/// Géométrie vide, sans SRID.
+impl<const N: usize, U> Default for MultiLineString<N, U> {
+    fn default() -> Self {
+        Self {
+            coordinates: Default::default(),
+            srid: None,
+        }
+    }
+}
+
+impl<const N: usize, U> GeometryImpl for MultiLineString<N, U> {
     type Coordinates = MultiLineStringCoordinates<N, U>;
 
     fn new<C: Into<Self::Coordinates>>(coordinates: C) -> Self {
@@ -22,14 +32,10 @@ impl<const N: usize, U>  GeometryImpl for MultiLineString<N, U> {
 
 impl<const N: usize, U> MultiLineString<N, U>
 where
-    U: Copy + PartialOrd,
+    U: Scalar,
 {
-    pub fn mbr(&self) -> MBR<U> {
-        MBR {
-            min_x: self.coordinates.min_x(),
-            max_x: self.coordinates.max_x(),
-            min_y: self.coordinates.min_y(),
-            max_y: self.coordinates.max_y(),
-        }
+    /// Rectangle englobant, `None` si la géométrie n'a aucune coordonnée exploitable.
+    pub fn mbr(&self) -> Option<MBR<N, U>> {
+        self.coordinates.mbr()
     }
 }
@@ -1,15 +1,25 @@
-use super::{GeometryImpl, VectorArray, MBR};
+use super::{GeometryImpl, Scalar, VectorArray, MBR};
 
 pub type MultiPointCoordinates<const N: usize, U> = VectorArray<N, U>;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 /// Un ensemble de point non relié dans un espace 2D.
 pub struct MultiPoint<const N: usize, U> {
     pub coordinates: MultiPointCoordinates<N, U>,
     pub srid: Option<u32>,
 }
 
-impl<const N: usize, U>  GeometryImpl for MultiPoint<N, U> {
+/// Géométrie vide, sans SRID.
+impl<const N: usize, U> Default for MultiPoint<N, U> {
+    fn default() -> Self {
+        Self {
+            coordinates: Default::default(),
+            srid: None,
+        }
+    }
+}
+
+impl<const N: usize, U> GeometryImpl for MultiPoint<N, U> {
     type Coordinates = MultiPointCoordinates<N, U>;
 
     fn new<C: Into<Self::Coordinates>>(coordinates: C) -> Self {
@@ -22,14 +32,30 @@ impl<const N: usize, U>  GeometryImpl for MultiPoint<N, U> {
 
 impl<const N: usize, U> MultiPoint<N, U>
 where
-    U: Copy + PartialOrd,
+    U: Scalar,
 {
-    pub fn mbr(&self) -> MBR<U> {
-        MBR {
-            min_x: self.coordinates.min_x(),
-            max_x: self.coordinates.max_x(),
-            min_y: self.coordinates.min_y(),
-            max_y: self.coordinates.max_y(),
-        }
+    /// Rectangle englobant, `None` si la géométrie n'a aucune coordonnée exploitable.
+    pub fn mbr(&self) -> Option<MBR<N, U>> {
+        self.coordinates.mbr()
     }
-}
\ No newline at end of file
+}
+
+impl<U> FromIterator<(U, U)> for MultiPoint<2, U> {
+    fn from_iter<T: IntoIterator<Item = (U, U)>>(iter: T) -> Self {
+        Self::new(
+            iter.into_iter()
+                .map(|(x, y)| [x, y])
+                .collect::<VectorArray<2, U>>(),
+        )
+    }
+}
+
+impl<U> FromIterator<(U, U, U)> for MultiPoint<3, U> {
+    fn from_iter<T: IntoIterator<Item = (U, U, U)>>(iter: T) -> Self {
+        Self::new(
+            iter.into_iter()
+                .map(|(x, y, z)| [x, y, z])
+                .collect::<VectorArray<3, U>>(),
+        )
+    }
+}
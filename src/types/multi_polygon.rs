@@ -1,15 +1,25 @@
-use super::{GeometryImpl, VectorTensor, MBR};
+use super::{GeometryImpl, Scalar, VectorTensor, MBR};
 
 pub type MultiPolygonCoordinates<const N: usize, U> = VectorTensor<N, U>;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 /// Un ensemble de polygones
 pub struct MultiPolygon<const N: usize, U> {
     pub coordinates: MultiPolygonCoordinates<N, U>,
     pub srid: Option<u32>,
 }
 
-impl<const N: usize, U>  GeometryImpl for MultiPolygon<N, U> {
+/// Géométrie vide, sans SRID.
+impl<const N: usize, U> Default for MultiPolygon<N, U> {
+    fn default() -> Self {
+        Self {
+            coordinates: Default::default(),
+            srid: None,
+        }
+    }
+}
+
+impl<const N: usize, U> GeometryImpl for MultiPolygon<N, U> {
     type Coordinates = MultiPolygonCoordinates<N, U>;
 
     fn new<C: Into<Self::Coordinates>>(coordinates: C) -> Self {
@@ -22,15 +32,10 @@ impl<const N: usize, U>  GeometryImpl for MultiPolygon<N, U> {
 
 impl<const N: usize, U> MultiPolygon<N, U>
 where
-    U: Copy + PartialOrd,
+    U: Scalar,
 {
-    pub fn mbr(&self) -> MBR<U> {
-        MBR {
-            min_x: self.coordinates.min_x(),
-            max_x: self.coordinates.max_x(),
-            min_y: self.coordinates.min_y(),
-            max_y: self.coordinates.max_y(),
-        }
+    /// Rectangle englobant, `None` si la géométrie n'a aucune coordonnée exploitable.
+    pub fn mbr(&self) -> Option<MBR<N, U>> {
+        self.coordinates.mbr()
     }
 }
-
@@ -0,0 +1,216 @@
+//! Sens de parcours des anneaux des polygones.
+use super::{
+    multi_polygon::MultiPolygon, polygon::Polygon, Geometry, GeometryRef, Scalar, VectorArray,
+    VectorMatrix,
+};
+
+/// Sens de parcours imposé aux anneaux d'un polygone.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum RingOrientation {
+    /// Anneau extérieur dans le sens direct, trous dans le sens horaire.
+    CounterClockwise,
+    /// Anneau extérieur dans le sens horaire, trous dans le sens direct (ESRI).
+    Clockwise,
+    /// Anneaux laissés tels quels, dans la convention de la base de données.
+    #[default]
+    Native,
+}
+
+impl RingOrientation {
+    /// Convention OGC Simple Features.
+    pub const OGC: Self = Self::CounterClockwise;
+    /// Convention GeoJSON (RFC 7946, section 3.1.6).
+    pub const RFC7946: Self = Self::CounterClockwise;
+
+    /// Vrai si l'anneau d'indice `ring` doit tourner dans le sens direct, `None`
+    /// si aucun sens n'est imposé.
+    fn is_counter_clockwise(self, ring: usize) -> Option<bool> {
+        match self {
+            Self::CounterClockwise => Some(ring == 0),
+            Self::Clockwise => Some(ring != 0),
+            Self::Native => None,
+        }
+    }
+}
+
+/// Sens de parcours d'un anneau fermé dans le plan XY, `None` si son aire est nulle.
+fn is_counter_clockwise<const N: usize, U: Scalar>(ring: &VectorArray<N, U>) -> Option<bool> {
+    let area = ring
+        .iter()
+        .zip(ring.iter().skip(1))
+        .fold(U::zero(), |area, (a, b)| {
+            area + a.x() * b.y() - b.x() * a.y()
+        });
+
+    (area != U::zero()).then(|| area > U::zero())
+}
+
+/// Vrai si l'anneau d'indice `index` tourne dans le sens imposé.
+///
+/// Un anneau d'aire nulle n'a pas de sens, il est toujours accepté.
+pub(crate) fn is_ring_oriented<const N: usize, U: Scalar>(
+    ring: &VectorArray<N, U>,
+    index: usize,
+    orientation: RingOrientation,
+) -> bool {
+    match (
+        orientation.is_counter_clockwise(index),
+        is_counter_clockwise(ring),
+    ) {
+        (Some(expected), Some(got)) => expected == got,
+        _ => true,
+    }
+}
+
+fn are_rings_oriented<const N: usize, U: Scalar>(
+    rings: &VectorMatrix<N, U>,
+    orientation: RingOrientation,
+) -> bool {
+    rings
+        .iter()
+        .enumerate()
+        .all(|(i, ring)| is_ring_oriented(ring, i, orientation))
+}
+
+fn orient_rings<const N: usize, U: Scalar>(
+    rings: &mut VectorMatrix<N, U>,
+    orientation: RingOrientation,
+) {
+    rings
+        .iter_mut()
+        .enumerate()
+        .filter(|(i, ring)| !is_ring_oriented(ring, *i, orientation))
+        .for_each(|(_, ring)| ring.reverse());
+}
+
+impl<const N: usize, U: Scalar> Polygon<N, U> {
+    /// Vrai si tous les anneaux tournent dans le sens imposé.
+    pub fn is_oriented(&self, orientation: RingOrientation) -> bool {
+        are_rings_oriented(&self.coordinates, orientation)
+    }
+
+    /// Inverse les anneaux qui ne tournent pas dans le sens imposé.
+    pub fn orient(&mut self, orientation: RingOrientation) {
+        orient_rings(&mut self.coordinates, orientation)
+    }
+
+    /// Voir [Polygon::orient].
+    pub fn oriented(mut self, orientation: RingOrientation) -> Self {
+        self.orient(orientation);
+        self
+    }
+}
+
+impl<const N: usize, U: Scalar> MultiPolygon<N, U> {
+    /// Vrai si tous les anneaux de tous les polygones tournent dans le sens imposé.
+    pub fn is_oriented(&self, orientation: RingOrientation) -> bool {
+        self.coordinates
+            .iter()
+            .all(|rings| are_rings_oriented(rings, orientation))
+    }
+
+    /// Inverse les anneaux qui ne tournent pas dans le sens imposé.
+    pub fn orient(&mut self, orientation: RingOrientation) {
+        self.coordinates
+            .iter_mut()
+            .for_each(|rings| orient_rings(rings, orientation))
+    }
+
+    /// Voir [MultiPolygon::orient].
+    pub fn oriented(mut self, orientation: RingOrientation) -> Self {
+        self.orient(orientation);
+        self
+    }
+}
+
+impl Geometry {
+    /// Inverse les anneaux des polygones qui ne tournent pas dans le sens imposé ;
+    /// les autres géométries sont laissées telles quelles.
+    pub fn orient(&mut self, orientation: RingOrientation) {
+        match self {
+            Geometry::Polygon(a) => a.orient(orientation),
+            Geometry::PolygonZ(a) => a.orient(orientation),
+            Geometry::MultiPolygon(a) => a.orient(orientation),
+            Geometry::MultiPolygonZ(a) => a.orient(orientation),
+            _ => {}
+        }
+    }
+
+    /// Géométrie dont les anneaux tournent dans le sens imposé, par exemple avant
+    /// de la lier dans une requête : les liaisons écrivent les anneaux tels quels.
+    pub fn oriented(mut self, orientation: RingOrientation) -> Self {
+        self.orient(orientation);
+        self
+    }
+
+    /// Voir [GeometryRef::is_oriented].
+    pub fn is_oriented(&self, orientation: RingOrientation) -> bool {
+        self.as_geometry_ref().is_oriented(orientation)
+    }
+}
+
+impl GeometryRef<'_> {
+    /// Vrai si les anneaux des polygones tournent dans le sens imposé, toujours
+    /// vrai pour les autres géométries.
+    pub fn is_oriented(&self, orientation: RingOrientation) -> bool {
+        match self {
+            GeometryRef::Polygon(a) => a.is_oriented(orientation),
+            GeometryRef::PolygonZ(a) => a.is_oriented(orientation),
+            GeometryRef::MultiPolygon(a) => a.is_oriented(orientation),
+            GeometryRef::MultiPolygonZ(a) => a.is_oriented(orientation),
+            _ => true,
+        }
+    }
+
+    /// Copie de la géométrie dont les anneaux tournent dans le sens imposé, `None`
+    /// si elle y est déjà et peut être utilisée sans copie.
+    #[cfg(any(feature = "ewkb", feature = "spatialite-format"))]
+    pub(crate) fn to_oriented(self, orientation: RingOrientation) -> Option<Geometry> {
+        (!self.is_oriented(orientation)).then(|| {
+            let mut oriented = self.to_geometry();
+            oriented.orient(orientation);
+            oriented
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{GeometryImpl as _, MultiPolygon, Polygon};
+
+    #[test]
+    fn test_orient() {
+        let shell = [
+            [0.0, 0.0],
+            [0.0, 10.0],
+            [10.0, 10.0],
+            [10.0, 0.0],
+            [0.0, 0.0],
+        ];
+        let hole = [[4.0, 4.0], [6.0, 4.0], [6.0, 6.0], [4.0, 6.0], [4.0, 4.0]];
+
+        let mut polygon = Polygon::new((shell, hole));
+        assert!(polygon.is_oriented(RingOrientation::Clockwise));
+        assert!(polygon.is_oriented(RingOrientation::Native));
+        assert!(!polygon.is_oriented(RingOrientation::OGC));
+
+        polygon.orient(RingOrientation::RFC7946);
+        assert!(polygon.is_oriented(RingOrientation::CounterClockwise));
+        assert_eq!(polygon.coordinates[0][1].y(), 0.0);
+        assert_eq!(polygon.coordinates[1][1].y(), 6.0);
+
+        let mut geometry =
+            Geometry::from(MultiPolygon::new(crate::types::VectorTensor2D::new(vec![
+                Polygon::new((shell, hole)).coordinates,
+            ])));
+        assert!(!geometry.is_oriented(RingOrientation::OGC));
+        geometry.orient(RingOrientation::OGC);
+        assert!(geometry.is_oriented(RingOrientation::OGC));
+
+        // Un anneau dégénéré n'a pas de sens de parcours.
+        let flat = Polygon::new([[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]]);
+        assert!(flat.is_oriented(RingOrientation::CounterClockwise));
+        assert!(flat.is_oriented(RingOrientation::Clockwise));
+    }
+}
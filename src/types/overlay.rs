@@ -0,0 +1,439 @@
+//! Opérations booléennes entre surfaces (union, intersection, différence).
+//!
+//! Les arêtes des deux opérandes sont découpées à leurs intersections, puis chaque
+//! morceau est conservé ou non selon sa position par rapport à l'autre opérande.
+//! Les morceaux retenus sont enfin chaînés en anneaux.
+use alloc::collections::{BTreeMap, BTreeSet};
+
+use super::{
+    planar::{coords, cross, ring_contains, segment_intersections, segments, signed_area, Coord},
+    GeometryImpl as _, MultiPolygon, Polygon, VectorTensor2D,
+};
+use crate::prelude::*;
+
+/// Opérations booléennes sur les surfaces 2D.
+///
+/// Les opérandes doivent être valides (anneaux fermés et sans auto-intersection).
+/// Le résultat est toujours un [MultiPolygon], éventuellement vide, qui reprend le
+/// SRID de `self`.
+pub trait BooleanOps {
+    /// Convertit la surface en multipolygone.
+    fn to_multi_polygon(&self) -> MultiPolygon;
+
+    /// Union des deux surfaces.
+    fn union<T: BooleanOps>(&self, other: &T) -> MultiPolygon {
+        overlay(
+            &self.to_multi_polygon(),
+            &other.to_multi_polygon(),
+            Operation::Union,
+        )
+    }
+
+    /// Partie commune aux deux surfaces.
+    fn intersection<T: BooleanOps>(&self, other: &T) -> MultiPolygon {
+        overlay(
+            &self.to_multi_polygon(),
+            &other.to_multi_polygon(),
+            Operation::Intersection,
+        )
+    }
+
+    /// Partie de `self` qui n'est pas dans `other`.
+    fn difference<T: BooleanOps>(&self, other: &T) -> MultiPolygon {
+        overlay(
+            &self.to_multi_polygon(),
+            &other.to_multi_polygon(),
+            Operation::Difference,
+        )
+    }
+
+    /// Parties appartenant à une seule des deux surfaces.
+    fn symmetric_difference<T: BooleanOps>(&self, other: &T) -> MultiPolygon {
+        let (a, b) = (self.to_multi_polygon(), other.to_multi_polygon());
+
+        let mut result = overlay(&a, &b, Operation::Difference);
+        let other_part = overlay(&b, &a, Operation::Difference);
+
+        result.coordinates = result
+            .coordinates
+            .iter()
+            .chain(other_part.coordinates.iter())
+            .cloned()
+            .collect();
+
+        result
+    }
+}
+
+impl BooleanOps for Polygon {
+    fn to_multi_polygon(&self) -> MultiPolygon {
+        let mut multi_polygon =
+            MultiPolygon::new(VectorTensor2D::new(vec![self.coordinates.clone()]));
+        multi_polygon.srid = self.srid;
+        multi_polygon
+    }
+}
+
+impl BooleanOps for MultiPolygon {
+    fn to_multi_polygon(&self) -> MultiPolygon {
+        self.clone()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operation {
+    Union,
+    Intersection,
+    Difference,
+}
+
+/// Position d'un morceau d'arête par rapport à l'autre opérande.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Position {
+    Inside,
+    Outside,
+    /// Arête commune, parcourue dans le même sens.
+    SharedSame,
+    /// Arête commune, parcourue en sens inverse.
+    SharedOpposite,
+}
+
+type Edge = (Coord, Coord);
+type Rings = Vec<Vec<Coord>>;
+type CoordKey = (u64, u64);
+type EdgeKey = (CoordKey, CoordKey);
+
+fn key(c: Coord) -> CoordKey {
+    // Normalise -0.0 pour que les deux zéros soient confondus.
+    ((c.0 + 0.0).to_bits(), (c.1 + 0.0).to_bits())
+}
+
+fn edge_key((a, b): Edge) -> EdgeKey {
+    (key(a), key(b))
+}
+
+/// Anneaux de chaque polygone, l'extérieur dans le sens direct et les trous dans
+/// le sens indirect.
+fn oriented_polygons(multi_polygon: &MultiPolygon) -> Vec<Rings> {
+    multi_polygon
+        .coordinates
+        .iter()
+        .map(|polygon| {
+            polygon
+                .iter()
+                .enumerate()
+                .map(|(i, ring)| {
+                    let mut ring = coords(ring);
+                    ring.dedup();
+
+                    if (signed_area(&ring) > 0.0) != (i == 0) {
+                        ring.reverse();
+                    }
+
+                    ring
+                })
+                .filter(|ring| ring.len() >= 4)
+                .collect::<Rings>()
+        })
+        .filter(|rings| !rings.is_empty())
+        .collect()
+}
+
+fn contains_point(polygons: &[Rings], p: Coord) -> bool {
+    polygons.iter().any(|rings| {
+        ring_contains(&rings[0], p) && !rings[1..].iter().any(|hole| ring_contains(hole, p))
+    })
+}
+
+/// Découpe les arêtes de `a` et de `b` à leurs intersections mutuelles.
+fn split_edges(a: &[Edge], b: &[Edge]) -> (Vec<Edge>, Vec<Edge>) {
+    let mut splits_a = vec![Vec::<Coord>::new(); a.len()];
+    let mut splits_b = vec![Vec::<Coord>::new(); b.len()];
+
+    for (i, ea) in a.iter().enumerate() {
+        for (j, eb) in b.iter().enumerate() {
+            for point in segment_intersections(*ea, *eb) {
+                splits_a[i].push(point);
+                splits_b[j].push(point);
+            }
+        }
+    }
+
+    (split(a, splits_a), split(b, splits_b))
+}
+
+fn split(edges: &[Edge], splits: Vec<Vec<Coord>>) -> Vec<Edge> {
+    edges
+        .iter()
+        .zip(splits)
+        .flat_map(|((a, b), mut points)| {
+            let direction = (b.0 - a.0, b.1 - a.1);
+            let param = |c: &Coord| (c.0 - a.0) * direction.0 + (c.1 - a.1) * direction.1;
+
+            points.push(*a);
+            points.push(*b);
+            points.sort_by(|c1, c2| param(c1).total_cmp(&param(c2)));
+            points.dedup_by_key(|c| key(*c));
+
+            points.windows(2).map(|w| (w[0], w[1])).collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn classify(edges: &[Edge], other_edges: &BTreeSet<EdgeKey>, other: &[Rings]) -> Vec<Position> {
+    edges
+        .iter()
+        .map(|&(a, b)| {
+            if other_edges.contains(&edge_key((a, b))) {
+                Position::SharedSame
+            } else if other_edges.contains(&edge_key((b, a))) {
+                Position::SharedOpposite
+            } else if contains_point(other, ((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0)) {
+                Position::Inside
+            } else {
+                Position::Outside
+            }
+        })
+        .collect()
+}
+
+fn overlay(a: &MultiPolygon, b: &MultiPolygon, operation: Operation) -> MultiPolygon {
+    let (polygons_a, polygons_b) = (oriented_polygons(a), oriented_polygons(b));
+
+    let edges_of = |polygons: &[Rings]| {
+        polygons
+            .iter()
+            .flatten()
+            .flat_map(|ring| segments(ring).collect::<Vec<_>>())
+            .collect::<Vec<Edge>>()
+    };
+
+    let (edges_a, edges_b) = split_edges(&edges_of(&polygons_a), &edges_of(&polygons_b));
+
+    let set_a = edges_a
+        .iter()
+        .copied()
+        .map(edge_key)
+        .collect::<BTreeSet<_>>();
+    let set_b = edges_b
+        .iter()
+        .copied()
+        .map(edge_key)
+        .collect::<BTreeSet<_>>();
+
+    let positions_a = classify(&edges_a, &set_b, &polygons_b);
+    let positions_b = classify(&edges_b, &set_a, &polygons_a);
+
+    let keep_a = |position: Position| match operation {
+        Operation::Union => matches!(position, Position::Outside | Position::SharedSame),
+        Operation::Intersection => matches!(position, Position::Inside | Position::SharedSame),
+        Operation::Difference => matches!(position, Position::Outside | Position::SharedOpposite),
+    };
+
+    // Les arêtes communes sont déjà prises en compte depuis `a`.
+    let keep_b = |position: Position| match operation {
+        Operation::Union => position == Position::Outside,
+        Operation::Intersection | Operation::Difference => position == Position::Inside,
+    };
+
+    let selected = edges_a
+        .iter()
+        .zip(positions_a)
+        .filter(|(_, position)| keep_a(*position))
+        .map(|(edge, _)| *edge)
+        .chain(
+            edges_b
+                .iter()
+                .zip(positions_b)
+                .filter(|(_, position)| keep_b(*position))
+                .map(|(&(p, q), _)| {
+                    // La différence parcourt les arêtes de `b` à rebours.
+                    if operation == Operation::Difference {
+                        (q, p)
+                    } else {
+                        (p, q)
+                    }
+                }),
+        )
+        .collect::<Vec<_>>();
+
+    let mut result = MultiPolygon::new(
+        assemble(link_rings(&selected))
+            .into_iter()
+            .map(|rings| {
+                rings
+                    .into_iter()
+                    .map(|ring| ring.into_iter().map(|(x, y)| [x, y]).collect())
+                    .collect::<Vec<Vec<[f64; 2]>>>()
+            })
+            .collect::<VectorTensor2D>(),
+    );
+    result.srid = a.srid;
+    result
+}
+
+/// Chaîne les arêtes orientées en anneaux fermés.
+///
+/// Lorsque plusieurs arêtes partent d'un même sommet, on tourne le plus à droite
+/// possible, afin de séparer les anneaux qui se touchent en un point.
+fn link_rings(edges: &[Edge]) -> Vec<Vec<Coord>> {
+    let mut outgoing = BTreeMap::<CoordKey, Vec<usize>>::new();
+    for (i, (a, _)) in edges.iter().enumerate() {
+        outgoing.entry(key(*a)).or_default().push(i);
+    }
+
+    let mut used = vec![false; edges.len()];
+    let mut rings = Vec::new();
+
+    for start in 0..edges.len() {
+        if used[start] {
+            continue;
+        }
+
+        used[start] = true;
+        let (origin, mut current) = edges[start];
+        let mut previous = origin;
+        let mut ring = vec![origin, current];
+
+        while key(current) != key(origin) {
+            let incoming = (current.0 - previous.0, current.1 - previous.1);
+
+            let next = outgoing
+                .get(&key(current))
+                .into_iter()
+                .flatten()
+                .filter(|i| !used[**i])
+                .min_by(|i, j| {
+                    let turn = |k: usize| {
+                        let (a, b) = edges[k];
+                        let outgoing = (b.0 - a.0, b.1 - a.1);
+                        (incoming.0 * outgoing.1 - incoming.1 * outgoing.0)
+                            .atan2(incoming.0 * outgoing.0 + incoming.1 * outgoing.1)
+                    };
+                    turn(**i).total_cmp(&turn(**j))
+                })
+                .copied();
+
+            let Some(next) = next else {
+                break;
+            };
+
+            used[next] = true;
+            previous = current;
+            current = edges[next].1;
+            ring.push(current);
+        }
+
+        if key(current) == key(origin) {
+            rings.push(remove_collinear(ring));
+        }
+    }
+
+    rings.into_iter().filter(|ring| ring.len() >= 4).collect()
+}
+
+/// Retire les sommets alignés avec leurs voisins.
+fn remove_collinear(ring: Vec<Coord>) -> Vec<Coord> {
+    let n = ring.len() - 1;
+    let mut simplified = (0..n)
+        .filter(|&i| {
+            let (prev, p, next) = (ring[(i + n - 1) % n], ring[i], ring[(i + 1) % n]);
+            let direction = (p.0 - prev.0) * (next.0 - p.0) + (p.1 - prev.1) * (next.1 - p.1);
+            cross(prev, p, next) != 0.0 || direction < 0.0
+        })
+        .map(|i| ring[i])
+        .collect::<Vec<_>>();
+
+    if let Some(first) = simplified.first().copied() {
+        simplified.push(first);
+    }
+
+    simplified
+}
+
+/// Regroupe les anneaux en polygones : chaque trou rejoint le plus petit extérieur
+/// qui le contient.
+fn assemble(rings: Vec<Vec<Coord>>) -> Vec<Rings> {
+    let (exteriors, holes): (Vec<_>, Vec<_>) =
+        rings.into_iter().partition(|ring| signed_area(ring) > 0.0);
+
+    let mut polygons = exteriors
+        .into_iter()
+        .map(|ring| vec![ring])
+        .collect::<Vec<Rings>>();
+
+    for hole in holes {
+        let probe = ((hole[0].0 + hole[1].0) / 2.0, (hole[0].1 + hole[1].1) / 2.0);
+
+        let owner = polygons
+            .iter_mut()
+            .filter(|rings| ring_contains(&rings[0], probe))
+            .min_by(|r1, r2| signed_area(&r1[0]).total_cmp(&signed_area(&r2[0])));
+
+        if let Some(rings) = owner {
+            rings.push(hole);
+        }
+    }
+
+    polygons
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(min: f64, max: f64) -> Polygon {
+        Polygon::new([[min, min], [max, min], [max, max], [min, max]])
+    }
+
+    fn area(multi_polygon: &MultiPolygon) -> f64 {
+        oriented_polygons(multi_polygon)
+            .iter()
+            .flatten()
+            .map(|ring| signed_area(ring))
+            .sum()
+    }
+
+    #[test]
+    fn test_overlapping_squares() {
+        let (a, b) = (square(0.0, 2.0), square(1.0, 3.0));
+
+        assert_eq!(area(&a.union(&b)), 7.0);
+        assert_eq!(area(&a.intersection(&b)), 1.0);
+        assert_eq!(area(&a.difference(&b)), 3.0);
+        assert_eq!(area(&a.symmetric_difference(&b)), 6.0);
+        assert_eq!(a.symmetric_difference(&b).coordinates.len(), 2);
+    }
+
+    #[test]
+    fn test_disjoint_squares() {
+        let (a, b) = (square(0.0, 1.0), square(2.0, 3.0));
+
+        assert_eq!(a.union(&b).coordinates.len(), 2);
+        assert!(a.intersection(&b).coordinates.is_empty());
+        assert_eq!(a.difference(&b), a.to_multi_polygon());
+    }
+
+    #[test]
+    fn test_adjacent_squares_are_merged() {
+        let a = square(0.0, 1.0);
+        let b = Polygon::new([[1.0, 0.0], [2.0, 0.0], [2.0, 1.0], [1.0, 1.0]]);
+
+        let union = a.union(&b);
+        assert_eq!(union.coordinates.len(), 1);
+        assert_eq!(union.coordinates[0][0].len(), 5);
+        assert_eq!(area(&union), 2.0);
+    }
+
+    #[test]
+    fn test_difference_creates_hole() {
+        let difference = square(0.0, 10.0).difference(&square(2.0, 4.0));
+
+        assert_eq!(difference.coordinates.len(), 1);
+        assert_eq!(difference.coordinates[0].len(), 2);
+        assert_eq!(area(&difference), 96.0);
+
+        // Retirer le trou le comble à nouveau.
+        assert_eq!(area(&difference.union(&square(2.0, 4.0))), 100.0);
+    }
+}
@@ -0,0 +1,113 @@
+//! Variantes parallèles (rayon) des parcours de coordonnées, pour les très
+//! grandes géométries et les lots de géométries.
+use rayon::prelude::*;
+
+use super::{Geometry, VectorArray, VectorMatrix, VectorTensor, MBR2D};
+
+impl Geometry {
+    /// Équivalent parallèle de [Geometry::map_coordinates].
+    pub fn par_map_coordinates<F>(&mut self, f: F)
+    where
+        F: Fn(&mut [f64]) + Sync + Send,
+    {
+        match self {
+            Geometry::Point(a) => f(&mut a.coordinates[..]),
+            Geometry::LineString(a) => par_map_array(&mut a.coordinates, &f),
+            Geometry::Polygon(a) => par_map_matrix(&mut a.coordinates, &f),
+            Geometry::MultiPoint(a) => par_map_array(&mut a.coordinates, &f),
+            Geometry::MultiLineString(a) => par_map_matrix(&mut a.coordinates, &f),
+            Geometry::MultiPolygon(a) => par_map_tensor(&mut a.coordinates, &f),
+            Geometry::PointZ(a) => f(&mut a.coordinates[..]),
+            Geometry::LineStringZ(a) => par_map_array(&mut a.coordinates, &f),
+            Geometry::PolygonZ(a) => par_map_matrix(&mut a.coordinates, &f),
+            Geometry::MultiPointZ(a) => par_map_array(&mut a.coordinates, &f),
+            Geometry::MultiLineStringZ(a) => par_map_matrix(&mut a.coordinates, &f),
+            Geometry::MultiPolygonZ(a) => par_map_tensor(&mut a.coordinates, &f),
+        }
+    }
+
+    /// Équivalent parallèle de [Geometry::mbr], réparti sur les anneaux et les parties.
+    pub fn par_mbr(&self) -> Option<MBR2D> {
+        match self {
+            Geometry::Polygon(a) => par_mbr_matrix(&a.coordinates),
+            Geometry::MultiLineString(a) => par_mbr_matrix(&a.coordinates),
+            Geometry::MultiPolygon(a) => par_mbr_tensor(&a.coordinates),
+            Geometry::PolygonZ(a) => par_mbr_matrix(&a.coordinates),
+            Geometry::MultiLineStringZ(a) => par_mbr_matrix(&a.coordinates),
+            Geometry::MultiPolygonZ(a) => par_mbr_tensor(&a.coordinates),
+            _ => self.mbr(),
+        }
+    }
+}
+
+impl MBR2D {
+    /// Équivalent parallèle de [MBR2D::from_geometries].
+    pub fn par_from_geometries(geometries: &[Geometry]) -> Option<Self> {
+        geometries
+            .par_iter()
+            .filter_map(Geometry::par_mbr)
+            .reduce_with(|a, b| a.union(&b))
+    }
+}
+
+fn par_map_array<const N: usize, F>(array: &mut VectorArray<N, f64>, f: &F)
+where
+    F: Fn(&mut [f64]) + Sync + Send,
+{
+    array.par_iter_mut().for_each(|v| f(&mut v[..]))
+}
+
+fn par_map_matrix<const N: usize, F>(matrix: &mut VectorMatrix<N, f64>, f: &F)
+where
+    F: Fn(&mut [f64]) + Sync + Send,
+{
+    matrix.par_iter_mut().for_each(|a| par_map_array(a, f))
+}
+
+fn par_map_tensor<const N: usize, F>(tensor: &mut VectorTensor<N, f64>, f: &F)
+where
+    F: Fn(&mut [f64]) + Sync + Send,
+{
+    tensor.par_iter_mut().for_each(|m| par_map_matrix(m, f))
+}
+
+fn par_mbr_matrix<const N: usize>(matrix: &VectorMatrix<N, f64>) -> Option<MBR2D> {
+    matrix
+        .par_iter()
+        .filter_map(|a| a.mbr().map(|mbr| mbr.to_2d()))
+        .reduce_with(|a, b| a.union(&b))
+}
+
+fn par_mbr_tensor<const N: usize>(tensor: &VectorTensor<N, f64>) -> Option<MBR2D> {
+    tensor
+        .par_iter()
+        .filter_map(par_mbr_matrix)
+        .reduce_with(|a, b| a.union(&b))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{GeometryImpl as _, MultiPolygon};
+
+    use super::*;
+
+    #[test]
+    fn test_parallel_matches_sequential() {
+        let mut geometry = Geometry::from(MultiPolygon::new(VectorTensor::from_iter([
+            vec![vec![[0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 0.0]]],
+            vec![vec![[10.0, -2.0], [12.0, -2.0], [12.0, 1.0], [10.0, -2.0]]],
+        ])));
+
+        assert_eq!(geometry.par_mbr(), geometry.mbr());
+
+        let mut expected = geometry.clone();
+        expected.map_coordinates(|c| c[0] += 1.0);
+        geometry.par_map_coordinates(|c| c[0] += 1.0);
+
+        assert_eq!(geometry, expected);
+        assert_eq!(
+            MBR2D::par_from_geometries(&[geometry.clone()]),
+            MBR2D::from_geometries(&[geometry])
+        );
+    }
+}
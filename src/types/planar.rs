@@ -0,0 +1,174 @@
+//! Primitives de géométrie planaire partagées par les algorithmes locaux.
+//!
+//! Les géométries y sont décomposées en composants simples dont les coordonnées
+//! sont projetées dans le plan XY.
+use super::{
+    segment::{Segment, SegmentIntersection},
+    Geometry, Vector, VectorArray, VectorMatrix,
+};
+use crate::prelude::*;
+
+/// Coordonnées d'un sommet dans le plan.
+pub(crate) type Coord = (f64, f64);
+
+/// Composant élémentaire d'une géométrie.
+pub(crate) enum Part {
+    Point(Coord),
+    Line(Vec<Coord>),
+    /// Anneau extérieur suivi des anneaux intérieurs.
+    Polygon(Vec<Vec<Coord>>),
+}
+
+pub(crate) fn coord<const N: usize>(v: &Vector<N, f64>) -> Coord {
+    (v.x(), v.y())
+}
+
+pub(crate) fn coords<const N: usize>(array: &VectorArray<N, f64>) -> Vec<Coord> {
+    array.iter().map(coord).collect()
+}
+
+fn rings<const N: usize>(matrix: &VectorMatrix<N, f64>) -> Vec<Vec<Coord>> {
+    matrix.iter().map(coords).collect()
+}
+
+/// Décompose une géométrie en composants élémentaires.
+pub(crate) fn parts(geometry: &Geometry) -> Vec<Part> {
+    match geometry {
+        Geometry::Point(a) => vec![Part::Point(coord(&a.coordinates))],
+        Geometry::PointZ(a) => vec![Part::Point(coord(&a.coordinates))],
+        Geometry::LineString(a) => vec![Part::Line(coords(&a.coordinates))],
+        Geometry::LineStringZ(a) => vec![Part::Line(coords(&a.coordinates))],
+        Geometry::Polygon(a) => vec![Part::Polygon(rings(&a.coordinates))],
+        Geometry::PolygonZ(a) => vec![Part::Polygon(rings(&a.coordinates))],
+        Geometry::MultiPoint(a) => a
+            .coordinates
+            .iter()
+            .map(|v| Part::Point(coord(v)))
+            .collect(),
+        Geometry::MultiPointZ(a) => a
+            .coordinates
+            .iter()
+            .map(|v| Part::Point(coord(v)))
+            .collect(),
+        Geometry::MultiLineString(a) => a
+            .coordinates
+            .iter()
+            .map(|l| Part::Line(coords(l)))
+            .collect(),
+        Geometry::MultiLineStringZ(a) => a
+            .coordinates
+            .iter()
+            .map(|l| Part::Line(coords(l)))
+            .collect(),
+        Geometry::MultiPolygon(a) => a
+            .coordinates
+            .iter()
+            .map(|p| Part::Polygon(rings(p)))
+            .collect(),
+        Geometry::MultiPolygonZ(a) => a
+            .coordinates
+            .iter()
+            .map(|p| Part::Polygon(rings(p)))
+            .collect(),
+    }
+}
+
+/// Produit vectoriel `(b - a) x (c - a)`, positif si `a, b, c` tournent dans le sens direct.
+pub(crate) fn cross(a: Coord, b: Coord, c: Coord) -> f64 {
+    (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0)
+}
+
+/// Vrai si `p` est sur le segment `[a, b]`.
+pub(crate) fn on_segment(a: Coord, b: Coord, p: Coord) -> bool {
+    segment(a, b).contains_point([p.0, p.1])
+}
+
+/// Vrai si les segments `[a, b]` et `[c, d]` ont au moins un point commun.
+pub(crate) fn segments_intersect(a: Coord, b: Coord, c: Coord, d: Coord) -> bool {
+    segment(a, b).intersects(&segment(c, d))
+}
+
+/// Vrai si les segments `[a, b]` et `[c, d]` se croisent en un point intérieur aux deux.
+pub(crate) fn segments_cross(a: Coord, b: Coord, c: Coord, d: Coord) -> bool {
+    segment(a, b).crosses(&segment(c, d))
+}
+
+/// Points communs aux segments `[p, p2]` et `[q, q2]` : aucun, un seul, ou les
+/// extrémités de leur recouvrement.
+pub(crate) fn segment_intersections(
+    (p, p2): (Coord, Coord),
+    (q, q2): (Coord, Coord),
+) -> Vec<Coord> {
+    match segment(p, p2).intersection(&segment(q, q2)) {
+        SegmentIntersection::None => vec![],
+        SegmentIntersection::Point([x, y]) => vec![(x, y)],
+        SegmentIntersection::Overlap([x1, y1], [x2, y2]) => vec![(x1, y1), (x2, y2)],
+    }
+}
+
+fn segment(a: Coord, b: Coord) -> Segment {
+    Segment::new([a.0, a.1], [b.0, b.1])
+}
+
+/// Test pair-impair (ray casting) d'appartenance d'un point à l'intérieur d'un anneau.
+///
+/// Le résultat n'est pas défini pour un point situé sur l'anneau, voir [on_ring].
+pub(crate) fn ring_contains(ring: &[Coord], (x, y): Coord) -> bool {
+    let mut inside = false;
+
+    for s in ring.windows(2) {
+        let (a, b) = (s[0], s[1]);
+
+        if (a.1 > y) != (b.1 > y) {
+            let x_cross = a.0 + (y - a.1) * (b.0 - a.0) / (b.1 - a.1);
+
+            if x < x_cross {
+                inside = !inside;
+            }
+        }
+    }
+
+    inside
+}
+
+/// Vrai si `p` est sur une des arêtes de la ligne brisée.
+pub(crate) fn on_ring(ring: &[Coord], p: Coord) -> bool {
+    ring.windows(2).any(|s| on_segment(s[0], s[1], p))
+}
+
+/// Position d'un point par rapport à un polygone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Location {
+    Interior,
+    Boundary,
+    Exterior,
+}
+
+pub(crate) fn locate_in_polygon(rings: &[Vec<Coord>], p: Coord) -> Location {
+    let Some((exterior, interiors)) = rings.split_first() else {
+        return Location::Exterior;
+    };
+
+    if rings.iter().any(|ring| on_ring(ring, p)) {
+        return Location::Boundary;
+    }
+
+    if ring_contains(exterior, p) && !interiors.iter().any(|ring| ring_contains(ring, p)) {
+        Location::Interior
+    } else {
+        Location::Exterior
+    }
+}
+
+/// Itère sur les segments d'une ligne brisée.
+pub(crate) fn segments(line: &[Coord]) -> impl Iterator<Item = (Coord, Coord)> + '_ {
+    line.windows(2).map(|s| (s[0], s[1]))
+}
+
+/// Aire signée d'un anneau fermé, positive si l'anneau tourne dans le sens direct.
+pub(crate) fn signed_area(ring: &[Coord]) -> f64 {
+    segments(ring)
+        .map(|(a, b)| a.0 * b.1 - b.0 * a.1)
+        .sum::<f64>()
+        / 2.0
+}
@@ -1,17 +1,27 @@
-use std::ops::{Deref, DerefMut};
+use core::ops::{Deref, DerefMut};
 
-use super::{GeometryImpl, Vector, MBR};
+use super::{GeometryImpl, Scalar, Vector, MBR};
 
 /// Type représentant les coordonnées d'un point.
 pub type PointCoordinates<const N: usize, U> = Vector<N, U>;
 
 /// Un point dans un espace n-d.
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
 pub struct Point<const N: usize, U> {
     pub coordinates: PointCoordinates<N, U>,
     pub srid: Option<u32>,
 }
 
+/// Origine du repère, sans SRID.
+impl<const N: usize, U: Scalar> Default for Point<N, U> {
+    fn default() -> Self {
+        Self {
+            coordinates: Vector::default(),
+            srid: None,
+        }
+    }
+}
+
 impl<const N: usize, U> GeometryImpl for Point<N, U> {
     type Coordinates = PointCoordinates<N, U>;
 
@@ -23,17 +33,53 @@ impl<const N: usize, U> GeometryImpl for Point<N, U> {
     }
 }
 
+impl<const N: usize, U> Point<N, U>
+where
+    U: Scalar,
+{
+    /// Rectangle englobant, `None` si une des coordonnées n'est pas comparable (NaN).
+    pub fn mbr(&self) -> Option<MBR<N, U>> {
+        self.coordinates.mbr()
+    }
+}
+
 impl<const N: usize, U> Point<N, U>
 where
     U: Copy,
 {
-    pub fn mbr(&self) -> MBR<U> {
-        MBR {
-            min_x: self.coordinates.x(),
-            max_x: self.coordinates.x(),
-            min_y: self.coordinates.y(),
-            max_y: self.coordinates.y(),
-        }
+    pub fn x(&self) -> U {
+        self.coordinates.x()
+    }
+
+    pub fn y(&self) -> U {
+        self.coordinates.y()
+    }
+
+    /// Altitude, `None` pour un point 2D.
+    pub fn z(&self) -> Option<U> {
+        self.coordinates.get(2).copied()
+    }
+
+    /// Longitude, soit l'abscisse d'un point en coordonnées géographiques.
+    pub fn lng(&self) -> U {
+        self.x()
+    }
+
+    /// Latitude, soit l'ordonnée d'un point en coordonnées géographiques.
+    pub fn lat(&self) -> U {
+        self.y()
+    }
+}
+
+impl<U> From<(U, U)> for Point<2, U> {
+    fn from((x, y): (U, U)) -> Self {
+        Self::new([x, y])
+    }
+}
+
+impl<U> From<(U, U, U)> for Point<3, U> {
+    fn from((x, y, z): (U, U, U)) -> Self {
+        Self::new([x, y, z])
     }
 }
 
@@ -50,3 +96,18 @@ impl<const N: usize, U> DerefMut for Point<N, U> {
         self.coordinates.deref_mut()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{Point, PointZ};
+
+    #[test]
+    fn test_accessors() {
+        let point = Point::from((2.35, 48.85));
+        assert_eq!((point.x(), point.y(), point.z()), (2.35, 48.85, None));
+        assert_eq!((point.lng(), point.lat()), (2.35, 48.85));
+
+        let point = PointZ::from((1.0, 2.0, 3.0));
+        assert_eq!((point.x(), point.y(), point.z()), (1.0, 2.0, Some(3.0)));
+    }
+}
@@ -1,14 +1,27 @@
-use super::{GeometryImpl, VectorMatrix, MBR};
+use super::{
+    orientation::is_ring_oriented, GeometryImpl, RingOrientation, Scalar, ValidationError,
+    VectorMatrix, MBR,
+};
 
 pub type PolygonCoordinates<const N: usize, U> = VectorMatrix<N, U>;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 /// Un polygone
 pub struct Polygon<const N: usize, U> {
     pub coordinates: PolygonCoordinates<N, U>,
     pub srid: Option<u32>,
 }
 
+/// Géométrie vide, sans SRID.
+impl<const N: usize, U> Default for Polygon<N, U> {
+    fn default() -> Self {
+        Self {
+            coordinates: Default::default(),
+            srid: None,
+        }
+    }
+}
+
 impl<const N: usize, U> GeometryImpl for Polygon<N, U>
 where
     U: Clone + PartialEq,
@@ -30,14 +43,122 @@ where
 
 impl<const N: usize, U> Polygon<N, U>
 where
-    U: Copy + PartialOrd,
+    U: Scalar,
 {
-    pub fn mbr(&self) -> MBR<U> {
-        MBR {
-            min_x: self.coordinates.min_x(),
-            max_x: self.coordinates.max_x(),
-            min_y: self.coordinates.min_y(),
-            max_y: self.coordinates.max_y(),
+    /// Construit un polygone sans corriger ses anneaux.
+    ///
+    /// Contrairement à [GeometryImpl::new], un anneau non fermé est refusé, tout comme
+    /// un anneau de moins de 4 points. Le sens de parcours de chaque anneau est aussi
+    /// vérifié, sauf pour [RingOrientation::Native] ; les anneaux d'aire nulle n'en ont pas.
+    pub fn try_new<C: Into<PolygonCoordinates<N, U>>>(
+        coordinates: C,
+        orientation: RingOrientation,
+    ) -> Result<Self, ValidationError> {
+        let coordinates: PolygonCoordinates<N, U> = coordinates.into();
+
+        for (i, ring) in coordinates.iter().enumerate() {
+            if ring.first() != ring.last() {
+                return Err(ValidationError::UnclosedRing { part: 0, ring: i });
+            }
+
+            if ring.len() < 4 {
+                return Err(ValidationError::TooFewPoints {
+                    part: 0,
+                    ring: Some(i),
+                    got: ring.len(),
+                });
+            }
+
+            if !is_ring_oriented(ring, i, orientation) {
+                return Err(ValidationError::WrongOrientation { part: 0, ring: i });
+            }
         }
+
+        Ok(Self {
+            coordinates,
+            srid: None,
+        })
+    }
+
+    /// Comme [GeometryImpl::new], puis inverse les anneaux qui ne tournent pas dans
+    /// le sens imposé.
+    pub fn new_with_policy<C: Into<PolygonCoordinates<N, U>>>(
+        coordinates: C,
+        orientation: RingOrientation,
+    ) -> Self {
+        let mut polygon = Self::new(coordinates);
+        polygon.orient(orientation);
+        polygon
+    }
+
+    /// Rectangle englobant, `None` si la géométrie n'a aucune coordonnée exploitable.
+    pub fn mbr(&self) -> Option<MBR<N, U>> {
+        self.coordinates.mbr()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Polygon;
+
+    #[test]
+    fn test_try_new() {
+        let shell = [
+            [0.0, 0.0],
+            [10.0, 0.0],
+            [10.0, 10.0],
+            [0.0, 10.0],
+            [0.0, 0.0],
+        ];
+        let hole = [[4.0, 4.0], [4.0, 6.0], [6.0, 6.0], [6.0, 4.0], [4.0, 4.0]];
+
+        let polygon = Polygon::try_new((shell, hole), RingOrientation::CounterClockwise)
+            .expect("valid polygon");
+        assert_eq!(polygon, Polygon::new((shell, hole)));
+
+        assert_eq!(
+            Polygon::try_new((shell, hole), RingOrientation::Clockwise),
+            Err(ValidationError::WrongOrientation { part: 0, ring: 0 })
+        );
+        assert_eq!(
+            Polygon::try_new((shell, shell), RingOrientation::CounterClockwise),
+            Err(ValidationError::WrongOrientation { part: 0, ring: 1 })
+        );
+        assert!(Polygon::try_new((shell, shell), RingOrientation::Native).is_ok());
+
+        assert_eq!(
+            Polygon::try_new(
+                [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0]],
+                RingOrientation::Native
+            ),
+            Err(ValidationError::UnclosedRing { part: 0, ring: 0 })
+        );
+        assert_eq!(
+            Polygon::try_new(
+                [[0.0, 0.0], [10.0, 0.0], [0.0, 0.0]],
+                RingOrientation::Native
+            ),
+            Err(ValidationError::TooFewPoints {
+                part: 0,
+                ring: Some(0),
+                got: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_new_with_policy() {
+        let shell = [[0.0, 0.0], [0.0, 10.0], [10.0, 10.0], [10.0, 0.0]];
+
+        let polygon = Polygon::new_with_policy(shell, RingOrientation::OGC);
+        assert_eq!(
+            polygon,
+            Polygon::new([[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0]])
+        );
+        assert_eq!(
+            Polygon::new_with_policy(shell, RingOrientation::Native),
+            Polygon::new(shell)
+        );
     }
 }
@@ -0,0 +1,191 @@
+//! Prédicats spatiaux planaires (`intersects`, `contains`).
+//!
+//! Ils couvrent les cas courants afin de filtrer localement, avant ou après la base
+//! de données, sans prétendre à l'exactitude de PostGIS sur les cas dégénérés.
+use super::{
+    planar::{
+        locate_in_polygon, on_ring, parts, segments, segments_cross, segments_intersect, Coord,
+        Location, Part,
+    },
+    Geometry, MBR2D,
+};
+use crate::prelude::*;
+
+impl Geometry {
+    /// Vrai si les deux géométries ont au moins un point commun (équivalent local
+    /// de `ST_Intersects`).
+    pub fn intersects(&self, other: &Geometry) -> bool {
+        if !mbr_intersects(self.mbr(), other.mbr()) {
+            return false;
+        }
+
+        let (a, b) = (parts(self), parts(other));
+        a.iter()
+            .any(|pa| b.iter().any(|pb| part_intersects(pa, pb)))
+    }
+
+    /// Vrai si `other` est entièrement contenue dans la géométrie (équivalent local
+    /// de `ST_Contains`).
+    ///
+    /// Chaque composant de `other` doit être contenu dans un même composant de `self`.
+    pub fn contains(&self, other: &Geometry) -> bool {
+        if !mbr_intersects(self.mbr(), other.mbr()) {
+            return false;
+        }
+
+        let (a, b) = (parts(self), parts(other));
+        !b.is_empty() && b.iter().all(|pb| a.iter().any(|pa| part_contains(pa, pb)))
+    }
+}
+
+/// Une géométrie sans rectangle englobant (vide) n'intersecte rien.
+fn mbr_intersects(a: Option<MBR2D>, b: Option<MBR2D>) -> bool {
+    let (Some(a), Some(b)) = (a, b) else {
+        return false;
+    };
+
+    (0..2).all(|i| a.min[i] <= b.max[i] && b.min[i] <= a.max[i])
+}
+
+fn midpoint(a: Coord, b: Coord) -> Coord {
+    ((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0)
+}
+
+fn on_line(line: &[Coord], p: Coord) -> bool {
+    match line {
+        [a] => *a == p,
+        _ => on_ring(line, p),
+    }
+}
+
+fn lines_intersect(l: &[Coord], m: &[Coord]) -> bool {
+    match (l, m) {
+        ([p], _) => on_line(m, *p),
+        (_, [p]) => on_line(l, *p),
+        _ => segments(l).any(|(a, b)| segments(m).any(|(c, d)| segments_intersect(a, b, c, d))),
+    }
+}
+
+fn part_intersects(a: &Part, b: &Part) -> bool {
+    match (a, b) {
+        (Part::Point(p), Part::Point(q)) => p == q,
+        (Part::Point(p), Part::Line(l)) | (Part::Line(l), Part::Point(p)) => on_line(l, *p),
+        (Part::Point(p), Part::Polygon(r)) | (Part::Polygon(r), Part::Point(p)) => {
+            locate_in_polygon(r, *p) != Location::Exterior
+        }
+        (Part::Line(l), Part::Line(m)) => lines_intersect(l, m),
+        (Part::Line(l), Part::Polygon(r)) | (Part::Polygon(r), Part::Line(l)) => {
+            l.iter()
+                .any(|p| locate_in_polygon(r, *p) != Location::Exterior)
+                || r.iter().any(|ring| lines_intersect(l, ring))
+        }
+        (Part::Polygon(r), Part::Polygon(s)) => {
+            r.iter()
+                .any(|ring| s.iter().any(|other| lines_intersect(ring, other)))
+                || first_vertex_in(r, s)
+                || first_vertex_in(s, r)
+        }
+    }
+}
+
+/// Vrai si le premier sommet de `r` n'est pas à l'extérieur de `s`.
+fn first_vertex_in(r: &[Vec<Coord>], s: &[Vec<Coord>]) -> bool {
+    r.first()
+        .and_then(|ring| ring.first())
+        .is_some_and(|p| locate_in_polygon(s, *p) != Location::Exterior)
+}
+
+/// Vrai si la ligne `l` est dans le polygone `r`, intérieur ou frontière.
+fn line_covered_by_polygon(r: &[Vec<Coord>], l: &[Coord]) -> bool {
+    let is_inside = |p: Coord| locate_in_polygon(r, p) != Location::Exterior;
+
+    l.iter().all(|p| is_inside(*p))
+        && segments(l).all(|(a, b)| {
+            is_inside(midpoint(a, b))
+                && r.iter()
+                    .all(|ring| segments(ring).all(|(c, d)| !segments_cross(a, b, c, d)))
+        })
+}
+
+fn part_contains(a: &Part, b: &Part) -> bool {
+    match (a, b) {
+        (Part::Point(p), Part::Point(q)) => p == q,
+        (Part::Line(l), Part::Point(p)) => {
+            // Les extrémités d'une ligne ouverte sont sur sa frontière.
+            let is_closed = l.len() > 2 && l.first() == l.last();
+            on_line(l, *p) && (is_closed || (l.first() != Some(p) && l.last() != Some(p)))
+        }
+        (Part::Line(l), Part::Line(m)) => {
+            m.iter().all(|p| on_line(l, *p)) && segments(m).all(|(a, b)| on_line(l, midpoint(a, b)))
+        }
+        (Part::Polygon(r), Part::Point(p)) => locate_in_polygon(r, *p) == Location::Interior,
+        (Part::Polygon(r), Part::Line(l)) => {
+            line_covered_by_polygon(r, l)
+                && (l.iter().copied())
+                    .chain(segments(l).map(|(a, b)| midpoint(a, b)))
+                    .any(|p| locate_in_polygon(r, p) == Location::Interior)
+        }
+        (Part::Polygon(r), Part::Polygon(s)) => {
+            let Some(shell) = s.first() else {
+                return false;
+            };
+
+            // Un trou de `r` ne doit pas se trouver à l'intérieur de `s`.
+            line_covered_by_polygon(r, shell)
+                && r.iter().skip(1).all(|hole| {
+                    hole.iter()
+                        .all(|p| locate_in_polygon(s, *p) != Location::Interior)
+                })
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{GeometryImpl as _, LineString, Point, Polygon};
+
+    use super::*;
+
+    fn square(min: f64, max: f64) -> Geometry {
+        Polygon::new([[min, min], [max, min], [max, max], [min, max]]).into()
+    }
+
+    #[test]
+    fn test_intersects() {
+        let polygon = square(0.0, 10.0);
+
+        assert!(polygon.intersects(&Point::new([5.0, 5.0]).into()));
+        assert!(polygon.intersects(&Point::new([10.0, 5.0]).into()));
+        assert!(!polygon.intersects(&Point::new([11.0, 5.0]).into()));
+        assert!(polygon.intersects(&square(5.0, 15.0)));
+        assert!(polygon.intersects(&square(2.0, 3.0)));
+        assert!(!polygon.intersects(&square(11.0, 15.0)));
+
+        let line = Geometry::from(LineString::new([[-5.0, 5.0], [15.0, 5.0]]));
+        assert!(polygon.intersects(&line));
+        assert!(line.intersects(&LineString::new([[0.0, 0.0], [0.0, 10.0]]).into()));
+        assert!(!line.intersects(&LineString::new([[0.0, 6.0], [10.0, 6.0]]).into()));
+    }
+
+    #[test]
+    fn test_contains() {
+        let polygon = square(0.0, 10.0);
+
+        assert!(polygon.contains(&Point::new([5.0, 5.0]).into()));
+        assert!(!polygon.contains(&Point::new([10.0, 5.0]).into()));
+        assert!(polygon.contains(&square(2.0, 3.0)));
+        assert!(polygon.contains(&polygon));
+        assert!(!polygon.contains(&square(5.0, 15.0)));
+        assert!(polygon.contains(&LineString::new([[1.0, 1.0], [9.0, 9.0]]).into()));
+        assert!(!polygon.contains(&LineString::new([[1.0, 1.0], [19.0, 9.0]]).into()));
+
+        let with_hole = Geometry::from(Polygon::new((
+            [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0]],
+            [[4.0, 4.0], [6.0, 4.0], [6.0, 6.0], [4.0, 6.0]],
+        )));
+        assert!(!with_hole.contains(&Point::new([5.0, 5.0]).into()));
+        assert!(!with_hole.contains(&square(3.0, 7.0)));
+        assert!(with_hole.contains(&square(1.0, 3.0)));
+    }
+}
@@ -0,0 +1,237 @@
+//! Reprojection de lots de géométries (fonctionnalité `proj4rs`), basée sur `proj4rs`.
+use std::{borrow::Cow, collections::HashMap};
+
+use proj4rs::{
+    transform::{transform, Transform, TransformClosure},
+    Proj,
+};
+
+use super::{CowGeometry, Geometry};
+use crate::error::Error;
+
+/// Définitions proj des SRID les plus courants.
+const BUILTIN_DEFINITIONS: [(u32, &str); 3] = [
+    (4326, "+proj=longlat +datum=WGS84 +no_defs"),
+    (
+        3857,
+        "+proj=merc +a=6378137 +b=6378137 +lat_ts=0 +lon_0=0 +x_0=0 +y_0=0 +k=1 +units=m \
+         +nadgrids=@null +no_defs",
+    ),
+    (
+        2154,
+        "+proj=lcc +lat_0=46.5 +lon_0=3 +lat_1=49 +lat_2=44 +x_0=700000 +y_0=6600000 \
+         +ellps=GRS80 +towgs84=0,0,0,0,0,0,0 +units=m +no_defs",
+    ),
+];
+
+/// Reprojette des géométries d'un SRID à un autre.
+///
+/// Les projections sont initialisées une seule fois par couple (source, cible),
+/// ce qui amortit leur coût sur un jeu de résultats entier. Les coordonnées
+/// géographiques sont exprimées en degrés.
+///
+/// `proj4rs` n'embarque pas la base EPSG : seuls EPSG:4326, EPSG:3857 et EPSG:2154
+/// sont connus de [Reprojector::new]. Tout autre SRID, source ou cible, doit être
+/// déclaré avec [Reprojector::define], faute de quoi la reprojection échoue avec
+/// [Error::UnknownSrid].
+#[derive(Default)]
+pub struct Reprojector {
+    definitions: HashMap<u32, String>,
+    cache: HashMap<(u32, u32), (Proj, Proj)>,
+}
+
+impl Reprojector {
+    /// Reprojecteur connaissant EPSG:4326, EPSG:3857 et EPSG:2154.
+    pub fn new() -> Self {
+        let mut reprojector = Self::default();
+
+        for (srid, definition) in BUILTIN_DEFINITIONS {
+            reprojector.define(srid, definition);
+        }
+
+        reprojector
+    }
+
+    /// Déclare (ou remplace) la définition proj d'un SRID.
+    pub fn define(&mut self, srid: u32, definition: &str) {
+        self.definitions.insert(srid, definition.to_owned());
+        self.cache
+            .retain(|(from, to), _| *from != srid && *to != srid);
+    }
+
+    /// Reprojette une géométrie vers le SRID `to`.
+    ///
+    /// En cas d'erreur, la géométrie est laissée intacte.
+    pub fn reproject(&mut self, geometry: &mut Geometry, to: u32) -> Result<(), Error> {
+        if let Cow::Owned(projected) = self.reprojected(geometry, to)? {
+            *geometry = projected;
+        }
+
+        Ok(())
+    }
+
+    /// Renvoie la géométrie reprojetée vers le SRID `to`.
+    ///
+    /// La géométrie est empruntée, sans copie, si elle est déjà dans le SRID cible.
+    pub fn reprojected<'a>(
+        &mut self,
+        geometry: &'a Geometry,
+        to: u32,
+    ) -> Result<CowGeometry<'a>, Error> {
+        let from = geometry
+            .srid()
+            .ok_or_else(|| Error::Projection("the geometry has no SRID".to_owned()))?;
+
+        if from == to {
+            return Ok(Cow::Borrowed(geometry));
+        }
+
+        let (source, target) = self.projections(from, to)?;
+
+        let mut projected = geometry.clone();
+
+        if source.is_latlong() {
+            projected.map_coordinates(|c| c.iter_mut().take(2).for_each(|v| *v = v.to_radians()));
+        }
+
+        transform(source, target, &mut ProjectedGeometry(&mut projected))
+            .map_err(|error| Error::Projection(error.to_string()))?;
+
+        if target.is_latlong() {
+            projected.map_coordinates(|c| c.iter_mut().take(2).for_each(|v| *v = v.to_degrees()));
+        }
+
+        projected.set_srid(Some(to));
+
+        Ok(Cow::Owned(projected))
+    }
+
+    /// Reprojette un lot de géométries vers le SRID `to`.
+    ///
+    /// S'arrête à la première erreur : les géométries précédentes restent reprojetées.
+    pub fn reproject_batch(&mut self, geometries: &mut [Geometry], to: u32) -> Result<(), Error> {
+        geometries
+            .iter_mut()
+            .try_for_each(|geometry| self.reproject(geometry, to))
+    }
+
+    fn projections(&mut self, from: u32, to: u32) -> Result<&(Proj, Proj), Error> {
+        if !self.cache.contains_key(&(from, to)) {
+            let projections = (self.projection(from)?, self.projection(to)?);
+            self.cache.insert((from, to), projections);
+        }
+
+        Ok(&self.cache[&(from, to)])
+    }
+
+    fn projection(&self, srid: u32) -> Result<Proj, Error> {
+        let definition = self
+            .definitions
+            .get(&srid)
+            .ok_or(Error::UnknownSrid(srid))?;
+
+        Proj::from_proj_string(definition).map_err(|error| Error::Projection(error.to_string()))
+    }
+}
+
+/// Expose les sommets d'une géométrie à `proj4rs`.
+struct ProjectedGeometry<'a>(&'a mut Geometry);
+
+impl Transform for ProjectedGeometry<'_> {
+    fn transform_coordinates<F: TransformClosure>(
+        &mut self,
+        f: &mut F,
+    ) -> proj4rs::errors::Result<()> {
+        let mut result = Ok(());
+
+        self.0.map_coordinates(|c| {
+            if result.is_err() {
+                return;
+            }
+
+            let z = c.get(2).copied().unwrap_or_default();
+
+            match f(c[0], c[1], z) {
+                Ok((x, y, z)) => {
+                    c[0] = x;
+                    c[1] = y;
+                    if let Some(c_z) = c.get_mut(2) {
+                        *c_z = z;
+                    }
+                }
+                Err(error) => result = Err(error),
+            }
+        });
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{GeometryImpl as _, Point, Vector};
+
+    #[test]
+    fn test_reproject_batch() {
+        let mut geometries =
+            [Point::new([2.3522, 48.8566]), Point::new([0.0, 0.0])].map(|mut point| {
+                point.srid = Some(4326);
+                Geometry::from(point)
+            });
+
+        let mut reprojector = Reprojector::new();
+        reprojector
+            .reproject_batch(&mut geometries, 2154)
+            .expect("cannot reproject geometries");
+
+        let Geometry::Point(paris) = &geometries[0] else {
+            panic!("not a point")
+        };
+        assert_eq!(paris.srid, Some(2154));
+        assert!((paris.coordinates.x() - 652_470.0).abs() < 100.0);
+        assert!((paris.coordinates.y() - 6_862_035.0).abs() < 100.0);
+
+        reprojector
+            .reproject_batch(&mut geometries, 4326)
+            .expect("cannot reproject geometries");
+
+        let Geometry::Point(paris) = &geometries[0] else {
+            panic!("not a point")
+        };
+        let expected = Vector::new([2.3522, 48.8566]);
+        assert!((paris.coordinates.x() - expected.x()).abs() < 1e-6);
+        assert!((paris.coordinates.y() - expected.y()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_reprojected_borrows_when_srid_matches() {
+        let mut point = Point::new([2.3522, 48.8566]);
+        point.srid = Some(4326);
+        let geometry = Geometry::from(point);
+
+        let mut reprojector = Reprojector::new();
+
+        assert!(matches!(
+            reprojector.reprojected(&geometry, 4326),
+            Ok(Cow::Borrowed(_))
+        ));
+        assert!(matches!(
+            reprojector.reprojected(&geometry, 3857),
+            Ok(Cow::Owned(_))
+        ));
+    }
+
+    #[test]
+    fn test_unknown_srid() {
+        let mut point = Point::new([0.0, 0.0]);
+        point.srid = Some(4326);
+        let mut geometry = Geometry::from(point);
+
+        assert!(matches!(
+            Reprojector::new().reproject(&mut geometry, 9999),
+            Err(Error::UnknownSrid(9999))
+        ));
+        assert_eq!(geometry.srid(), Some(4326));
+    }
+}
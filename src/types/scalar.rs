@@ -0,0 +1,12 @@
+//! Scalaires utilisables comme coordonnées.
+use core::fmt::Debug;
+
+use num_traits::{Num, NumCast};
+
+/// Type numérique des coordonnées (`i32`, `i64`, `f32`, `f64`, ...).
+///
+/// Il fournit l'arithmétique de base, la comparaison (pour le calcul des emprises)
+/// et la conversion entre espaces de coordonnées.
+pub trait Scalar: Num + NumCast + Copy + PartialOrd + Debug {}
+
+impl<T> Scalar for T where T: Num + NumCast + Copy + PartialOrd + Debug {}
@@ -0,0 +1,264 @@
+//! Intersection robuste de segments 2D.
+//!
+//! Le test d'orientation est d'abord évalué en virgule flottante ; lorsque le
+//! résultat est trop proche de zéro pour que son signe soit fiable, il est
+//! réévalué en double précision étendue (double-double).
+
+use crate::prelude::*;
+
+/// Position d'un point par rapport à une droite orientée.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    /// À gauche de la droite.
+    CounterClockwise,
+    /// À droite de la droite.
+    Clockwise,
+    Collinear,
+}
+
+/// Orientation du triplet `(a, b, c)`, soit la position de `c` par rapport à `(ab)`.
+pub fn orientation(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> Orientation {
+    let det = orient2d(a, b, c);
+
+    if det > 0.0 {
+        Orientation::CounterClockwise
+    } else if det < 0.0 {
+        Orientation::Clockwise
+    } else {
+        Orientation::Collinear
+    }
+}
+
+/// Résultat de l'intersection de deux segments.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SegmentIntersection {
+    None,
+    /// Les segments se rencontrent en un seul point.
+    Point([f64; 2]),
+    /// Les segments sont colinéaires et se recouvrent sur `[début, fin]`.
+    Overlap([f64; 2], [f64; 2]),
+}
+
+/// Segment de droite dans le plan.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Segment {
+    pub start: [f64; 2],
+    pub end: [f64; 2],
+}
+
+impl Segment {
+    pub fn new(start: [f64; 2], end: [f64; 2]) -> Self {
+        Self { start, end }
+    }
+
+    /// Vrai si `p` appartient au segment.
+    pub fn contains_point(&self, p: [f64; 2]) -> bool {
+        orientation(self.start, self.end, p) == Orientation::Collinear && self.in_bounds(p)
+    }
+
+    /// Vrai si les deux segments ont au moins un point commun.
+    pub fn intersects(&self, other: &Segment) -> bool {
+        self.intersection(other) != SegmentIntersection::None
+    }
+
+    /// Vrai si les segments se croisent en un point intérieur à chacun d'eux.
+    pub fn crosses(&self, other: &Segment) -> bool {
+        use Orientation::*;
+
+        let opposite = |o1, o2| {
+            matches!(
+                (o1, o2),
+                (Clockwise, CounterClockwise) | (CounterClockwise, Clockwise)
+            )
+        };
+
+        opposite(
+            orientation(self.start, self.end, other.start),
+            orientation(self.start, self.end, other.end),
+        ) && opposite(
+            orientation(other.start, other.end, self.start),
+            orientation(other.start, other.end, self.end),
+        )
+    }
+
+    /// Intersection des deux segments.
+    ///
+    /// Lorsque le point d'intersection est une extrémité d'un des segments, cette
+    /// extrémité est renvoyée telle quelle, sans erreur d'arrondi.
+    pub fn intersection(&self, other: &Segment) -> SegmentIntersection {
+        let (a, b, c, d) = (self.start, self.end, other.start, other.end);
+
+        let o1 = orientation(a, b, c);
+        let o2 = orientation(a, b, d);
+        let o3 = orientation(c, d, a);
+        let o4 = orientation(c, d, b);
+
+        if o1 == Orientation::Collinear && o2 == Orientation::Collinear {
+            return self.collinear_overlap(other);
+        }
+
+        if o1 == o2 || o3 == o4 {
+            return SegmentIntersection::None;
+        }
+
+        let point = if o1 == Orientation::Collinear {
+            c
+        } else if o2 == Orientation::Collinear {
+            d
+        } else if o3 == Orientation::Collinear {
+            a
+        } else if o4 == Orientation::Collinear {
+            b
+        } else {
+            let r = [b[0] - a[0], b[1] - a[1]];
+            let s = [d[0] - c[0], d[1] - c[1]];
+            let t = ((c[0] - a[0]) * s[1] - (c[1] - a[1]) * s[0]) / (r[0] * s[1] - r[1] * s[0]);
+            let t = t.clamp(0.0, 1.0);
+
+            [a[0] + t * r[0], a[1] + t * r[1]]
+        };
+
+        SegmentIntersection::Point(point)
+    }
+
+    fn in_bounds(&self, p: [f64; 2]) -> bool {
+        let (a, b) = (self.start, self.end);
+
+        p[0] >= a[0].min(b[0])
+            && p[0] <= a[0].max(b[0])
+            && p[1] >= a[1].min(b[1])
+            && p[1] <= a[1].max(b[1])
+    }
+
+    fn collinear_overlap(&self, other: &Segment) -> SegmentIntersection {
+        // Projection sur l'axe dominant du segment.
+        let axis =
+            usize::from((self.end[1] - self.start[1]).abs() > (self.end[0] - self.start[0]).abs());
+
+        let mut points = [other.start, other.end]
+            .into_iter()
+            .filter(|p| self.in_bounds(*p))
+            .chain(
+                [self.start, self.end]
+                    .into_iter()
+                    .filter(|p| other.in_bounds(*p)),
+            )
+            .collect::<Vec<_>>();
+
+        points.sort_by(|p, q| p[axis].total_cmp(&q[axis]));
+        points.dedup();
+
+        match points.as_slice() {
+            [] => SegmentIntersection::None,
+            [p] => SegmentIntersection::Point(*p),
+            [first, .., last] => SegmentIntersection::Overlap(*first, *last),
+        }
+    }
+}
+
+/// Déterminant `(a - c) x (b - c)`, dont le signe est garanti.
+fn orient2d(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> f64 {
+    let left = (a[0] - c[0]) * (b[1] - c[1]);
+    let right = (a[1] - c[1]) * (b[0] - c[0]);
+    let det = left - right;
+
+    // Borne d'erreur de Shewchuk pour l'évaluation directe.
+    let bound = 3.330_669_073_875_471_6e-16 * (left.abs() + right.abs());
+
+    if det.abs() > bound {
+        det
+    } else {
+        orient2d_extended(a, b, c)
+    }
+}
+
+/// Évaluation en double-double du déterminant d'orientation.
+fn orient2d_extended(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> f64 {
+    let (adx, adx_err) = two_diff(a[0], c[0]);
+    let (bdy, bdy_err) = two_diff(b[1], c[1]);
+    let (ady, ady_err) = two_diff(a[1], c[1]);
+    let (bdx, bdx_err) = two_diff(b[0], c[0]);
+
+    let (left, left_err) = two_product(adx, bdy);
+    let (right, right_err) = two_product(ady, bdx);
+
+    let (det, det_err) = two_diff(left, right);
+
+    let tail = left_err - right_err + adx * bdy_err + adx_err * bdy - ady * bdx_err - ady_err * bdx
+        + adx_err * bdy_err
+        - ady_err * bdx_err;
+
+    det + (det_err + tail)
+}
+
+/// `a - b` sous forme `(arrondi, erreur)` exacte.
+fn two_diff(a: f64, b: f64) -> (f64, f64) {
+    let x = a - b;
+    let b_virtual = a - x;
+    let a_virtual = x + b_virtual;
+    (x, (a - a_virtual) + (b_virtual - b))
+}
+
+/// `a * b` sous forme `(arrondi, erreur)` exacte.
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let x = a * b;
+    (x, a.mul_add(b, -x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crossing_segments() {
+        let s1 = Segment::new([0.0, 0.0], [2.0, 2.0]);
+        let s2 = Segment::new([0.0, 2.0], [2.0, 0.0]);
+
+        assert_eq!(s1.intersection(&s2), SegmentIntersection::Point([1.0, 1.0]));
+        assert!(s1.crosses(&s2));
+    }
+
+    #[test]
+    fn test_touching_segments() {
+        let s1 = Segment::new([0.0, 0.0], [2.0, 0.0]);
+        let s2 = Segment::new([1.0, 0.0], [1.0, 5.0]);
+
+        assert_eq!(s1.intersection(&s2), SegmentIntersection::Point([1.0, 0.0]));
+        assert!(!s1.crosses(&s2));
+        assert_eq!(
+            s1.intersection(&Segment::new([3.0, 0.0], [3.0, 5.0])),
+            SegmentIntersection::None
+        );
+    }
+
+    #[test]
+    fn test_collinear_segments() {
+        let s1 = Segment::new([0.0, 0.0], [4.0, 0.0]);
+
+        assert_eq!(
+            s1.intersection(&Segment::new([6.0, 0.0], [2.0, 0.0])),
+            SegmentIntersection::Overlap([2.0, 0.0], [4.0, 0.0])
+        );
+        assert_eq!(
+            s1.intersection(&Segment::new([4.0, 0.0], [5.0, 0.0])),
+            SegmentIntersection::Point([4.0, 0.0])
+        );
+        assert_eq!(
+            s1.intersection(&Segment::new([5.0, 0.0], [6.0, 0.0])),
+            SegmentIntersection::None
+        );
+    }
+
+    #[test]
+    fn test_orientation_near_degenerate() {
+        // Points quasi alignés, mal classés par une évaluation naïve.
+        let (a, b) = ([0.5, 0.5], [12.0, 12.0]);
+        let c = [24.0, 24.0];
+
+        assert_eq!(orientation(a, b, c), Orientation::Collinear);
+        assert_eq!(
+            orientation(a, b, [24.0, 24.000_000_000_000_004]),
+            Orientation::CounterClockwise
+        );
+    }
+}
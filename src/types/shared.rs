@@ -0,0 +1,95 @@
+//! Géométrie partagée, dont le clonage ne copie pas les coordonnées.
+use alloc::sync::Arc;
+use core::ops::Deref;
+
+use super::{Geometry, GeometryRef};
+
+/// Géométrie partagée entre plusieurs propriétaires (requêtes, threads de travail).
+///
+/// Cloner une [SharedGeometry] n'incrémente qu'un compteur de références ; les coordonnées
+/// ne sont copiées qu'à la première modification d'une géométrie encore partagée
+/// (voir [SharedGeometry::make_mut]).
+///
+/// Elle s'encode sans copie via [SharedGeometry::as_geometry_ref].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SharedGeometry(Arc<Geometry>);
+
+impl SharedGeometry {
+    pub fn new(geometry: Geometry) -> Self {
+        Self(Arc::new(geometry))
+    }
+
+    /// Accès en écriture, en copiant d'abord la géométrie si elle est encore partagée.
+    pub fn make_mut(&mut self) -> &mut Geometry {
+        Arc::make_mut(&mut self.0)
+    }
+
+    /// Vrai si les deux géométries partagent les mêmes coordonnées.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+
+    /// Récupère la géométrie, clonée seulement si elle est encore partagée.
+    pub fn into_inner(self) -> Geometry {
+        Arc::unwrap_or_clone(self.0)
+    }
+
+    /// Emprunte la géométrie, sans la cloner.
+    pub fn as_geometry_ref(&self) -> GeometryRef<'_> {
+        self.0.as_geometry_ref()
+    }
+}
+
+impl Deref for SharedGeometry {
+    type Target = Geometry;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<Geometry> for SharedGeometry {
+    fn as_ref(&self) -> &Geometry {
+        &self.0
+    }
+}
+
+impl From<Geometry> for SharedGeometry {
+    fn from(value: Geometry) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<SharedGeometry> for Geometry {
+    fn from(value: SharedGeometry) -> Self {
+        value.into_inner()
+    }
+}
+
+impl<'a> From<&'a SharedGeometry> for GeometryRef<'a> {
+    fn from(value: &'a SharedGeometry) -> Self {
+        value.as_geometry_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{GeometryImpl as _, Point};
+
+    #[test]
+    fn test_copy_on_write() {
+        let original = SharedGeometry::from(Geometry::from(Point::new([1.0, 2.0])));
+
+        let mut copy = original.clone();
+        assert!(copy.ptr_eq(&original));
+        assert_eq!(GeometryRef::from(&copy), original.as_geometry_ref());
+
+        copy.make_mut().set_srid(Some(4326));
+        assert!(!copy.ptr_eq(&original));
+        assert_eq!(original.srid(), None);
+        assert_eq!(copy.srid(), Some(4326));
+
+        assert_eq!(copy.into_inner().srid(), Some(4326));
+    }
+}
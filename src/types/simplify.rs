@@ -0,0 +1,124 @@
+//! Simplification de Ramer–Douglas–Peucker, équivalent local de `ST_Simplify`.
+use super::{
+    distance::segment_distance, line_string::LineString, planar::coord, polygon::Polygon,
+    VectorArray,
+};
+use crate::prelude::*;
+
+impl<const N: usize> LineString<N, f64> {
+    /// Simplifie la ligne en retirant les sommets situés à moins de `tolerance`
+    /// de la ligne simplifiée. Les extrémités sont toujours conservées.
+    pub fn simplify(&self, tolerance: f64) -> Self {
+        Self {
+            coordinates: simplify_array(&self.coordinates, tolerance),
+            srid: self.srid,
+        }
+    }
+}
+
+impl<const N: usize> Polygon<N, f64> {
+    /// Simplifie chaque anneau du polygone.
+    ///
+    /// Un anneau qui deviendrait dégénéré (moins de 4 points) est conservé tel quel.
+    pub fn simplify(&self, tolerance: f64) -> Self {
+        Self {
+            coordinates: self
+                .coordinates
+                .iter()
+                .map(|ring| {
+                    let simplified = simplify_array(ring, tolerance);
+
+                    if simplified.len() < 4 {
+                        ring.clone()
+                    } else {
+                        simplified
+                    }
+                })
+                .collect(),
+            srid: self.srid,
+        }
+    }
+}
+
+fn simplify_array<const N: usize>(
+    array: &VectorArray<N, f64>,
+    tolerance: f64,
+) -> VectorArray<N, f64> {
+    if array.len() < 3 {
+        return array.clone();
+    }
+
+    let mut keep = vec![false; array.len()];
+    keep[0] = true;
+    keep[array.len() - 1] = true;
+
+    // Pile des intervalles [début, fin] restant à traiter.
+    let mut stack = vec![(0, array.len() - 1)];
+
+    while let Some((start, end)) = stack.pop() {
+        let (a, b) = (coord(&array[start]), coord(&array[end]));
+
+        let farthest = (start + 1..end)
+            .map(|i| (i, segment_distance(a, b, coord(&array[i]))))
+            .max_by(|(_, d1), (_, d2)| d1.total_cmp(d2));
+
+        if let Some((i, distance)) = farthest {
+            if distance > tolerance {
+                keep[i] = true;
+                stack.push((start, i));
+                stack.push((i, end));
+            }
+        }
+    }
+
+    array
+        .iter()
+        .zip(keep)
+        .filter(|(_, keep)| *keep)
+        .map(|(v, _)| *v)
+        .collect::<VectorArray<N, f64>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{GeometryImpl as _, LineString, Polygon};
+
+    #[test]
+    fn test_simplify_line_string() {
+        let line = LineString::new([
+            [0.0, 0.0],
+            [1.0, 0.1],
+            [2.0, -0.1],
+            [3.0, 5.0],
+            [4.0, 6.0],
+            [5.0, 7.0],
+        ]);
+
+        assert_eq!(
+            line.simplify(0.5),
+            LineString::new([[0.0, 0.0], [2.0, -0.1], [3.0, 5.0], [5.0, 7.0]])
+        );
+        // Seul le sommet aligné est retiré avec une tolérance nulle.
+        assert_eq!(line.simplify(0.0).coordinates.len(), 5);
+    }
+
+    #[test]
+    fn test_simplify_polygon() {
+        let polygon = Polygon::new([
+            [0.0, 0.0],
+            [5.0, 0.1],
+            [10.0, 0.0],
+            [10.0, 10.0],
+            [0.0, 10.0],
+        ]);
+
+        assert_eq!(
+            polygon.simplify(1.0),
+            Polygon::new([[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0]])
+        );
+
+        // Un triangle ne peut pas être réduit davantage.
+        let triangle = Polygon::new([[0.0, 0.0], [10.0, 0.0], [5.0, 0.5]]);
+        assert_eq!(triangle.simplify(1.0), triangle);
+    }
+}
@@ -0,0 +1,141 @@
+//! Clés de tri suivant des courbes de remplissage (Hilbert, Morton), pour ordonner
+//! les géométries avant un chargement en masse dans un index spatial.
+use super::{Geometry, MBR2D};
+
+impl MBR2D {
+    /// Centre du rectangle, ramené dans `[0, 1]²` relativement à `extent`.
+    fn normalized_center(&self, extent: &MBR2D) -> (f64, f64) {
+        let normalize = |v: f64, min: f64, max: f64| {
+            if max > min {
+                ((v - min) / (max - min)).clamp(0.0, 1.0)
+            } else {
+                0.0
+            }
+        };
+
+        (
+            normalize(
+                (self.min_x() + self.max_x()) / 2.0,
+                extent.min_x(),
+                extent.max_x(),
+            ),
+            normalize(
+                (self.min_y() + self.max_y()) / 2.0,
+                extent.min_y(),
+                extent.max_y(),
+            ),
+        )
+    }
+
+    /// Position du centre du rectangle sur la courbe de Hilbert d'ordre `order`
+    /// (borné à 31) couvrant `extent`.
+    pub fn hilbert_index(&self, extent: &MBR2D, order: u32) -> u64 {
+        let order = order.clamp(1, 31);
+        let side = (1u64 << order) - 1;
+        let (x, y) = self.normalized_center(extent);
+
+        hilbert_xy_to_index(order, (x * side as f64) as u64, (y * side as f64) as u64)
+    }
+
+    /// Position du centre du rectangle sur la courbe de Morton (ordre Z) couvrant
+    /// `extent`, avec 32 bits par axe.
+    pub fn morton_index(&self, extent: &MBR2D) -> u64 {
+        let (x, y) = self.normalized_center(extent);
+        let side = u32::MAX as f64;
+
+        spread_bits((x * side) as u32) | (spread_bits((y * side) as u32) << 1)
+    }
+}
+
+/// Trie les géométries suivant la courbe de Hilbert de leur rectangle englobant.
+pub fn sort_geometries_hilbert(geometries: &mut [Geometry]) {
+    let Some(extent) = MBR2D::from_geometries(geometries.iter()) else {
+        return;
+    };
+
+    // Les géométries vides sont placées en fin de liste.
+    geometries.sort_by_cached_key(|geometry| {
+        geometry
+            .mbr()
+            .map_or(u64::MAX, |mbr| mbr.hilbert_index(&extent, 16))
+    });
+}
+
+/// Conversion (x, y) vers l'indice sur la courbe de Hilbert de côté `2^order`.
+fn hilbert_xy_to_index(order: u32, mut x: u64, mut y: u64) -> u64 {
+    let n = 1u64 << order;
+    let mut index = 0;
+    let mut s = n / 2;
+
+    while s > 0 {
+        let rx = u64::from(x & s > 0);
+        let ry = u64::from(y & s > 0);
+        index += s * s * ((3 * rx) ^ ry);
+
+        // Rotation du quadrant.
+        if ry == 0 {
+            if rx == 1 {
+                x = n - 1 - x;
+                y = n - 1 - y;
+            }
+            core::mem::swap(&mut x, &mut y);
+        }
+
+        s /= 2;
+    }
+
+    index
+}
+
+/// Intercale un bit nul entre chaque bit de `v`.
+fn spread_bits(v: u32) -> u64 {
+    let mut v = u64::from(v);
+    v = (v | (v << 16)) & 0x0000_ffff_0000_ffff;
+    v = (v | (v << 8)) & 0x00ff_00ff_00ff_00ff;
+    v = (v | (v << 4)) & 0x0f0f_0f0f_0f0f_0f0f;
+    v = (v | (v << 2)) & 0x3333_3333_3333_3333;
+    v = (v | (v << 1)) & 0x5555_5555_5555_5555;
+    v
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{GeometryImpl as _, Point};
+
+    use super::*;
+
+    #[test]
+    fn test_hilbert_order_1() {
+        // La courbe d'ordre 1 parcourt (0,0), (0,1), (1,1), (1,0).
+        assert_eq!(hilbert_xy_to_index(1, 0, 0), 0);
+        assert_eq!(hilbert_xy_to_index(1, 0, 1), 1);
+        assert_eq!(hilbert_xy_to_index(1, 1, 1), 2);
+        assert_eq!(hilbert_xy_to_index(1, 1, 0), 3);
+    }
+
+    #[test]
+    fn test_morton_index() {
+        let extent = MBR2D::new([0.0, 0.0], [1.0, 1.0]);
+        let corner = |x: f64, y: f64| Point::new([x, y]).mbr().unwrap().morton_index(&extent);
+
+        assert_eq!(corner(0.0, 0.0), 0);
+        assert!(corner(1.0, 0.0) < corner(0.0, 1.0));
+        assert_eq!(corner(1.0, 1.0), u64::MAX);
+    }
+
+    #[test]
+    fn test_sort_geometries_hilbert() {
+        let mut geometries = [[1.0, 0.0], [0.0, 0.0], [1.0, 1.0], [0.0, 1.0]]
+            .map(|c| Geometry::from(Point::new(c)))
+            .to_vec();
+
+        sort_geometries_hilbert(&mut geometries);
+
+        assert_eq!(
+            geometries,
+            [[0.0, 0.0], [0.0, 1.0], [1.0, 1.0], [1.0, 0.0]]
+                .map(|c| Geometry::from(Point::new(c)))
+                .to_vec()
+        );
+    }
+}
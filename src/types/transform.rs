@@ -0,0 +1,243 @@
+//! Parcours des coordonnées et transformations affines.
+// Sans `std`, les fonctions mathématiques de `f64` viennent de `libm` ; l'import
+// est inutile lorsqu'une autre dépendance lie `std`.
+use alloc::borrow::Cow;
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use num_traits::Float as _;
+
+use super::{CowGeometry, Geometry, Vector, VectorArray, VectorMatrix, VectorTensor};
+
+impl Geometry {
+    /// Applique `f` à chaque sommet de la géométrie, sous la forme `[x, y]` ou `[x, y, z]`.
+    ///
+    /// C'est la primitive sur laquelle reposent les transformations de coordonnées.
+    pub fn map_coordinates<F: FnMut(&mut [f64])>(&mut self, mut f: F) {
+        match self {
+            Geometry::Point(a) => map_vector(&mut a.coordinates, &mut f),
+            Geometry::LineString(a) => map_array(&mut a.coordinates, &mut f),
+            Geometry::Polygon(a) => map_matrix(&mut a.coordinates, &mut f),
+            Geometry::MultiPoint(a) => map_array(&mut a.coordinates, &mut f),
+            Geometry::MultiLineString(a) => map_matrix(&mut a.coordinates, &mut f),
+            Geometry::MultiPolygon(a) => map_tensor(&mut a.coordinates, &mut f),
+            Geometry::PointZ(a) => map_vector(&mut a.coordinates, &mut f),
+            Geometry::LineStringZ(a) => map_array(&mut a.coordinates, &mut f),
+            Geometry::PolygonZ(a) => map_matrix(&mut a.coordinates, &mut f),
+            Geometry::MultiPointZ(a) => map_array(&mut a.coordinates, &mut f),
+            Geometry::MultiLineStringZ(a) => map_matrix(&mut a.coordinates, &mut f),
+            Geometry::MultiPolygonZ(a) => map_tensor(&mut a.coordinates, &mut f),
+        }
+    }
+
+    /// Renvoie la géométrie transformée par `transform`.
+    pub fn affine_transform(&self, transform: &AffineTransform) -> Geometry {
+        let mut geometry = self.clone();
+        geometry.map_coordinates(|c| transform.apply(c));
+        geometry
+    }
+
+    /// Comme [Geometry::affine_transform], mais emprunte la géométrie,
+    /// sans copie, si la transformation est l'identité.
+    pub fn affine_transform_cow(&self, transform: &AffineTransform) -> CowGeometry<'_> {
+        if transform.is_identity() {
+            Cow::Borrowed(self)
+        } else {
+            Cow::Owned(self.affine_transform(transform))
+        }
+    }
+}
+
+fn map_vector<const N: usize, F: FnMut(&mut [f64])>(vector: &mut Vector<N, f64>, f: &mut F) {
+    f(&mut vector[..])
+}
+
+fn map_array<const N: usize, F: FnMut(&mut [f64])>(array: &mut VectorArray<N, f64>, f: &mut F) {
+    array.iter_mut().for_each(|v| map_vector(v, f))
+}
+
+fn map_matrix<const N: usize, F: FnMut(&mut [f64])>(matrix: &mut VectorMatrix<N, f64>, f: &mut F) {
+    matrix.iter_mut().for_each(|a| map_array(a, f))
+}
+
+fn map_tensor<const N: usize, F: FnMut(&mut [f64])>(tensor: &mut VectorTensor<N, f64>, f: &mut F) {
+    tensor.iter_mut().for_each(|m| map_matrix(m, f))
+}
+
+/// Transformation affine 3D, équivalent local de `ST_Affine`.
+///
+/// Chaque ligne de la matrice `[a, b, c, offset]` calcule une coordonnée :
+/// `x' = a x + b y + c z + x_offset`. Les géométries 2D ignorent la troisième
+/// ligne et la troisième colonne.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AffineTransform {
+    pub matrix: [[f64; 4]; 3],
+}
+
+impl Default for AffineTransform {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl AffineTransform {
+    pub fn identity() -> Self {
+        Self {
+            matrix: [
+                [1.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 0.0],
+            ],
+        }
+    }
+
+    pub fn is_identity(&self) -> bool {
+        *self == Self::identity()
+    }
+
+    /// Transformation 2D `x' = a x + b y + x_offset`, `y' = d x + e y + y_offset`.
+    pub fn new_2d(a: f64, b: f64, d: f64, e: f64, x_offset: f64, y_offset: f64) -> Self {
+        Self {
+            matrix: [
+                [a, b, 0.0, x_offset],
+                [d, e, 0.0, y_offset],
+                [0.0, 0.0, 1.0, 0.0],
+            ],
+        }
+    }
+
+    pub fn translate(dx: f64, dy: f64) -> Self {
+        Self::translate_3d(dx, dy, 0.0)
+    }
+
+    pub fn translate_3d(dx: f64, dy: f64, dz: f64) -> Self {
+        let mut transform = Self::identity();
+        transform.matrix[0][3] = dx;
+        transform.matrix[1][3] = dy;
+        transform.matrix[2][3] = dz;
+        transform
+    }
+
+    pub fn scale(sx: f64, sy: f64) -> Self {
+        Self::scale_3d(sx, sy, 1.0)
+    }
+
+    pub fn scale_3d(sx: f64, sy: f64, sz: f64) -> Self {
+        let mut transform = Self::identity();
+        transform.matrix[0][0] = sx;
+        transform.matrix[1][1] = sy;
+        transform.matrix[2][2] = sz;
+        transform
+    }
+
+    /// Rotation de `angle` radians dans le sens direct autour de `origin`, dans le plan XY.
+    pub fn rotate(angle: f64, origin: [f64; 2]) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let [ox, oy] = origin;
+
+        Self::new_2d(
+            cos,
+            -sin,
+            sin,
+            cos,
+            ox - cos * ox + sin * oy,
+            oy - sin * ox - cos * oy,
+        )
+    }
+
+    /// Transformation appliquant `self` puis `next`.
+    pub fn then(&self, next: &AffineTransform) -> Self {
+        let (m, n) = (&self.matrix, &next.matrix);
+
+        Self {
+            matrix: core::array::from_fn(|i| {
+                core::array::from_fn(|j| {
+                    let translation = if j == 3 { n[i][3] } else { 0.0 };
+                    (0..3).map(|k| n[i][k] * m[k][j]).sum::<f64>() + translation
+                })
+            }),
+        }
+    }
+
+    /// Transforme un sommet `[x, y]` ou `[x, y, z]` en place.
+    pub fn apply(&self, coordinates: &mut [f64]) {
+        let m = &self.matrix;
+
+        match coordinates {
+            [x, y] => {
+                (*x, *y) = (
+                    m[0][0] * *x + m[0][1] * *y + m[0][3],
+                    m[1][0] * *x + m[1][1] * *y + m[1][3],
+                );
+            }
+            [x, y, z, ..] => {
+                (*x, *y, *z) = (
+                    m[0][0] * *x + m[0][1] * *y + m[0][2] * *z + m[0][3],
+                    m[1][0] * *x + m[1][1] * *y + m[1][2] * *z + m[1][3],
+                    m[2][0] * *x + m[2][1] * *y + m[2][2] * *z + m[2][3],
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::FRAC_PI_2;
+
+    use crate::types::{GeometryImpl as _, LineString, PointZ};
+
+    use super::*;
+
+    fn round(geometry: Geometry) -> Geometry {
+        let mut geometry = geometry;
+        geometry.map_coordinates(|c| c.iter_mut().for_each(|v| *v = v.round()));
+        geometry
+    }
+
+    #[test]
+    fn test_affine_transform_cow() {
+        let line = Geometry::from(LineString::new([[1.0, 1.0], [2.0, 1.0]]));
+
+        assert!(matches!(
+            line.affine_transform_cow(&AffineTransform::identity()),
+            Cow::Borrowed(_)
+        ));
+        assert_eq!(
+            line.affine_transform_cow(&AffineTransform::scale(2.0, 2.0))
+                .into_owned(),
+            LineString::new([[2.0, 2.0], [4.0, 2.0]]).into()
+        );
+    }
+
+    #[test]
+    fn test_affine_transform() {
+        let line = Geometry::from(LineString::new([[1.0, 1.0], [2.0, 1.0]]));
+
+        assert_eq!(
+            line.affine_transform(&AffineTransform::translate(1.0, -1.0)),
+            LineString::new([[2.0, 0.0], [3.0, 0.0]]).into()
+        );
+        assert_eq!(
+            round(line.affine_transform(&AffineTransform::rotate(FRAC_PI_2, [1.0, 1.0]))),
+            LineString::new([[1.0, 1.0], [1.0, 2.0]]).into()
+        );
+
+        let transform =
+            AffineTransform::scale(2.0, 2.0).then(&AffineTransform::translate(1.0, 0.0));
+        assert_eq!(
+            line.affine_transform(&transform),
+            LineString::new([[3.0, 2.0], [5.0, 2.0]]).into()
+        );
+    }
+
+    #[test]
+    fn test_affine_transform_3d() {
+        let point = Geometry::from(PointZ::new([1.0, 2.0, 3.0]));
+
+        assert_eq!(
+            point.affine_transform(&AffineTransform::scale_3d(2.0, 2.0, 2.0)),
+            PointZ::new([2.0, 4.0, 6.0]).into()
+        );
+    }
+}
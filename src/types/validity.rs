@@ -0,0 +1,307 @@
+//! Validité OGC des géométries, afin de rejeter les données invalides avant PostGIS.
+use core::fmt;
+
+use super::{
+    planar::{
+        locate_in_polygon, parts, segment_intersections, segments, segments_cross, Coord, Location,
+        Part,
+    },
+    Geometry,
+};
+use crate::prelude::*;
+
+/// Défaut rendant une géométrie invalide.
+///
+/// `part` est l'indice du composant dans une géométrie multiple (0 sinon) et
+/// `ring` l'indice de l'anneau dans un polygone.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationError {
+    /// Le premier et le dernier point de l'anneau diffèrent.
+    UnclosedRing { part: usize, ring: usize },
+    /// Une ligne a moins de 2 points, ou un anneau moins de 4.
+    TooFewPoints {
+        part: usize,
+        ring: Option<usize>,
+        got: usize,
+    },
+    /// Deux points consécutifs sont identiques.
+    DuplicatePoint {
+        part: usize,
+        ring: Option<usize>,
+        index: usize,
+    },
+    /// Un anneau se recoupe, ou croise un autre anneau du même polygone.
+    SelfIntersection {
+        part: usize,
+        ring: usize,
+        at: [f64; 2],
+    },
+    /// Un trou n'est pas contenu dans l'anneau extérieur.
+    HoleOutsideShell { part: usize, ring: usize },
+    /// L'anneau ne tourne pas dans le sens imposé par [super::RingOrientation].
+    WrongOrientation { part: usize, ring: usize },
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnclosedRing { part, ring } => {
+                write!(f, "ring {ring} of part {part} is not closed")
+            }
+            Self::TooFewPoints {
+                part,
+                ring: Some(ring),
+                got,
+            } => {
+                write!(f, "ring {ring} of part {part} has too few points ({got})")
+            }
+            Self::TooFewPoints {
+                part,
+                ring: None,
+                got,
+            } => {
+                write!(f, "part {part} has too few points ({got})")
+            }
+            Self::DuplicatePoint {
+                part,
+                ring: Some(ring),
+                index,
+            } => {
+                write!(f, "ring {ring} of part {part} repeats point {index}")
+            }
+            Self::DuplicatePoint {
+                part,
+                ring: None,
+                index,
+            } => {
+                write!(f, "part {part} repeats point {index}")
+            }
+            Self::SelfIntersection { part, ring, at } => write!(
+                f,
+                "ring {ring} of part {part} self-intersects at ({}, {})",
+                at[0], at[1]
+            ),
+            Self::HoleOutsideShell { part, ring } => {
+                write!(f, "hole {ring} of part {part} lies outside the shell")
+            }
+            Self::WrongOrientation { part, ring } => {
+                write!(f, "ring {ring} of part {part} has the wrong orientation")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ValidationError {}
+
+impl Geometry {
+    /// Vrai si la géométrie est valide au sens de [Geometry::validate].
+    pub fn is_valid(&self) -> bool {
+        self.validate().is_empty()
+    }
+
+    /// Liste les défauts de la géométrie : anneaux non fermés, auto-intersections,
+    /// trous hors de l'extérieur, points consécutifs dupliqués et nombre de points
+    /// insuffisant.
+    pub fn validate(&self) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+
+        for (part, component) in parts(self).iter().enumerate() {
+            match component {
+                Part::Point(_) => {}
+                Part::Line(line) => {
+                    if line.len() < 2 {
+                        errors.push(ValidationError::TooFewPoints {
+                            part,
+                            ring: None,
+                            got: line.len(),
+                        });
+                    }
+                    check_duplicates(line, part, None, &mut errors);
+                }
+                Part::Polygon(rings) => validate_polygon(rings, part, &mut errors),
+            }
+        }
+
+        errors
+    }
+}
+
+fn check_duplicates(
+    line: &[Coord],
+    part: usize,
+    ring: Option<usize>,
+    errors: &mut Vec<ValidationError>,
+) {
+    errors.extend(
+        line.windows(2)
+            .enumerate()
+            .filter(|(_, w)| w[0] == w[1])
+            .map(|(i, _)| ValidationError::DuplicatePoint {
+                part,
+                ring,
+                index: i + 1,
+            }),
+    );
+}
+
+fn validate_polygon(rings: &[Vec<Coord>], part: usize, errors: &mut Vec<ValidationError>) {
+    let nb_errors = errors.len();
+
+    for (i, ring) in rings.iter().enumerate() {
+        if ring.first() != ring.last() {
+            errors.push(ValidationError::UnclosedRing { part, ring: i });
+        }
+
+        if ring.len() < 4 {
+            errors.push(ValidationError::TooFewPoints {
+                part,
+                ring: Some(i),
+                got: ring.len(),
+            });
+        }
+
+        check_duplicates(ring, part, Some(i), errors);
+    }
+
+    // Les tests topologiques supposent des anneaux bien formés.
+    if errors.len() > nb_errors {
+        return;
+    }
+
+    for (i, ring) in rings.iter().enumerate() {
+        if let Some(at) = ring_self_intersection(ring) {
+            errors.push(ValidationError::SelfIntersection { part, ring: i, at });
+        }
+    }
+
+    for (i, hole) in rings.iter().enumerate().skip(1) {
+        let shell = &rings[..1];
+
+        if hole
+            .iter()
+            .any(|p| locate_in_polygon(shell, *p) == Location::Exterior)
+        {
+            errors.push(ValidationError::HoleOutsideShell { part, ring: i });
+        }
+
+        let crossing = rings.iter().take(i).find_map(|other| {
+            segments(hole).find_map(|(a, b)| {
+                segments(other)
+                    .find(|(c, d)| segments_cross(a, b, *c, *d))
+                    .and_then(|(c, d)| segment_intersections((a, b), (c, d)).first().copied())
+            })
+        });
+
+        if let Some((x, y)) = crossing {
+            errors.push(ValidationError::SelfIntersection {
+                part,
+                ring: i,
+                at: [x, y],
+            });
+        }
+    }
+}
+
+/// Premier point où deux arêtes non adjacentes de l'anneau se rencontrent.
+fn ring_self_intersection(ring: &[Coord]) -> Option<[f64; 2]> {
+    let edges = segments(ring).collect::<Vec<_>>();
+    let n = edges.len();
+
+    for i in 0..n {
+        for j in i + 1..n {
+            // Les arêtes consécutives (et la première avec la dernière) partagent un sommet.
+            if j == i + 1 || (i == 0 && j == n - 1) {
+                continue;
+            }
+
+            if let Some((x, y)) = segment_intersections(edges[i], edges[j]).first() {
+                return Some([*x, *y]);
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{GeometryImpl as _, LineString, Polygon, VectorArray2D, VectorMatrix2D};
+
+    use super::*;
+
+    #[test]
+    fn test_valid_polygon() {
+        let polygon = Geometry::from(Polygon::new((
+            [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0]],
+            [[4.0, 4.0], [6.0, 4.0], [6.0, 6.0], [4.0, 6.0]],
+        )));
+
+        assert!(polygon.is_valid());
+    }
+
+    #[test]
+    fn test_bow_tie() {
+        let polygon = Geometry::from(Polygon::new([
+            [0.0, 0.0],
+            [10.0, 10.0],
+            [10.0, 0.0],
+            [0.0, 10.0],
+        ]));
+
+        assert_eq!(
+            polygon.validate(),
+            vec![ValidationError::SelfIntersection {
+                part: 0,
+                ring: 0,
+                at: [5.0, 5.0]
+            }]
+        );
+    }
+
+    #[test]
+    fn test_hole_outside_shell() {
+        let polygon = Geometry::from(Polygon::new((
+            [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0]],
+            [[20.0, 20.0], [30.0, 20.0], [30.0, 30.0]],
+        )));
+
+        assert_eq!(
+            polygon.validate(),
+            vec![ValidationError::HoleOutsideShell { part: 0, ring: 1 }]
+        );
+    }
+
+    #[test]
+    fn test_malformed_rings_and_lines() {
+        // Les constructeurs ferment les anneaux, on construit donc les coordonnées à la main.
+        let mut polygon = Polygon::new([[0.0, 0.0], [10.0, 0.0], [10.0, 10.0]]);
+        polygon.coordinates = VectorMatrix2D::new(vec![VectorArray2D::from([
+            [0.0, 0.0],
+            [10.0, 0.0],
+            [10.0, 0.0],
+            [10.0, 10.0],
+        ])]);
+
+        assert_eq!(
+            Geometry::from(polygon).validate(),
+            vec![
+                ValidationError::UnclosedRing { part: 0, ring: 0 },
+                ValidationError::DuplicatePoint {
+                    part: 0,
+                    ring: Some(0),
+                    index: 2
+                },
+            ]
+        );
+
+        let line = Geometry::from(LineString::new([[0.0, 0.0]]));
+        assert_eq!(
+            line.validate(),
+            vec![ValidationError::TooFewPoints {
+                part: 0,
+                ring: None,
+                got: 1
+            }]
+        );
+    }
+}
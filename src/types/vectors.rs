@@ -1,7 +1,13 @@
-use std::ops::{Deref, DerefMut};
+use core::ops::{Add, Deref, DerefMut, Div, Index, IndexMut, Mul, Neg, Sub};
+
+use num_traits::{Float, NumCast};
+use smallvec::SmallVec;
+
+use super::{Scalar, MBR};
+use crate::prelude::*;
 
 /// Un vecteur dimension N.
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 pub struct Vector<const N: usize, U>([U; N]);
 
 impl<const N: usize, U> From<[U; N]> for Vector<N, U> {
@@ -16,6 +22,13 @@ impl<const N: usize, U> Vector<N, U> {
     }
 }
 
+/// Vecteur nul.
+impl<const N: usize, U: Scalar> Default for Vector<N, U> {
+    fn default() -> Self {
+        Self([U::zero(); N])
+    }
+}
+
 impl<const N: usize, U> Vector<N, U>
 where
     U: Copy,
@@ -33,9 +46,22 @@ where
     }
 }
 
+impl<const N: usize, U> Vector<N, U>
+where
+    U: Scalar,
+{
+    /// Emprise réduite au vecteur, `None` si une des coordonnées est NaN.
+    pub fn mbr(&self) -> Option<MBR<N, U>> {
+        self.0
+            .iter()
+            .all(|v| v.partial_cmp(v).is_some())
+            .then(|| MBR::new(self.0, self.0))
+    }
+}
+
 impl<const N: usize, U> IntoIterator for Vector<N, U> {
     type Item = U;
-    type IntoIter = std::array::IntoIter<U, N>;
+    type IntoIter = core::array::IntoIter<U, N>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.0.into_iter()
@@ -56,13 +82,239 @@ impl<const N: usize, U> DerefMut for Vector<N, U> {
     }
 }
 
+/// Implémente une opération terme à terme entre deux vecteurs, par valeur et par référence.
+macro_rules! impl_vector_op {
+    ($trait:ident, $method:ident) => {
+        impl<const N: usize, U> $trait for Vector<N, U>
+        where
+            U: Copy + $trait<Output = U>,
+        {
+            type Output = Vector<N, U>;
+
+            fn $method(self, rhs: Self) -> Self::Output {
+                (&self).$method(&rhs)
+            }
+        }
+
+        impl<const N: usize, U> $trait for &Vector<N, U>
+        where
+            U: Copy + $trait<Output = U>,
+        {
+            type Output = Vector<N, U>;
+
+            fn $method(self, rhs: Self) -> Self::Output {
+                Vector(core::array::from_fn(|i| self.0[i].$method(rhs.0[i])))
+            }
+        }
+    };
+}
+
+/// Implémente une opération entre un vecteur et un scalaire.
+macro_rules! impl_vector_scalar_op {
+    ($trait:ident, $method:ident) => {
+        impl<const N: usize, U> $trait<U> for Vector<N, U>
+        where
+            U: Copy + $trait<Output = U>,
+        {
+            type Output = Vector<N, U>;
+
+            fn $method(self, rhs: U) -> Self::Output {
+                Vector(self.0.map(|v| v.$method(rhs)))
+            }
+        }
+
+        impl<const N: usize, U> $trait<U> for &Vector<N, U>
+        where
+            U: Copy + $trait<Output = U>,
+        {
+            type Output = Vector<N, U>;
+
+            fn $method(self, rhs: U) -> Self::Output {
+                Vector(self.0.map(|v| v.$method(rhs)))
+            }
+        }
+    };
+}
+
+impl_vector_op!(Add, add);
+impl_vector_op!(Sub, sub);
+impl_vector_scalar_op!(Mul, mul);
+impl_vector_scalar_op!(Div, div);
+
+impl<const N: usize, U> Neg for Vector<N, U>
+where
+    U: Neg<Output = U>,
+{
+    type Output = Vector<N, U>;
+
+    fn neg(self) -> Self::Output {
+        Vector(self.0.map(Neg::neg))
+    }
+}
+
+impl<const N: usize, U> Vector<N, U>
+where
+    U: Scalar,
+{
+    /// Produit scalaire.
+    pub fn dot(&self, other: &Self) -> U {
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .fold(U::zero(), |acc, (a, b)| acc + *a * *b)
+    }
+
+    /// Conversion vers un autre espace de coordonnées, `None` si une des
+    /// coordonnées n'est pas représentable.
+    pub fn cast<V: Scalar>(&self) -> Option<Vector<N, V>> {
+        all_some(self.0.map(<V as NumCast>::from)).map(Vector)
+    }
+}
+
+impl<U> Vector<2, U>
+where
+    U: Copy + Sub<Output = U> + Mul<Output = U>,
+{
+    /// Produit vectoriel en 2D, soit la composante Z du produit des vecteurs plongés en 3D.
+    pub fn cross(&self, other: &Self) -> U {
+        self.x() * other.y() - self.y() * other.x()
+    }
+}
+
+impl<U> Vector<3, U>
+where
+    U: Copy + Sub<Output = U> + Mul<Output = U>,
+{
+    /// Produit vectoriel.
+    pub fn cross(&self, other: &Self) -> Self {
+        let (a, b) = (&self.0, &other.0);
+
+        Vector([
+            a[1] * b[2] - a[2] * b[1],
+            a[2] * b[0] - a[0] * b[2],
+            a[0] * b[1] - a[1] * b[0],
+        ])
+    }
+}
+
+impl<const N: usize, U> Vector<N, U>
+where
+    U: Scalar + Float,
+{
+    /// Norme euclidienne.
+    pub fn norm(&self) -> U {
+        self.dot(self).sqrt()
+    }
+
+    /// Vecteur unitaire de même direction, `None` pour le vecteur nul.
+    pub fn normalize(&self) -> Option<Self> {
+        let norm = self.norm();
+        (norm > U::zero() && norm.is_finite()).then(|| self / norm)
+    }
+}
+
+/// Nombre de vecteurs traités de front lors du calcul d'une emprise.
+const EXTENT_LANES: usize = 8;
+
+/// Emprise d'une suite de vecteurs ; les coordonnées NaN sont ignorées axe par axe.
+///
+/// Les vecteurs sont parcourus par paquets de [EXTENT_LANES], chaque voie ayant ses
+/// propres accumulateurs : la boucle interne, sans branchement ni dépendance entre
+/// voies, peut ainsi être vectorisée par le compilateur.
+fn extent<const N: usize, U: Scalar>(vectors: &[Vector<N, U>]) -> Option<MBR<N, U>> {
+    // Amorce avec la première valeur exploitable de chaque axe.
+    let seed = all_some(core::array::from_fn(|axis| {
+        vectors
+            .iter()
+            .map(|v| v.0[axis])
+            .find(|c| c.partial_cmp(c).is_some())
+    }))?;
+
+    let mut min = [seed; EXTENT_LANES];
+    let mut max = [seed; EXTENT_LANES];
+
+    let chunks = vectors.chunks_exact(EXTENT_LANES);
+    let remainder = chunks.remainder();
+
+    for chunk in chunks {
+        for lane in 0..EXTENT_LANES {
+            accumulate_extent(&mut min[lane], &mut max[lane], &chunk[lane]);
+        }
+    }
+
+    for vector in remainder {
+        accumulate_extent(&mut min[0], &mut max[0], vector);
+    }
+
+    let lanes = min.into_iter().zip(max);
+    let (min, max) = lanes
+        .reduce(|(min, max), (lane_min, lane_max)| {
+            (
+                core::array::from_fn(|axis| {
+                    pick(lane_min[axis] < min[axis], lane_min[axis], min[axis])
+                }),
+                core::array::from_fn(|axis| {
+                    pick(lane_max[axis] > max[axis], lane_max[axis], max[axis])
+                }),
+            )
+        })
+        .unwrap_or((seed, seed));
+
+    Some(MBR::new(min, max))
+}
+
+#[inline(always)]
+fn accumulate_extent<const N: usize, U: Scalar>(
+    min: &mut [U; N],
+    max: &mut [U; N],
+    vector: &Vector<N, U>,
+) {
+    for axis in 0..N {
+        let c = vector.0[axis];
+        // Une coordonnée NaN échoue aux deux comparaisons et laisse les accumulateurs inchangés.
+        min[axis] = pick(c < min[axis], c, min[axis]);
+        max[axis] = pick(c > max[axis], c, max[axis]);
+    }
+}
+
+#[inline(always)]
+fn pick<U>(condition: bool, a: U, b: U) -> U {
+    if condition {
+        a
+    } else {
+        b
+    }
+}
+
+fn all_some<const N: usize, U: Copy>(values: [Option<U>; N]) -> Option<[U; N]> {
+    values
+        .iter()
+        .all(Option::is_some)
+        .then(|| values.map(|v| v.unwrap()))
+}
+
+/// Nombre de vecteurs stockés sans allocation dans un [VectorArray].
+pub const INLINE_VECTORS: usize = 8;
+
 /// Un tableau 1D de vecteur de dimension N.
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct VectorArray<const N: usize, U>(Vec<Vector<N, U>>);
+///
+/// Les petits tableaux (points, anneaux simples) sont stockés en ligne, sans
+/// allocation sur le tas, jusqu'à [INLINE_VECTORS] vecteurs.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VectorArray<const N: usize, U>(SmallVec<[Vector<N, U>; INLINE_VECTORS]>);
 
 impl<const N: usize, U> VectorArray<N, U> {
     pub fn new(a: Vec<Vector<N, U>>) -> Self {
-        Self(a)
+        Self(SmallVec::from_vec(a))
+    }
+
+    /// Octets alloués sur le tas, nuls tant que les vecteurs sont stockés en ligne.
+    pub fn heap_size(&self) -> usize {
+        if self.0.spilled() {
+            self.0.capacity() * core::mem::size_of::<Vector<N, U>>()
+        } else {
+            0
+        }
     }
 }
 
@@ -78,47 +330,29 @@ where
 {
     /// Vérifie que la liste de points forme un anneau, sinon le ferme automatiquement.
     pub fn close_ring(&mut self) {
-        if self.first() != self.last() {
-            self.0
-                .push(self.first().cloned().expect("ring must not be empty"));
+        if let Some(first) = self.first().filter(|first| Some(*first) != self.last()) {
+            self.0.push(first.clone());
         }
     }
 }
 
 impl<const N: usize, U> VectorArray<N, U>
 where
-    U: Copy + PartialOrd,
+    U: Scalar,
 {
-    pub fn min_x(&self) -> U {
-        self.0
-            .iter()
-            .map(Vector::x)
-            .min_by(|a, b| a.partial_cmp(b).unwrap())
-            .unwrap()
-    }
-
-    pub fn max_x(&self) -> U {
+    /// Conversion vers un autre espace de coordonnées, `None` si une des
+    /// coordonnées n'est pas représentable.
+    pub fn cast<V: Scalar>(&self) -> Option<VectorArray<N, V>> {
         self.0
             .iter()
-            .map(Vector::x)
-            .max_by(|a, b| a.partial_cmp(b).unwrap())
-            .unwrap()
+            .map(Vector::cast)
+            .collect::<Option<_>>()
+            .map(VectorArray)
     }
 
-    pub fn min_y(&self) -> U {
-        self.0
-            .iter()
-            .map(Vector::y)
-            .min_by(|a, b| a.partial_cmp(b).unwrap())
-            .unwrap()
-    }
-
-    pub fn max_y(&self) -> U {
-        self.0
-            .iter()
-            .map(Vector::y)
-            .max_by(|a, b| a.partial_cmp(b).unwrap())
-            .unwrap()
+    /// Emprise des vecteurs ; les coordonnées NaN sont ignorées axe par axe.
+    pub fn mbr(&self) -> Option<MBR<N, U>> {
+        extent(&self.0)
     }
 }
 
@@ -136,13 +370,20 @@ impl<const N: usize, U> FromIterator<[U; N]> for VectorArray<N, U> {
 
 impl<const N: usize, U> IntoIterator for VectorArray<N, U> {
     type Item = Vector<N, U>;
-    type IntoIter = <Vec<Vector<N, U>> as IntoIterator>::IntoIter;
+    type IntoIter = smallvec::IntoIter<[Vector<N, U>; INLINE_VECTORS]>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.0.into_iter()
     }
 }
 
+/// Un ensemble vide.
+impl<const N: usize, U> Default for VectorArray<N, U> {
+    fn default() -> Self {
+        Self(SmallVec::new())
+    }
+}
+
 impl<const N: usize, U> Deref for VectorArray<N, U> {
     type Target = [Vector<N, U>];
 
@@ -151,7 +392,13 @@ impl<const N: usize, U> Deref for VectorArray<N, U> {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+impl<const N: usize, U> DerefMut for VectorArray<N, U> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 /// Une matrice 2D de vecteur de dimension N.
 pub struct VectorMatrix<const N: usize, U>(Vec<VectorArray<N, U>>);
 
@@ -159,6 +406,12 @@ impl<const N: usize, U> VectorMatrix<N, U> {
     pub fn new(coordinates: Vec<VectorArray<N, U>>) -> Self {
         Self(coordinates)
     }
+
+    /// Octets alloués sur le tas par la matrice et ses tableaux.
+    pub fn heap_size(&self) -> usize {
+        self.0.capacity() * core::mem::size_of::<VectorArray<N, U>>()
+            + self.0.iter().map(VectorArray::heap_size).sum::<usize>()
+    }
 }
 
 impl<const N: usize, U, T1> From<T1> for VectorMatrix<N, U>
@@ -212,6 +465,13 @@ where
     }
 }
 
+/// Un ensemble vide.
+impl<const N: usize, U> Default for VectorMatrix<N, U> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
 impl<const N: usize, U> Deref for VectorMatrix<N, U> {
     type Target = [VectorArray<N, U>];
 
@@ -251,42 +511,27 @@ impl<const N: usize, U> FromIterator<Vec<[U; N]>> for VectorMatrix<N, U> {
 
 impl<const N: usize, U> VectorMatrix<N, U>
 where
-    U: Copy + PartialOrd,
+    U: Scalar,
 {
-    pub fn min_x(&self) -> U {
+    /// Conversion vers un autre espace de coordonnées, `None` si une des
+    /// coordonnées n'est pas représentable.
+    pub fn cast<V: Scalar>(&self) -> Option<VectorMatrix<N, V>> {
         self.0
             .iter()
-            .map(VectorArray::min_x)
-            .min_by(|a, b| a.partial_cmp(b).unwrap())
-            .unwrap()
+            .map(VectorArray::cast)
+            .collect::<Option<Vec<_>>>()
+            .map(VectorMatrix)
     }
 
-    pub fn max_x(&self) -> U {
+    pub fn mbr(&self) -> Option<MBR<N, U>> {
         self.0
             .iter()
-            .map(VectorArray::max_x)
-            .max_by(|a, b| a.partial_cmp(b).unwrap())
-            .unwrap()
-    }
-
-    pub fn min_y(&self) -> U {
-        self.0
-            .iter()
-            .map(VectorArray::min_y)
-            .min_by(|a, b| a.partial_cmp(b).unwrap())
-            .unwrap()
-    }
-
-    pub fn max_y(&self) -> U {
-        self.0
-            .iter()
-            .map(VectorArray::max_y)
-            .max_by(|a, b| a.partial_cmp(b).unwrap())
-            .unwrap()
+            .filter_map(VectorArray::mbr)
+            .reduce(|a, b| a.union(&b))
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 /// Un tenseur 3D de vecteur de dimension N
 pub struct VectorTensor<const N: usize, U>(Vec<VectorMatrix<N, U>>);
 
@@ -294,6 +539,19 @@ impl<const N: usize, U> VectorTensor<N, U> {
     pub fn new(coordinates: Vec<VectorMatrix<N, U>>) -> Self {
         Self(coordinates)
     }
+
+    /// Octets alloués sur le tas par le tenseur et ses matrices.
+    pub fn heap_size(&self) -> usize {
+        self.0.capacity() * core::mem::size_of::<VectorMatrix<N, U>>()
+            + self.0.iter().map(VectorMatrix::heap_size).sum::<usize>()
+    }
+}
+
+/// Un ensemble vide.
+impl<const N: usize, U> Default for VectorTensor<N, U> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
 }
 
 impl<const N: usize, U> Deref for VectorTensor<N, U> {
@@ -304,6 +562,12 @@ impl<const N: usize, U> Deref for VectorTensor<N, U> {
     }
 }
 
+impl<const N: usize, U> DerefMut for VectorTensor<N, U> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
 impl<const N: usize, U> IntoIterator for VectorTensor<N, U> {
     type Item = VectorMatrix<N, U>;
     type IntoIter = <Vec<Self::Item> as IntoIterator>::IntoIter;
@@ -329,38 +593,23 @@ impl<const N: usize, U> FromIterator<Vec<Vec<[U; N]>>> for VectorTensor<N, U> {
 
 impl<const N: usize, U> VectorTensor<N, U>
 where
-    U: Copy + PartialOrd,
+    U: Scalar,
 {
-    pub fn min_x(&self) -> U {
-        self.0
-            .iter()
-            .map(VectorMatrix::min_x)
-            .min_by(|a, b| a.partial_cmp(b).unwrap())
-            .unwrap()
-    }
-
-    pub fn max_x(&self) -> U {
-        self.0
-            .iter()
-            .map(VectorMatrix::max_x)
-            .max_by(|a, b| a.partial_cmp(b).unwrap())
-            .unwrap()
-    }
-
-    pub fn min_y(&self) -> U {
+    /// Conversion vers un autre espace de coordonnées, `None` si une des
+    /// coordonnées n'est pas représentable.
+    pub fn cast<V: Scalar>(&self) -> Option<VectorTensor<N, V>> {
         self.0
             .iter()
-            .map(VectorMatrix::min_y)
-            .min_by(|a, b| a.partial_cmp(b).unwrap())
-            .unwrap()
+            .map(VectorMatrix::cast)
+            .collect::<Option<Vec<_>>>()
+            .map(VectorTensor)
     }
 
-    pub fn max_y(&self) -> U {
+    pub fn mbr(&self) -> Option<MBR<N, U>> {
         self.0
             .iter()
-            .map(VectorMatrix::max_y)
-            .max_by(|a, b| a.partial_cmp(b).unwrap())
-            .unwrap()
+            .filter_map(VectorMatrix::mbr)
+            .reduce(|a, b| a.union(&b))
     }
 }
 
@@ -372,3 +621,244 @@ where
         Self::new(vec![VectorMatrix::from(value)])
     }
 }
+
+/// Implémente les opérations d'édition en place d'un conteneur de vecteurs.
+macro_rules! impl_vector_container_mutations {
+    ($name:ident, $item:ty) => {
+        impl<const N: usize, U> $name<N, U> {
+            /// Ajoute un élément à la fin.
+            pub fn push(&mut self, value: $item) {
+                self.0.push(value)
+            }
+
+            /// Insère un élément à la position `index`.
+            ///
+            /// Panique si `index > len`.
+            pub fn insert(&mut self, index: usize, value: $item) {
+                self.0.insert(index, value)
+            }
+
+            /// Retire et renvoie l'élément à la position `index`, `None` si elle n'existe pas.
+            pub fn remove(&mut self, index: usize) -> Option<$item> {
+                (index < self.0.len()).then(|| self.0.remove(index))
+            }
+
+            /// Ne conserve que les `len` premiers éléments.
+            pub fn truncate(&mut self, len: usize) {
+                self.0.truncate(len)
+            }
+
+            /// Ne conserve que les éléments vérifiant le prédicat.
+            pub fn retain<F: FnMut(&$item) -> bool>(&mut self, mut f: F) {
+                self.0.retain(|v| f(v))
+            }
+        }
+    };
+}
+
+/// Implémente l'accès indexé et le parcours par référence d'un conteneur de vecteurs.
+macro_rules! impl_vector_container_access {
+    ($name:ident, $item:ty) => {
+        impl<const N: usize, U> $name<N, U> {
+            /// Élément à la position `index`, `None` si elle n'existe pas.
+            pub fn get(&self, index: usize) -> Option<&$item> {
+                self.0.get(index)
+            }
+
+            pub fn get_mut(&mut self, index: usize) -> Option<&mut $item> {
+                self.0.get_mut(index)
+            }
+
+            pub fn iter(&self) -> core::slice::Iter<'_, $item> {
+                self.0.iter()
+            }
+
+            pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, $item> {
+                self.0.iter_mut()
+            }
+        }
+
+        impl<const N: usize, U> Index<usize> for $name<N, U> {
+            type Output = $item;
+
+            fn index(&self, index: usize) -> &Self::Output {
+                &self.0[index]
+            }
+        }
+
+        impl<const N: usize, U> IndexMut<usize> for $name<N, U> {
+            fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+                &mut self.0[index]
+            }
+        }
+
+        impl<'a, const N: usize, U> IntoIterator for &'a $name<N, U> {
+            type Item = &'a $item;
+            type IntoIter = core::slice::Iter<'a, $item>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.0.iter()
+            }
+        }
+
+        impl<'a, const N: usize, U> IntoIterator for &'a mut $name<N, U> {
+            type Item = &'a mut $item;
+            type IntoIter = core::slice::IterMut<'a, $item>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.0.iter_mut()
+            }
+        }
+    };
+}
+
+impl_vector_container_access!(VectorArray, Vector<N, U>);
+impl_vector_container_access!(VectorMatrix, VectorArray<N, U>);
+impl_vector_container_access!(VectorTensor, VectorMatrix<N, U>);
+
+impl<const N: usize, U> VectorMatrix<N, U> {
+    /// Premier anneau, c'est-à-dire l'anneau extérieur d'un polygone.
+    pub fn first_ring(&self) -> Option<&VectorArray<N, U>> {
+        self.0.first()
+    }
+
+    /// Anneaux suivant le premier, c'est-à-dire les trous d'un polygone.
+    pub fn interior_rings(&self) -> &[VectorArray<N, U>] {
+        self.0.get(1..).unwrap_or_default()
+    }
+}
+
+impl_vector_container_mutations!(VectorArray, Vector<N, U>);
+impl_vector_container_mutations!(VectorMatrix, VectorArray<N, U>);
+impl_vector_container_mutations!(VectorTensor, VectorMatrix<N, U>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vector_arithmetic() {
+        let a = Vector::new([1.0, 2.0, 3.0]);
+        let b = Vector::new([4.0, -5.0, 6.0]);
+
+        assert_eq!(a + b, Vector::new([5.0, -3.0, 9.0]));
+        assert_eq!(a - b, Vector::new([-3.0, 7.0, -3.0]));
+        assert_eq!(&a * 2.0, Vector::new([2.0, 4.0, 6.0]));
+        assert_eq!(b / 2.0, Vector::new([2.0, -2.5, 3.0]));
+        assert_eq!(-a, Vector::new([-1.0, -2.0, -3.0]));
+        assert_eq!(a.dot(&b), 12.0);
+        assert_eq!(a.cross(&b), Vector::new([27.0, 6.0, -13.0]));
+    }
+
+    #[test]
+    fn test_vector_norm() {
+        let v = Vector::new([3.0, 4.0]);
+
+        assert_eq!(v.norm(), 5.0);
+        assert_eq!(v.normalize(), Some(Vector::new([0.6, 0.8])));
+        assert_eq!(Vector::new([0.0, 0.0]).normalize(), None);
+        assert_eq!(Vector::new([1, 0]).cross(&Vector::new([0, 1])), 1);
+    }
+
+    #[test]
+    fn test_vector_array_mutations() {
+        let mut array = VectorArray::from([[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]]);
+
+        array.push(Vector::new([3.0, 3.0]));
+        array.insert(0, Vector::new([-1.0, -1.0]));
+        assert_eq!(array.remove(2), Some(Vector::new([1.0, 1.0])));
+        assert_eq!(array.remove(10), None);
+
+        array.retain(|v| v.x() != 2.0);
+        array.truncate(2);
+
+        assert_eq!(array, VectorArray::from([[-1.0, -1.0], [0.0, 0.0]]));
+    }
+
+    #[test]
+    fn test_small_arrays_are_inline() {
+        let mut ring = VectorArray::from([[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 0.0]]);
+        assert!(!ring.0.spilled());
+
+        (0..INLINE_VECTORS).for_each(|i| ring.push(Vector::new([i as f64, 0.0])));
+        assert!(ring.0.spilled());
+    }
+
+    #[test]
+    fn test_extent_lanes() {
+        // Assez de points pour remplir plusieurs paquets, plus un reste.
+        let array = (0..37)
+            .map(|i| {
+                let x = if i % 5 == 0 {
+                    f64::NAN
+                } else {
+                    (i as f64 * 7.3) % 11.0 - 5.0
+                };
+                [x, -(i as f64)]
+            })
+            .collect::<VectorArray<2, f64>>();
+
+        let xs = array.iter().map(|v| v.x()).filter(|x| !x.is_nan());
+        let min_x = xs.clone().fold(f64::INFINITY, f64::min);
+        let max_x = xs.fold(f64::NEG_INFINITY, f64::max);
+
+        assert_eq!(array.mbr(), Some(MBR::new([min_x, -36.0], [max_x, 0.0])));
+    }
+
+    #[test]
+    fn test_extent_of_degenerate_data() {
+        let nan = f64::NAN;
+        let matrix = VectorMatrix::from_iter([
+            vec![[nan, nan], [nan, nan]],
+            vec![[1.0, nan], [nan, 2.0], [-1.0, 0.5]],
+            vec![],
+        ]);
+
+        assert_eq!(matrix.mbr(), Some(MBR::new([-1.0, 0.5], [1.0, 2.0])));
+        assert_eq!(
+            VectorTensor::from_iter([vec![vec![[nan, 0.0]]]]).mbr(),
+            None
+        );
+        assert_eq!(VectorTensor::<2, f64>::new(vec![]).mbr(), None);
+
+        let mut empty = VectorArray::<2, f64>::new(vec![]);
+        empty.close_ring();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_matrix_access() {
+        let mut polygon = VectorMatrix::<2, f64>::from((
+            [[0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 0.0]],
+            [[1.0, 1.0], [2.0, 1.0], [2.0, 2.0], [1.0, 1.0]],
+        ));
+
+        assert_eq!(polygon.first_ring().map(|ring| ring.len()), Some(4));
+        assert_eq!(polygon.interior_rings().len(), 1);
+        assert_eq!(polygon[1][2], Vector::new([2.0, 2.0]));
+        assert!(polygon.get(2).is_none());
+
+        for ring in &mut polygon {
+            for vector in ring {
+                vector[0] += 1.0;
+            }
+        }
+
+        let xs = (&polygon).into_iter().flatten().map(Vector::x);
+        assert_eq!(xs.sum::<f64>(), 12.0 + 10.0);
+        assert!(VectorMatrix::<2, f64>::new(vec![])
+            .interior_rings()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_vector_cast() {
+        let array = VectorArray::from([[1.5f64, -2.0], [3.0, 4.25]]);
+        let cast = array.cast::<i32>().expect("cannot cast coordinates");
+
+        assert_eq!(cast, VectorArray::from([[1, -2], [3, 4]]));
+        assert_eq!(cast.mbr(), Some(MBR::new([1, -2], [3, 4])));
+        assert_eq!(Vector::new([f64::NAN, 0.0]).cast::<i32>(), None);
+        assert_eq!(Vector::new([3.0f32, 4.0]).norm(), 5.0);
+    }
+}
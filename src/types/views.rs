@@ -0,0 +1,171 @@
+//! Vues typées sur les coordonnées des géométries.
+//!
+//! Elles exposent des accesseurs nommés sans révéler la représentation interne
+//! (tableaux, [VectorArray]) que laissent voir les implémentations de `Deref`.
+use super::{
+    line_string::LineString, multi_line_string::MultiLineString, multi_point::MultiPoint,
+    multi_polygon::MultiPolygon, point::Point, polygon::Polygon, Vector, VectorArray,
+};
+
+/// Vue sur les coordonnées d'un point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PointView<'a, const N: usize, U>(&'a Vector<N, U>);
+
+impl<'a, const N: usize, U: Copy> PointView<'a, N, U> {
+    /// Nombre de coordonnées du point.
+    pub fn dimension(&self) -> usize {
+        N
+    }
+
+    pub fn x(&self) -> U {
+        self.0.x()
+    }
+
+    pub fn y(&self) -> U {
+        self.0.y()
+    }
+
+    /// Altitude, `None` pour un point 2D.
+    pub fn z(&self) -> Option<U> {
+        self.0.get(2).copied()
+    }
+
+    /// Coordonnées brutes du point.
+    pub fn to_array(&self) -> [U; N] {
+        **self.0
+    }
+}
+
+/// Vue sur une suite de points : une ligne, ou un anneau de polygone.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RingView<'a, const N: usize, U>(&'a VectorArray<N, U>);
+
+impl<'a, const N: usize, U: Copy + PartialEq> RingView<'a, N, U> {
+    /// Nombre de points.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Vrai si le premier et le dernier point sont identiques.
+    pub fn is_closed(&self) -> bool {
+        !self.is_empty() && self.0.first() == self.0.last()
+    }
+
+    /// Point d'indice `index`, `None` s'il n'existe pas.
+    pub fn point(&self, index: usize) -> Option<PointView<'a, N, U>> {
+        self.0.get(index).map(PointView)
+    }
+
+    /// Itère sur les points.
+    pub fn points(&self) -> impl ExactSizeIterator<Item = PointView<'a, N, U>> + 'a {
+        self.0.iter().map(PointView)
+    }
+
+    /// Itère sur les segments reliant deux points consécutifs.
+    pub fn segments(
+        &self,
+    ) -> impl Iterator<Item = (PointView<'a, N, U>, PointView<'a, N, U>)> + 'a {
+        self.0
+            .windows(2)
+            .map(|s| (PointView(&s[0]), PointView(&s[1])))
+    }
+}
+
+impl<const N: usize, U> Point<N, U> {
+    /// Vue sur les coordonnées du point.
+    pub fn view(&self) -> PointView<'_, N, U> {
+        PointView(&self.coordinates)
+    }
+}
+
+impl<const N: usize, U> LineString<N, U> {
+    /// Vue sur les points de la ligne.
+    pub fn view(&self) -> RingView<'_, N, U> {
+        RingView(&self.coordinates)
+    }
+}
+
+impl<const N: usize, U> MultiPoint<N, U> {
+    /// Itère sur les points.
+    pub fn points(&self) -> impl ExactSizeIterator<Item = PointView<'_, N, U>> {
+        self.coordinates.iter().map(PointView)
+    }
+}
+
+impl<const N: usize, U> MultiLineString<N, U> {
+    /// Itère sur les lignes.
+    pub fn lines(&self) -> impl ExactSizeIterator<Item = RingView<'_, N, U>> {
+        self.coordinates.iter().map(RingView)
+    }
+}
+
+impl<const N: usize, U> Polygon<N, U> {
+    /// Anneau extérieur, `None` pour un polygone vide.
+    pub fn exterior(&self) -> Option<RingView<'_, N, U>> {
+        self.coordinates.first().map(RingView)
+    }
+
+    /// Itère sur les trous du polygone.
+    pub fn interiors(&self) -> impl ExactSizeIterator<Item = RingView<'_, N, U>> {
+        self.coordinates.iter().skip(1).map(RingView)
+    }
+
+    /// Itère sur tous les anneaux, en commençant par l'anneau extérieur.
+    pub fn rings(&self) -> impl ExactSizeIterator<Item = RingView<'_, N, U>> {
+        self.coordinates.iter().map(RingView)
+    }
+}
+
+impl<const N: usize, U> MultiPolygon<N, U> {
+    /// Itère sur les anneaux de chaque polygone.
+    pub fn polygons(
+        &self,
+    ) -> impl ExactSizeIterator<Item = impl ExactSizeIterator<Item = RingView<'_, N, U>>> {
+        self.coordinates
+            .iter()
+            .map(|rings| rings.iter().map(RingView))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{GeometryImpl as _, LineString, MultiPoint, Point, PointZ, Polygon};
+
+    #[test]
+    fn test_views() {
+        let point = Point::new([1.0, 2.0]);
+        let view = point.view();
+        assert_eq!((view.x(), view.y(), view.z()), (1.0, 2.0, None));
+        assert_eq!(view.dimension(), 2);
+        assert_eq!(view.to_array(), [1.0, 2.0]);
+        assert_eq!(PointZ::new([1.0, 2.0, 3.0]).view().z(), Some(3.0));
+
+        let line = LineString::new([[0.0, 0.0], [1.0, 1.0], [2.0, 0.0]]);
+        let view = line.view();
+        assert_eq!(view.len(), 3);
+        assert!(!view.is_closed());
+        assert_eq!(view.point(1).map(|p| p.x()), Some(1.0));
+        assert_eq!(view.point(3), None);
+        assert_eq!(view.segments().count(), 2);
+        assert_eq!(
+            view.points().map(|p| p.y()).collect::<Vec<_>>(),
+            [0.0, 1.0, 0.0]
+        );
+
+        let polygon = Polygon::new((
+            [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0]],
+            [[4.0, 4.0], [6.0, 4.0], [6.0, 6.0], [4.0, 6.0]],
+        ));
+        assert!(polygon.exterior().is_some_and(|ring| ring.is_closed()));
+        assert_eq!(polygon.interiors().len(), 1);
+        assert_eq!(polygon.rings().map(|ring| ring.len()).sum::<usize>(), 10);
+        assert!(Polygon::default().exterior().is_none());
+
+        let points = MultiPoint::new([[0.0, 1.0], [2.0, 3.0]]);
+        assert_eq!(points.points().map(|p| p.x()).sum::<f64>(), 2.0);
+    }
+}
@@ -0,0 +1,642 @@
+//! Rendu et lecture Well-Known Text (WKT) des géométries.
+//!
+//! `{}` affiche le WKT (`POINT Z (1 2 3)`), `{:#}` l'EWKT de PostGIS, préfixé du SRID
+//! s'il y en a un (`SRID=4326;POINT(1 2)`). Une précision (`{:.2}`) arrondit les coordonnées.
+//!
+//! [Geometry::from_str] lit l'un et l'autre.
+//!
+//! [serialize] et [deserialize] en font la représentation serde par défaut de
+//! [Geometry], aussi utilisable via `#[serde(with = "sql_gis::types::wkt")]`.
+use core::{
+    fmt::{self, Display, Formatter, Write as _},
+    str::FromStr,
+};
+
+use crate::error::Error;
+use crate::prelude::*;
+
+use super::{
+    CoordinatesRef, Geometry, GeometryImpl as _, GeometryKind, GeometryRef, LineString,
+    LineStringZ, MultiLineString, MultiLineStringZ, MultiPoint, MultiPointZ, MultiPolygon,
+    MultiPolygonZ, Point, PointZ, Polygon, PolygonZ, Vector, VectorArray, VectorMatrix,
+    VectorTensor,
+};
+
+impl Display for GeometryRef<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let (true, Some(srid)) = (f.alternate(), self.srid()) {
+            write!(f, "SRID={srid};")?;
+        }
+
+        let kind = self.kind();
+        f.write_str(kind.wkt_tag())?;
+        if kind.is_3d() {
+            f.write_str(" Z ")?;
+        }
+
+        match self.borrow_coordinates() {
+            CoordinatesRef::Vector2D(v) => write_point(f, v),
+            CoordinatesRef::VectorArray2D(a) if kind.base_kind() == GeometryKind::MultiPoint => {
+                write_list(f, a.iter(), write_point)
+            }
+            CoordinatesRef::VectorArray2D(a) => write_list(f, a.iter(), write_vector),
+            CoordinatesRef::VectorMatrix2D(m) => write_list(f, m.iter(), |f, ring| {
+                write_list(f, ring.iter(), write_vector)
+            }),
+            CoordinatesRef::VectorTensor2D(t) => write_list(f, t.iter(), |f, polygon| {
+                write_list(f, polygon.iter(), |f, ring| {
+                    write_list(f, ring.iter(), write_vector)
+                })
+            }),
+            CoordinatesRef::Vector3D(v) => write_point(f, v),
+            CoordinatesRef::VectorArray3D(a) if kind.base_kind() == GeometryKind::MultiPoint => {
+                write_list(f, a.iter(), write_point)
+            }
+            CoordinatesRef::VectorArray3D(a) => write_list(f, a.iter(), write_vector),
+            CoordinatesRef::VectorMatrix3D(m) => write_list(f, m.iter(), |f, ring| {
+                write_list(f, ring.iter(), write_vector)
+            }),
+            CoordinatesRef::VectorTensor3D(t) => write_list(f, t.iter(), |f, polygon| {
+                write_list(f, polygon.iter(), |f, ring| {
+                    write_list(f, ring.iter(), write_vector)
+                })
+            }),
+        }
+    }
+}
+
+impl Display for Geometry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.as_geometry_ref().fmt(f)
+    }
+}
+
+macro_rules! impl_display {
+    ($($geometry_type:ident),*) => {
+        $(
+            impl Display for $geometry_type {
+                fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                    GeometryRef::from(self).fmt(f)
+                }
+            }
+        )*
+    };
+}
+
+impl_display!(
+    Point,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    PointZ,
+    LineStringZ,
+    PolygonZ,
+    MultiPointZ,
+    MultiLineStringZ,
+    MultiPolygonZ
+);
+
+/// Un point dont toutes les coordonnées sont NaN est vide, comme en WKB.
+fn write_point<const N: usize>(f: &mut Formatter<'_>, vector: &Vector<N, f64>) -> fmt::Result {
+    if vector.iter().all(|scalar| scalar.is_nan()) {
+        return f.write_str(" EMPTY");
+    }
+
+    f.write_char('(')?;
+    write_vector(f, vector)?;
+    f.write_char(')')
+}
+
+fn write_vector<const N: usize>(f: &mut Formatter<'_>, vector: &Vector<N, f64>) -> fmt::Result {
+    for (i, scalar) in vector.iter().enumerate() {
+        if i > 0 {
+            f.write_char(' ')?;
+        }
+
+        match f.precision() {
+            Some(precision) => write!(f, "{scalar:.precision$}")?,
+            None => write!(f, "{scalar}")?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Écrit une liste parenthésée, ou ` EMPTY` si elle est vide.
+fn write_list<T>(
+    f: &mut Formatter<'_>,
+    items: impl ExactSizeIterator<Item = T>,
+    write_item: impl Fn(&mut Formatter<'_>, T) -> fmt::Result,
+) -> fmt::Result {
+    if items.len() == 0 {
+        return f.write_str(" EMPTY");
+    }
+
+    f.write_char('(')?;
+    for (i, item) in items.enumerate() {
+        if i > 0 {
+            f.write_char(',')?;
+        }
+        write_item(f, item)?;
+    }
+    f.write_char(')')
+}
+
+impl FromStr for Geometry {
+    type Err = Error;
+
+    /// Lit une géométrie WKT ou EWKT.
+    ///
+    /// Sans suffixe `Z`, la dimension est celle des coordonnées, comme en EWKT
+    /// (`POINT(1 2 3)`). Avec la fonctionnalité `ewkb`, l'EWKB en hexadécimal renvoyé
+    /// par PostGIS est aussi accepté.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        #[cfg(feature = "ewkb")]
+        if let Some(bytes) = crate::ewkb::decode_hex(s.trim()).filter(|bytes| !bytes.is_empty()) {
+            use crate::io::Decodable as _;
+
+            return crate::ewkb::EWKBGeometry::decode_from_slice(&bytes).map(Geometry::from);
+        }
+
+        Parser::new(s).parse()
+    }
+}
+
+/// Sérialise une géométrie en EWKT, à utiliser via `#[serde(with = "sql_gis::types::wkt")]`.
+///
+/// Le SRID est conservé en préfixe (`SRID=4326;POINT(1 2)`) ; les coordonnées sont
+/// écrites sans perte de précision.
+pub fn serialize<S>(geometry: &Geometry, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.collect_str(&format_args!("{geometry:#}"))
+}
+
+/// Désérialise une géométrie WKT ou EWKT, voir [serialize].
+///
+/// Contrairement à [Geometry::from_str], l'EWKB hexadécimal n'est pas accepté :
+/// le format lu ne dépend pas des fonctionnalités actives.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Geometry, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    deserializer.deserialize_str(WktVisitor)
+}
+
+struct WktVisitor;
+
+impl serde::de::Visitor<'_> for WktVisitor {
+    type Value = Geometry;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a WKT or EWKT string")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Parser::new(v).parse().map_err(E::custom)
+    }
+}
+
+/// Sommet lu, de dimension vérifiée par [Parser::vertex].
+type Vertex = Vec<f64>;
+
+/// Coordonnées lues, avant de connaître leur dimension.
+enum Body {
+    /// Un point vide.
+    Empty,
+    Vertex(Vertex),
+    Array(Vec<Vertex>),
+    Matrix(Vec<Vec<Vertex>>),
+    Tensor(Vec<Vec<Vec<Vertex>>>),
+}
+
+/// Analyseur WKT descendant récursif.
+struct Parser<'s> {
+    input: &'s str,
+    offset: usize,
+    /// Dimension des sommets, fixée par le premier sommet lu.
+    dimension: Option<usize>,
+}
+
+impl<'s> Parser<'s> {
+    fn new(input: &'s str) -> Self {
+        Self {
+            input,
+            offset: 0,
+            dimension: None,
+        }
+    }
+
+    fn parse(mut self) -> Result<Geometry, Error> {
+        let srid = self.srid()?;
+        let (kind, is_3d) = self.header()?;
+
+        let body = match kind {
+            GeometryKind::Point if self.empty() => Body::Empty,
+            GeometryKind::Point => {
+                self.expect(b'(')?;
+                let vertex = self.vertex()?;
+                self.expect(b')')?;
+                Body::Vertex(vertex)
+            }
+            GeometryKind::LineString => Body::Array(self.list(Self::vertex)?),
+            GeometryKind::MultiPoint => Body::Array(self.list(Self::point)?),
+            GeometryKind::Polygon | GeometryKind::MultiLineString => {
+                Body::Matrix(self.list(|parser| parser.list(Self::vertex))?)
+            }
+            GeometryKind::MultiPolygon => {
+                Body::Tensor(self.list(|parser| parser.list(|parser| parser.list(Self::vertex)))?)
+            }
+            _ => {
+                return Err(Error::unsupported_geometry(
+                    kind,
+                    "geometry collections are not supported",
+                ))
+            }
+        };
+
+        self.skip_whitespace();
+        if self.offset < self.input.len() {
+            return Err(self.error("trailing characters after the geometry"));
+        }
+
+        let mut geometry = match (self.dimension, is_3d) {
+            (Some(3), _) | (None, true) => build::<3>(kind, body),
+            (Some(2) | None, false) => build::<2>(kind, body),
+            _ => return Err(self.error("expecting 3 coordinates per vertex")),
+        };
+        geometry.set_srid(srid);
+        Ok(geometry)
+    }
+
+    /// Préfixe EWKT `SRID=<srid>;`, facultatif.
+    fn srid(&mut self) -> Result<Option<u32>, Error> {
+        self.skip_whitespace();
+        let rest = &self.input[self.offset..];
+        if !rest
+            .get(..5)
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case("SRID="))
+        {
+            return Ok(None);
+        }
+
+        self.offset += 5;
+        let digits = self.take_while(|b| b.is_ascii_digit());
+        let srid = digits.parse().map_err(|_| self.error("invalid SRID"))?;
+        self.expect(b';')?;
+        Ok(Some(srid))
+    }
+
+    /// Mot-clé du type, suivi ou suffixé d'une dimension (`POINT Z`, `POINTZ`).
+    fn header(&mut self) -> Result<(GeometryKind, bool), Error> {
+        self.skip_whitespace();
+        let start = self.offset;
+        let word = self.word();
+
+        let (tag, mut modifier) = match GeometryKind::from_wkt_tag(word, false) {
+            Some(_) => (word, ""),
+            None => ["ZM", "Z", "M"]
+                .into_iter()
+                .find_map(|suffix| {
+                    let split = word.len().checked_sub(suffix.len())?;
+                    let (tag, modifier) = word.split_at_checked(split)?;
+                    modifier
+                        .eq_ignore_ascii_case(suffix)
+                        .then_some((tag, modifier))
+                })
+                .unwrap_or((word, "")),
+        };
+
+        let kind = GeometryKind::from_wkt_tag(tag, false)
+            .ok_or(Error::invalid_wkt(start, "unknown geometry type"))?;
+
+        if modifier.is_empty() {
+            let before = self.offset;
+            modifier = self.word();
+            if modifier.eq_ignore_ascii_case("EMPTY") {
+                self.offset = before;
+                modifier = "";
+            }
+        }
+
+        match modifier.to_ascii_uppercase().as_str() {
+            "" => Ok((kind, false)),
+            "Z" => Ok((kind, true)),
+            "M" | "ZM" => Err(self.error("measured coordinates are not supported")),
+            _ => Err(self.error("unknown dimension")),
+        }
+    }
+
+    /// Liste parenthésée, ou `EMPTY`.
+    fn list<T>(&mut self, item: impl Fn(&mut Self) -> Result<T, Error>) -> Result<Vec<T>, Error> {
+        if self.empty() {
+            return Ok(Vec::new());
+        }
+
+        self.expect(b'(')?;
+        let mut items = vec![item(self)?];
+        while self.eat(b',') {
+            items.push(item(self)?);
+        }
+        self.expect(b')')?;
+
+        Ok(items)
+    }
+
+    /// Point d'un `MULTIPOINT`, parenthésé ou non.
+    fn point(&mut self) -> Result<Vertex, Error> {
+        if !self.eat(b'(') {
+            return self.vertex();
+        }
+
+        let vertex = self.vertex()?;
+        self.expect(b')')?;
+        Ok(vertex)
+    }
+
+    fn vertex(&mut self) -> Result<Vertex, Error> {
+        let start = self.offset;
+        let mut vertex = Vertex::with_capacity(3);
+        while let Some(scalar) = self.number()? {
+            vertex.push(scalar);
+        }
+
+        match (self.dimension, vertex.len()) {
+            (None, len @ (2 | 3)) => self.dimension = Some(len),
+            (Some(dimension), len) if dimension == len => {}
+            _ => {
+                return Err(Error::invalid_wkt(
+                    start,
+                    "expecting 2 or 3 coordinates per vertex, the same for every vertex",
+                ))
+            }
+        }
+
+        Ok(vertex)
+    }
+
+    fn number(&mut self) -> Result<Option<f64>, Error> {
+        self.skip_whitespace();
+        let start = self.offset;
+        let number = self.take_while(|b| b.is_ascii_alphanumeric() || b"+-.".contains(&b));
+        if number.is_empty() {
+            return Ok(None);
+        }
+
+        number
+            .parse()
+            .map(Some)
+            .map_err(|_| Error::invalid_wkt(start, "invalid number"))
+    }
+
+    fn empty(&mut self) -> bool {
+        let before = self.offset;
+        if self.word().eq_ignore_ascii_case("EMPTY") {
+            return true;
+        }
+
+        self.offset = before;
+        false
+    }
+
+    fn word(&mut self) -> &'s str {
+        self.skip_whitespace();
+        self.take_while(|b| b.is_ascii_alphabetic())
+    }
+
+    fn eat(&mut self, expecting: u8) -> bool {
+        self.skip_whitespace();
+        if self.input.as_bytes().get(self.offset) == Some(&expecting) {
+            self.offset += 1;
+            return true;
+        }
+
+        false
+    }
+
+    fn expect(&mut self, expecting: u8) -> Result<(), Error> {
+        if self.eat(expecting) {
+            return Ok(());
+        }
+
+        Err(self.error(match expecting {
+            b'(' => "expecting '('",
+            b')' => "expecting ')'",
+            _ => "expecting ';'",
+        }))
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.take_while(|b| b.is_ascii_whitespace());
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(u8) -> bool) -> &'s str {
+        let start = self.offset;
+        let len = self.input.as_bytes()[start..]
+            .iter()
+            .take_while(|&&b| predicate(b))
+            .count();
+
+        self.offset += len;
+        &self.input[start..self.offset]
+    }
+
+    fn error(&self, reason: &'static str) -> Error {
+        Error::invalid_wkt(self.offset, reason)
+    }
+}
+
+/// Construit la géométrie, une fois la dimension `N` des sommets connue.
+fn build<const N: usize>(kind: GeometryKind, body: Body) -> Geometry
+where
+    Geometry: From<super::point::Point<N, f64>>
+        + From<super::line_string::LineString<N, f64>>
+        + From<super::polygon::Polygon<N, f64>>
+        + From<super::multi_point::MultiPoint<N, f64>>
+        + From<super::multi_line_string::MultiLineString<N, f64>>
+        + From<super::multi_polygon::MultiPolygon<N, f64>>,
+{
+    fn vector<const N: usize>(vertex: Vertex) -> Vector<N, f64> {
+        Vector::new(core::array::from_fn(|i| vertex[i]))
+    }
+
+    fn array<const N: usize>(vertices: Vec<Vertex>) -> VectorArray<N, f64> {
+        vertices.into_iter().map(vector).collect()
+    }
+
+    fn matrix<const N: usize>(rings: Vec<Vec<Vertex>>) -> VectorMatrix<N, f64> {
+        rings.into_iter().map(array).collect()
+    }
+
+    match (kind, body) {
+        (_, Body::Empty) => super::point::Point::new([f64::NAN; N]).into(),
+        (_, Body::Vertex(vertex)) => super::point::Point::new(vector::<N>(vertex)).into(),
+        (GeometryKind::MultiPoint, Body::Array(points)) => {
+            super::multi_point::MultiPoint::new(array::<N>(points)).into()
+        }
+        (_, Body::Array(vertices)) => {
+            super::line_string::LineString::new(array::<N>(vertices)).into()
+        }
+        (GeometryKind::MultiLineString, Body::Matrix(lines)) => {
+            super::multi_line_string::MultiLineString::new(matrix::<N>(lines)).into()
+        }
+        (_, Body::Matrix(rings)) => super::polygon::Polygon::new(matrix::<N>(rings)).into(),
+        (_, Body::Tensor(polygons)) => super::multi_polygon::MultiPolygon::new(
+            polygons
+                .into_iter()
+                .map(matrix::<N>)
+                .collect::<VectorTensor<N, f64>>(),
+        )
+        .into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::types::{
+        Geometry, GeometryImpl as _, LineString, LineStringZ, MultiPoint, MultiPolygon, Point,
+        PointZ, Polygon, VectorArray2D, VectorMatrix2D, VectorTensor2D,
+    };
+
+    #[test]
+    fn test_display_wkt() {
+        let mut point = Geometry::from(Point::new([1.0, 2.5]));
+        assert_eq!(point.to_string(), "POINT(1 2.5)");
+        assert_eq!(format!("{point:#}"), "POINT(1 2.5)");
+
+        point.set_srid(Some(4326));
+        assert_eq!(format!("{point:#}"), "SRID=4326;POINT(1 2.5)");
+        assert_eq!(format!("{point:.2}"), "POINT(1.00 2.50)");
+
+        assert_eq!(PointZ::new([1.0, 2.0, 3.0]).to_string(), "POINT Z (1 2 3)");
+        assert_eq!(Point::new([f64::NAN, f64::NAN]).to_string(), "POINT EMPTY");
+        assert_eq!(
+            LineString::new([[0.0, 0.0], [1.0, 1.0]]).to_string(),
+            "LINESTRING(0 0,1 1)"
+        );
+        assert_eq!(
+            MultiPoint::new([[0.0, 0.0], [1.0, 1.0]]).to_string(),
+            "MULTIPOINT((0 0),(1 1))"
+        );
+        assert_eq!(
+            Polygon::new([[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 0.0]]).to_string(),
+            "POLYGON((0 0,1 0,1 1,0 0))"
+        );
+        assert_eq!(
+            MultiPolygon::new(VectorTensor2D::new(vec![VectorMatrix2D::from([
+                [0.0, 0.0],
+                [1.0, 0.0],
+                [0.0, 0.0]
+            ])]))
+            .to_string(),
+            "MULTIPOLYGON(((0 0,1 0,0 0)))"
+        );
+        assert_eq!(
+            LineString::new(VectorArray2D::new(vec![])).to_string(),
+            "LINESTRING EMPTY"
+        );
+    }
+
+    #[test]
+    fn test_parse_wkt() {
+        let geometries: Vec<Geometry> = vec![
+            Point::new([1.0, 2.5]).into(),
+            PointZ::new([1.0, 2.0, 3.0]).into(),
+            LineString::new([[0.0, 0.0], [1.0, 1.0]]).into(),
+            MultiPoint::new([[0.0, 0.0], [1.0, 1.0]]).into(),
+            Polygon::new([[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 0.0]]).into(),
+        ];
+
+        for mut geometry in geometries {
+            assert_eq!(geometry.to_string().parse::<Geometry>().unwrap(), geometry);
+
+            geometry.set_srid(Some(2154));
+            assert_eq!(
+                format!("{geometry:#}").parse::<Geometry>().unwrap(),
+                geometry
+            );
+        }
+
+        assert_eq!(
+            "  linestringz(0 0 1, 1 1 1) ".parse::<Geometry>().unwrap(),
+            LineStringZ::new([[0.0, 0.0, 1.0], [1.0, 1.0, 1.0]]).into()
+        );
+        assert_eq!(
+            "POINT(1 2 3)".parse::<Geometry>().unwrap(),
+            PointZ::new([1.0, 2.0, 3.0]).into()
+        );
+        assert_eq!(
+            "MULTIPOINT(0 0, 1 -1.5e2)".parse::<Geometry>().unwrap(),
+            MultiPoint::new([[0.0, 0.0], [1.0, -150.0]]).into()
+        );
+        assert_eq!(
+            "POLYGON EMPTY".parse::<Geometry>().unwrap(),
+            Polygon::new(VectorMatrix2D::new(vec![])).into()
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_wkt() {
+        let error = |wkt: &str| match wkt.parse::<Geometry>() {
+            Err(Error::InvalidWkt { offset, .. }) => offset,
+            other => panic!("expecting an invalid WKT error, got {other:?}"),
+        };
+
+        assert_eq!(error("CIRCLE(0 0)"), 0);
+        assert_eq!(error("POINT(1 2"), 9);
+        assert_eq!(error("LINESTRING(0 0, 1 1 1)"), 15);
+        assert_eq!(error("POINT Z (1 2)"), 13);
+        assert_eq!(error("POINT M (1 2 3)"), 7);
+        assert_eq!(error("POINT(1 2) POINT(3 4)"), 11);
+        assert_eq!(error("SRID=abc;POINT(1 2)"), 5);
+        assert!(matches!(
+            "GEOMETRYCOLLECTION EMPTY".parse::<Geometry>(),
+            Err(Error::UnsupportedGeometry { .. })
+        ));
+    }
+
+    #[test]
+    fn test_serde_wkt() {
+        let mut point = Geometry::from(Point::new([1.5, 2.0]));
+        point.set_srid(Some(4326));
+
+        let encoded = serde_json::to_string(&point).expect("cannot serialize geometry");
+        assert_eq!(encoded, r#""SRID=4326;POINT(1.5 2)""#);
+        assert_eq!(
+            serde_json::from_str::<Geometry>(&encoded).expect("cannot deserialize geometry"),
+            point
+        );
+        assert!(serde_json::from_str::<Geometry>(r#""0101000000000000000000F03F""#).is_err());
+    }
+
+    #[cfg(feature = "ewkb")]
+    #[test]
+    fn test_parse_hex_ewkb() {
+        let mut point = Geometry::from(Point::new([1.0, 2.0]));
+        point.set_srid(Some(4326));
+
+        assert_eq!(
+            "0101000020E6100000000000000000F03F0000000000000040"
+                .parse::<Geometry>()
+                .unwrap(),
+            point
+        );
+
+        // SELECT 'SRID=4326;MULTIPOINT((1 2),(3 4))'::geometry, tel qu'affiché par psql.
+        let hex = "0104000020E6100000020000000101000000000000000000F03F000000000000004001010000\
+                   0000000000000008400000000000001040";
+        assert_eq!(
+            hex.parse::<Geometry>().unwrap(),
+            "SRID=4326;MULTIPOINT((1 2),(3 4))"
+                .parse::<Geometry>()
+                .unwrap()
+        );
+    }
+}
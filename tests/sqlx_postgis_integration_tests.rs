@@ -1,9 +1,13 @@
+#![cfg(all(feature = "postgis", feature = "sea-query"))]
+
 use std::{
     error::Error,
     ops::{Deref, DerefMut},
 };
 
-use sql_gis::sql_types::PgPoint;
+use sea_query::{Alias, Expr, PostgresQueryBuilder, Query};
+use sea_query_binder::SqlxBinder;
+use sql_gis::{sql_types::PgPoint, types::GeometryImpl as _};
 use sqlx::{postgres::PgConnectOptions, Connection, PgConnection};
 
 struct PgInstance {
@@ -35,7 +39,7 @@ async fn connect_to_database() -> Result<PgInstance, Box<dyn Error>> {
 
     let conn = PgConnection::connect_with(&opts)
         .await
-        .expect(&format!("failed to connect to database",));
+        .expect("failed to connect to database");
 
     Ok(PgInstance { conn })
 }
@@ -78,3 +82,38 @@ async fn test_postgis_isomorphism() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+#[sqlx::test]
+/// Teste la liaison d'une géométrie construite avec sea-query via sea-query-binder
+async fn test_postgis_sea_query_binder() -> Result<(), Box<dyn Error>> {
+    let mut instance = setup().await.expect("failed to setup environment");
+
+    let expected = PgPoint::new([10.1, 20.2]);
+
+    let (sql, values) = Query::insert()
+        .into_table(Alias::new("gis_points"))
+        .columns([Alias::new("pt")])
+        .values_panic([expected.clone().into()])
+        .returning_col(Alias::new("id"))
+        .build_sqlx(PostgresQueryBuilder);
+
+    let (id,): (i32,) = sqlx::query_as_with(&sql, values)
+        .fetch_one(instance.deref_mut())
+        .await
+        .expect("failed to insert geometry");
+
+    let (sql, values) = Query::select()
+        .column(Alias::new("pt"))
+        .from(Alias::new("gis_points"))
+        .and_where(Expr::col(Alias::new("id")).eq(id))
+        .build_sqlx(PostgresQueryBuilder);
+
+    let (value,): (PgPoint,) = sqlx::query_as_with(&sql, values)
+        .fetch_one(instance.deref_mut())
+        .await
+        .expect("failed to retrieve geometry");
+
+    assert_eq!(expected, value);
+
+    Ok(())
+}
@@ -1,9 +1,8 @@
+#![cfg(feature = "spatialite")]
+
 use std::{error::Error, str::FromStr};
 
-use sql_gis::{
-    sql_types::SpatiaLitePoint,
-    types::{GeometryImpl as _, Point},
-};
+use sql_gis::{sql_types::SpatiaLitePoint, types::GeometryImpl as _};
 use sqlx::{sqlite::SqliteConnectOptions, Connection, SqliteConnection};
 
 /// Crée une base de données en mémoire, et charge l'extension SpatiaLite.
@@ -31,7 +30,8 @@ async fn setup() -> Result<SqliteConnection, Box<dyn Error>> {
 async fn test_spatialite_isomorphism() -> Result<(), Box<dyn Error>> {
     let mut conn = setup().await.expect("cannot setup test environment");
 
-    let expected = SpatiaLitePoint::new([10.1, 20.2]);
+    let mut expected = SpatiaLitePoint::new([10.1, 20.2]);
+    expected.srid = Some(4326);
 
     let (id,): (u32,) = sqlx::query_as("INSERT INTO gis_points (pt) VALUES (?) RETURNING id")
         .bind(&expected)
@@ -46,4 +46,4 @@ async fn test_spatialite_isomorphism() -> Result<(), Box<dyn Error>> {
     assert_eq!(expected, value);
 
     Ok(())
-}
\ No newline at end of file
+}